@@ -0,0 +1,113 @@
+//! 압축 HWP 파일 일괄 추출 벤치마크
+//!
+//! `cargo bench --bench batch`로 실행한다. 임시 디렉토리에 합성 문서를 만들고
+//! [`hwarang::extract_text_batch`] 반복 시간의 최솟값과 중앙값을 출력한다.
+
+use std::hint::black_box;
+use std::io::{Cursor, Write};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+const HWPTAG_DOCUMENT_PROPERTIES: u32 = 16;
+const HWPTAG_PARA_HEADER: u32 = 66;
+const HWPTAG_PARA_TEXT: u32 = 67;
+
+/// 레코드 하나를 직렬화한다 (4095 이상은 확장 크기 사용).
+fn push_record(out: &mut Vec<u8>, tag_id: u32, level: u32, data: &[u8]) {
+    let size = data.len() as u32;
+    let packed_size = size.min(0xFFF);
+    out.extend_from_slice(&(tag_id | (level << 10) | (packed_size << 20)).to_le_bytes());
+    if packed_size == 0xFFF {
+        out.extend_from_slice(&size.to_le_bytes());
+    }
+    out.extend_from_slice(data);
+}
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder =
+        flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// `paragraphs`개 문단짜리 섹션을 `sections`개 담은 압축 HWP 문서
+fn document(sections: u16, paragraphs: usize) -> Vec<u8> {
+    let text: Vec<u8> = "가나다라마바사아자차카타파하 "
+        .encode_utf16()
+        .cycle()
+        .take(200)
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    let mut section = Vec::new();
+    for _ in 0..paragraphs {
+        push_record(&mut section, HWPTAG_PARA_HEADER, 0, &[0; 22]);
+        push_record(&mut section, HWPTAG_PARA_TEXT, 1, &text);
+    }
+    let section = deflate(&section);
+
+    let mut props = vec![0u8; 26];
+    props[..2].copy_from_slice(&sections.to_le_bytes());
+    let mut doc_info = Vec::new();
+    push_record(&mut doc_info, HWPTAG_DOCUMENT_PROPERTIES, 0, &props);
+
+    let mut header = b"HWP Document File".to_vec();
+    header.resize(32, 0);
+    header.extend_from_slice(&0x0501_0000u32.to_le_bytes());
+    header.extend_from_slice(&1u32.to_le_bytes()); // 압축
+    header.resize(256, 0);
+
+    let mut comp = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+    comp.create_storage("/BodyText").unwrap();
+    let mut streams = vec![
+        ("/FileHeader".to_string(), header),
+        ("/DocInfo".to_string(), deflate(&doc_info)),
+    ];
+    for i in 0..sections {
+        streams.push((format!("/BodyText/Section{i}"), section.clone()));
+    }
+    for (path, data) in streams {
+        comp.create_stream(&path).unwrap().write_all(&data).unwrap();
+    }
+    comp.flush().unwrap();
+    comp.into_inner().into_inner()
+}
+
+fn bench(name: &str, paths: &[PathBuf], iterations: usize) {
+    let mut times: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            let results = black_box(hwarang::extract_text_batch(black_box(paths)));
+            assert!(results.iter().all(|r| r.result.is_ok()));
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    let median = times[times.len() / 2];
+    println!(
+        "{name}: {} files, min {:?}, median {:?} ({:.0} files/s)",
+        paths.len(),
+        times[0],
+        median,
+        paths.len() as f64 / median.as_secs_f64()
+    );
+}
+
+fn main() {
+    let dir = std::env::temp_dir().join(format!("hwarang-bench-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let write_files = |prefix: &str, count: usize, data: &[u8]| -> Vec<PathBuf> {
+        (0..count)
+            .map(|i| {
+                let path = dir.join(format!("{prefix}{i}.hwp"));
+                std::fs::write(&path, data).unwrap();
+                path
+            })
+            .collect()
+    };
+
+    let small = write_files("small", 512, &document(1, 50));
+    let large = write_files("large", 64, &document(4, 2000));
+    bench("batch/512-small", &small, 20);
+    bench("batch/64-large", &large, 10);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,53 @@
+//! 큰 문단의 PARA_TEXT 분할 벤치마크
+//!
+//! `cargo bench --bench para_text`로 실행한다. 외부 벤치마크 도구 없이 반복 시간의
+//! 최솟값과 중앙값을 출력한다.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use hwarang::hwp::para_text;
+
+/// 100글자마다 표 컨트롤이 끼어 있는 약 `units` 코드 유닛짜리 PARA_TEXT
+fn large_paragraph(units: usize) -> Vec<u8> {
+    let mut data = Vec::with_capacity(units * 2);
+    let mut written = 0;
+    while written < units {
+        for ch in "가나다라마바사아자차카타파하 "
+            .encode_utf16()
+            .cycle()
+            .take(100)
+        {
+            data.extend_from_slice(&ch.to_le_bytes());
+        }
+        data.extend_from_slice(&11u16.to_le_bytes());
+        data.extend_from_slice(&[0u8; 14]);
+        written += 108;
+    }
+    data
+}
+
+fn bench(name: &str, data: &[u8], iterations: usize) {
+    let mut times: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(para_text::extract_text_segments(black_box(data)));
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    let mib = data.len() as f64 / (1024.0 * 1024.0);
+    let median = times[times.len() / 2];
+    println!(
+        "{name}: {:.2} MiB, min {:?}, median {:?} ({:.0} MiB/s)",
+        mib,
+        times[0],
+        median,
+        mib / median.as_secs_f64()
+    );
+}
+
+fn main() {
+    bench("segments/64k-units", &large_paragraph(64 * 1024), 200);
+    bench("segments/4m-units", &large_paragraph(4 * 1024 * 1024), 20);
+}
@@ -0,0 +1,142 @@
+//! 소유 레코드와 빌린 레코드 파싱의 섹션 추출 비교 벤치마크
+//!
+//! `cargo bench --bench records`로 실행한다. 섹션 크기마다
+//! [`ExtractOptions::borrowed_records_threshold`]를 0(항상 빌림)과 `usize::MAX`(항상 소유)로
+//! 두고 추출 시간의 최솟값·중앙값과 최대 힙 사용량을 출력한다.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::io::{Cursor, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use hwarang::{ExtractOptions, HwpDocument};
+
+const HWPTAG_DOCUMENT_PROPERTIES: u32 = 16;
+const HWPTAG_PARA_HEADER: u32 = 66;
+const HWPTAG_PARA_TEXT: u32 = 67;
+
+/// 현재·최대 할당 바이트를 세는 할당자
+struct Counting;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// 레코드 하나를 직렬화한다 (4095 이상은 확장 크기 사용).
+fn push_record(out: &mut Vec<u8>, tag_id: u32, level: u32, data: &[u8]) {
+    let size = data.len() as u32;
+    let packed_size = size.min(0xFFF);
+    out.extend_from_slice(&(tag_id | (level << 10) | (packed_size << 20)).to_le_bytes());
+    if packed_size == 0xFFF {
+        out.extend_from_slice(&size.to_le_bytes());
+    }
+    out.extend_from_slice(data);
+}
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder =
+        flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// 압축 해제 크기가 약 `section_bytes`인 섹션 하나짜리 압축 HWP 문서
+fn document(section_bytes: usize) -> Vec<u8> {
+    let text: Vec<u8> = "가나다라마바사아자차카타파하 "
+        .encode_utf16()
+        .cycle()
+        .take(200)
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    let mut section = Vec::with_capacity(section_bytes);
+    while section.len() < section_bytes {
+        push_record(&mut section, HWPTAG_PARA_HEADER, 0, &[0; 22]);
+        push_record(&mut section, HWPTAG_PARA_TEXT, 1, &text);
+    }
+
+    let mut props = vec![0u8; 26];
+    props[..2].copy_from_slice(&1u16.to_le_bytes());
+    let mut doc_info = Vec::new();
+    push_record(&mut doc_info, HWPTAG_DOCUMENT_PROPERTIES, 0, &props);
+
+    let mut header = b"HWP Document File".to_vec();
+    header.resize(32, 0);
+    header.extend_from_slice(&0x0501_0000u32.to_le_bytes());
+    header.extend_from_slice(&1u32.to_le_bytes()); // 압축
+    header.resize(256, 0);
+
+    let mut comp = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+    comp.create_storage("/BodyText").unwrap();
+    for (path, data) in [
+        ("/FileHeader", header),
+        ("/DocInfo", deflate(&doc_info)),
+        ("/BodyText/Section0", deflate(&section)),
+    ] {
+        comp.create_stream(path).unwrap().write_all(&data).unwrap();
+    }
+    comp.flush().unwrap();
+    comp.into_inner().into_inner()
+}
+
+fn bench(name: &str, bytes: &[u8], threshold: usize, iterations: usize) {
+    let opts = ExtractOptions {
+        borrowed_records_threshold: threshold,
+        ..Default::default()
+    };
+    let mut peak = 0;
+    let mut times: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let mut doc = HwpDocument::from_bytes(bytes).unwrap();
+            PEAK.store(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+            let base = CURRENT.load(Ordering::Relaxed);
+            let start = Instant::now();
+            black_box(doc.extract_text_with_options(black_box(&opts)).unwrap());
+            let elapsed = start.elapsed();
+            peak = peak.max(PEAK.load(Ordering::Relaxed) - base);
+            elapsed
+        })
+        .collect();
+    times.sort();
+    println!(
+        "{name}: min {:?}, median {:?}, peak heap {:.2} MiB",
+        times[0],
+        times[times.len() / 2],
+        peak as f64 / (1024.0 * 1024.0)
+    );
+}
+
+fn main() {
+    for (label, size, iterations) in [
+        ("64k", 64 * 1024, 200),
+        ("1m", 1024 * 1024, 50),
+        ("16m", 16 * 1024 * 1024, 10),
+    ] {
+        let bytes = document(size);
+        bench(
+            &format!("records/{label}/owned"),
+            &bytes,
+            usize::MAX,
+            iterations,
+        );
+        bench(&format!("records/{label}/borrowed"), &bytes, 0, iterations);
+    }
+}
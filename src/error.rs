@@ -1,12 +1,46 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
 use thiserror::Error;
 
 /// Errors that can occur while reading or parsing HWP/HWPX documents.
+///
+/// New variants may be added in minor releases, so `match` statements outside
+/// this crate need a wildcard arm.
+///
+/// # Examples
+///
+/// ```
+/// use hwarang::error::HwpError;
+///
+/// fn needs_password(err: &HwpError) -> bool {
+///     match err {
+///         HwpError::PasswordProtected => true,
+///         _ => false,
+///     }
+/// }
+///
+/// assert!(needs_password(&HwpError::PasswordProtected));
+/// assert!(!needs_password(&HwpError::UnsupportedFormat));
+/// ```
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum HwpError {
     /// An I/O error occurred while reading the file or stream.
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// The input file could not be opened. Unlike [`Io`](Self::Io), the
+    /// message names the file, so batch diagnostics show which one failed.
+    #[error("Cannot open {}: {source}", path.display())]
+    Open {
+        /// The file that could not be opened.
+        path: PathBuf,
+        /// The underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+
     /// The file does not start with the expected HWP OLE signature.
     #[error("Invalid HWP signature")]
     InvalidSignature,
@@ -31,10 +65,22 @@ pub enum HwpError {
     #[error("Decompression failed: {0}")]
     DecompressFailed(String),
 
+    /// A compressed stream ended before the final deflate block, so only
+    /// part of it could be decompressed. The payload is the number of bytes
+    /// recovered.
+    #[error("Compressed stream is truncated ({0} bytes recovered)")]
+    TruncatedStream(usize),
+
     /// AES decryption of a distribution-document stream failed.
     #[error("Decryption failed: {0}")]
     DecryptFailed(String),
 
+    /// A distribution-document stream decrypted without error but did not
+    /// yield valid records, which usually means the key stored in the
+    /// stream header does not match the data.
+    #[error("Invalid records after decryption: {0}")]
+    InvalidDecryptedRecords(String),
+
     /// A general parse error for malformed or unexpected data.
     #[error("Parse error: {0}")]
     Parse(String),
@@ -43,6 +89,11 @@ pub enum HwpError {
     #[error("Unsupported file format")]
     UnsupportedFormat,
 
+    /// The HWPX package is wrapped by Hancom DRM. The content is encrypted
+    /// and cannot be extracted; the payload names the DRM marker entry found.
+    #[error("DRM-protected document ({0})")]
+    DrmProtected(String),
+
     /// An error specific to HWPX (ZIP/XML) processing.
     #[error("HWPX error: {0}")]
     Hwpx(String),
@@ -51,6 +102,48 @@ pub enum HwpError {
 /// A specialised `Result` type for HWP operations.
 pub type Result<T> = std::result::Result<T, HwpError>;
 
+/// A recoverable problem met while extracting text.
+///
+/// Extraction still succeeds, but the text may be incomplete or laid out
+/// differently from the document. Returned by
+/// [`extract_text_with_warnings`](crate::extract_text_with_warnings).
+///
+/// New variants may be added in minor releases, so `match` statements outside
+/// this crate need a wildcard arm.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Warning {
+    /// A table declared more cells than
+    /// [`ExtractOptions::max_table_cells`](crate::ExtractOptions::max_table_cells)
+    /// allows, so its cells were written one per line instead of as a grid.
+    #[error("Table of {rows}x{cols} cells exceeds the cell limit; written as plain lines")]
+    TableTooLarge {
+        /// Row count declared by the table.
+        rows: u16,
+        /// Column count declared by the table.
+        cols: u16,
+    },
+
+    /// A section's compressed stream ended before its final block. The
+    /// paragraphs decoded before the cut were kept and the rest of the
+    /// section is missing.
+    #[error("Section {section} is truncated; kept the text of its first {recovered} bytes")]
+    TruncatedSection {
+        /// Zero-based section number (`SectionN` stream index).
+        section: u16,
+        /// Number of decompressed bytes recovered before the cut.
+        recovered: usize,
+    },
+}
+
+/// 입력 파일을 연다. 실패하면 경로를 담은 [`HwpError::Open`]을 반환한다.
+pub(crate) fn open_file(path: &Path) -> Result<File> {
+    File::open(path).map_err(|source| HwpError::Open {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -62,6 +155,17 @@ mod tests {
         assert!(msg.contains("I/O error"), "got: {msg}");
     }
 
+    #[test]
+    fn test_open_error_names_path() {
+        let path = std::env::temp_dir().join("hwarang_missing_dir/없는 문서.hwp");
+        let err = open_file(&path).unwrap_err();
+        assert!(matches!(&err, HwpError::Open { source, .. }
+            if source.kind() == std::io::ErrorKind::NotFound));
+        let msg = err.to_string();
+        assert!(msg.starts_with("Cannot open "), "got: {msg}");
+        assert!(msg.contains(&path.display().to_string()), "got: {msg}");
+    }
+
     #[test]
     fn test_display_invalid_signature() {
         let msg = HwpError::InvalidSignature.to_string();
@@ -110,6 +214,44 @@ mod tests {
         assert_eq!(msg, "Decompression failed: corrupt");
     }
 
+    #[test]
+    fn test_display_truncated_stream() {
+        let msg = HwpError::TruncatedStream(151).to_string();
+        assert_eq!(msg, "Compressed stream is truncated (151 bytes recovered)");
+    }
+
+    #[test]
+    fn test_display_invalid_decrypted_records() {
+        let msg = HwpError::InvalidDecryptedRecords("Section1".into()).to_string();
+        assert_eq!(msg, "Invalid records after decryption: Section1");
+    }
+
+    #[test]
+    fn test_display_table_too_large() {
+        let msg = Warning::TableTooLarge {
+            rows: 60_000,
+            cols: 3,
+        }
+        .to_string();
+        assert_eq!(
+            msg,
+            "Table of 60000x3 cells exceeds the cell limit; written as plain lines"
+        );
+    }
+
+    #[test]
+    fn test_display_truncated_section() {
+        let msg = Warning::TruncatedSection {
+            section: 2,
+            recovered: 151,
+        }
+        .to_string();
+        assert_eq!(
+            msg,
+            "Section 2 is truncated; kept the text of its first 151 bytes"
+        );
+    }
+
     #[test]
     fn test_display_parse() {
         let msg = HwpError::Parse("unexpected".into()).to_string();
@@ -121,4 +263,22 @@ mod tests {
         let msg = HwpError::InvalidRecordHeader.to_string();
         assert_eq!(msg, "Invalid record header");
     }
+
+    /// 외부 크레이트에서 권장되는 매칭 방식: 관심 있는 변형만 처리하고 나머지는 와일드카드
+    fn describe(err: &HwpError) -> &'static str {
+        match err {
+            HwpError::PasswordProtected => "password",
+            HwpError::UnsupportedFormat => "format",
+            HwpError::Io(_) => "io",
+            _ => "other",
+        }
+    }
+
+    #[test]
+    fn test_match_with_wildcard_arm() {
+        assert_eq!(describe(&HwpError::PasswordProtected), "password");
+        assert_eq!(describe(&HwpError::UnsupportedFormat), "format");
+        assert_eq!(describe(&HwpError::Parse("x".into())), "other");
+        assert_eq!(describe(&HwpError::InvalidRecordHeader), "other");
+    }
 }
@@ -1,8 +1,19 @@
+use std::cell::RefCell;
+use std::ops::Deref;
+
 use byteorder::{LittleEndian, ReadBytesExt};
 
+use crate::error::Warning;
+use crate::hwp::bindata::{self, BinData};
 use crate::hwp::control;
+use crate::hwp::docinfo::{self, Alignment, CharShape, DocInfo, ParaHead, ParaShape};
+use crate::hwp::field;
+use crate::hwp::form;
 use crate::hwp::para_text;
+use crate::hwp::paragraph;
 use crate::hwp::record::{self, Record};
+use crate::markdown;
+use crate::options::{CellNewlines, ExtractOptions, FloatingObjects, FootnotePlacement, TableMode};
 
 /// 섹션 레코드 시퀀스에서 텍스트를 추출한다.
 ///
@@ -10,19 +21,620 @@ use crate::hwp::record::{self, Record};
 /// 컨트롤 서브트리(표 셀, 각주, 텍스트박스 등)를 인라인으로 재귀 처리하여
 /// 문서 흐름 순서대로 텍스트를 출력한다.
 pub fn extract_section_text(records: &[Record], text: &mut String) {
+    extract_section_text_with_options(records, &ExtractOptions::default(), text);
+}
+
+/// [`extract_section_text`]의 옵션 지정 버전
+pub fn extract_section_text_with_options(
+    records: &[Record],
+    opts: &ExtractOptions,
+    text: &mut String,
+) {
+    let ctx = WalkContext::new(records, opts);
+    walk_section(records, &ctx, text);
+}
+
+/// 문서 전역 설정(DocInfo)을 반영하는 [`extract_section_text_with_options`]
+pub fn extract_section_text_with_doc_info(
+    records: &[Record],
+    doc_info: &DocInfo,
+    opts: &ExtractOptions,
+    text: &mut String,
+) {
+    extract_section_text_with_warnings(records, doc_info, opts, text);
+}
+
+/// [`extract_section_text_with_doc_info`]와 같되, 추출 중 생긴 경고를 반환한다.
+pub fn extract_section_text_with_warnings<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    doc_info: &DocInfo,
+    opts: &ExtractOptions,
+    text: &mut String,
+) -> Vec<Warning> {
+    let ctx = WalkContext::with_doc_info(records, doc_info, opts);
+    walk_section(records, &ctx, text);
+    ctx.warnings.into_inner()
+}
+
+/// 추출 결과 문자열에서 문단 하나가 차지하는 바이트 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParaSpan {
+    /// 시작 오프셋 (바이트, 포함)
+    pub start: usize,
+    /// 끝 오프셋 (바이트, 제외)
+    pub end: usize,
+    /// 범위의 출처
+    pub kind: ParaKind,
+}
+
+/// [`ParaSpan`]의 출처 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParaKind {
+    /// 본문 문단
+    Body,
+    /// 표
+    Table,
+    /// 각주
+    Footnote,
+    /// 미주
+    Endnote,
+    /// 머리말/꼬리말
+    HeaderFooter,
+    /// 수식
+    Equation,
+    /// 그 밖의 컨트롤 (글상자, 그림, 숨은 설명 등)
+    Other,
+}
+
+impl ParaKind {
+    /// 컨트롤 ID로 분류한다.
+    fn from_ctrl_id(id: Option<u32>) -> Self {
+        match id {
+            Some(control::CTRL_TABLE) => ParaKind::Table,
+            Some(control::CTRL_FOOTNOTE) => ParaKind::Footnote,
+            Some(control::CTRL_ENDNOTE) => ParaKind::Endnote,
+            Some(control::CTRL_HEADER | control::CTRL_FOOTER) => ParaKind::HeaderFooter,
+            Some(control::CTRL_EQUATION) => ParaKind::Equation,
+            _ => ParaKind::Other,
+        }
+    }
+}
+
+/// [`extract_section_text_with_doc_info`]와 같되, 최상위 문단과 그 문단의
+/// 컨트롤 출력 범위를 `spans`에 기록한다.
+///
+/// 본문 문단 범위는 줄바꿈까지 포함하며 서로 이어진다. 컨트롤(표, 각주 등)
+/// 범위는 이를 앵커한 본문 문단 범위 안에 중첩되고, 바깥 범위가 먼저 온다.
+/// 오프셋은 `text`의 기존 내용을 포함한 절대 위치다.
+pub fn extract_section_text_with_spans(
+    records: &[Record],
+    doc_info: &DocInfo,
+    opts: &ExtractOptions,
+    text: &mut String,
+    spans: &mut Vec<ParaSpan>,
+) {
+    let ctx = WalkContext {
+        spans: Some(RefCell::new(Vec::new())),
+        ..WalkContext::with_doc_info(records, doc_info, opts)
+    };
+    walk_section(records, &ctx, text);
+    spans.extend(ctx.spans.map(RefCell::into_inner).unwrap_or_default());
+}
+
+/// 본문과 머리말·꼬리말·각주를 나누어 담은 추출 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentRegions {
+    /// 머리말·꼬리말·각주/미주를 뺀 본문 텍스트
+    pub body: String,
+    /// 머리말 텍스트 (컨트롤마다 하나, 문서 순서)
+    pub headers: Vec<String>,
+    /// 꼬리말 텍스트
+    pub footers: Vec<String>,
+    /// 각주·미주 텍스트
+    pub footnotes: Vec<String>,
+    /// `headers`와 같은 순서의 각 머리말 적용 쪽
+    pub header_pages: Vec<HeaderFooterPages>,
+    /// `footers`와 같은 순서의 각 꼬리말 적용 쪽
+    pub footer_pages: Vec<HeaderFooterPages>,
+}
+
+/// 머리말·꼬리말이 적용되는 쪽
+///
+/// 한 구역에 양쪽용과 짝수·홀수 쪽용 머리말을 따로 둘 수 있다. HWP에는 첫 쪽 전용
+/// 머리말이 없으며, 첫 쪽만 다르게 하려면 감추기 컨트롤을 쓴다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HeaderFooterPages {
+    /// 모든 쪽 (기본값)
+    #[default]
+    Both,
+    /// 짝수 쪽
+    Even,
+    /// 홀수 쪽
+    Odd,
+}
+
+impl HeaderFooterPages {
+    /// 머리말·꼬리말 CTRL_HEADER의 적용 쪽 (알 수 없는 값은 양쪽)
+    fn from_ctrl_header(data: &[u8]) -> Self {
+        match control::header_footer_page_type(data) {
+            Some(1) => HeaderFooterPages::Even,
+            Some(2) => HeaderFooterPages::Odd,
+            _ => HeaderFooterPages::Both,
+        }
+    }
+}
+
+/// [`extract_section_text_with_doc_info`]와 같되, 머리말·꼬리말·각주/미주 컨트롤을
+/// 본문에 넣지 않고 `regions`의 해당 목록에 따로 모은다. 본문은 `regions.body`에 덧붙는다.
+pub fn extract_section_regions(
+    records: &[Record],
+    doc_info: &DocInfo,
+    opts: &ExtractOptions,
+    regions: &mut DocumentRegions,
+) {
+    let ctx = WalkContext {
+        regions: Some(RefCell::new(std::mem::take(regions))),
+        ..WalkContext::with_doc_info(records, doc_info, opts)
+    };
+    let mut body = String::new();
+    walk_section(records, &ctx, &mut body);
+    *regions = ctx.regions.map(RefCell::into_inner).unwrap_or_default();
+    regions.body.push_str(&body);
+}
+
+/// 본문에서 옮긴 섹션의 각주·미주 텍스트 ([`extract_section_text_with_notes`])
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SectionNotes {
+    /// 각주 텍스트 (참조 순서, 끝 줄바꿈 제외)
+    pub footnotes: Vec<String>,
+    /// 미주 텍스트 (참조 순서, 끝 줄바꿈 제외)
+    pub endnotes: Vec<String>,
+    /// 추출 중 생긴 경고
+    pub warnings: Vec<Warning>,
+}
+
+/// [`extract_section_text_with_doc_info`]와 같되, 옵션에 따라 각주
+/// ([`ExtractOptions::footnote_placement`])와 미주([`ExtractOptions::endnotes_at_end`])를
+/// 본문 대신 따로 모은다.
+///
+/// 참조 위치에는 `[fn:N]`·`[en:N]` 표시를 남기며, 번호는 각각 `first_footnote`와
+/// `first_endnote`부터 매긴다. 문서 전체에 걸쳐 번호를 이으려면 앞 섹션들의
+/// [`count_footnotes`]·[`count_endnotes`] 합에 1을 더한 값을 넘긴다.
+pub fn extract_section_text_with_notes(
+    records: &[Record],
+    doc_info: &DocInfo,
+    opts: &ExtractOptions,
+    first_footnote: usize,
+    first_endnote: usize,
+    text: &mut String,
+) -> SectionNotes {
+    let moved = |move_notes: bool, next_number: usize| {
+        move_notes.then(|| {
+            RefCell::new(MovedNotes {
+                next_number,
+                texts: Vec::new(),
+            })
+        })
+    };
+    let ctx = WalkContext {
+        footnotes: moved(
+            opts.footnote_placement != FootnotePlacement::Inline,
+            first_footnote,
+        ),
+        endnotes: moved(opts.endnotes_at_end, first_endnote),
+        ..WalkContext::with_doc_info(records, doc_info, opts)
+    };
+    walk_section(records, &ctx, text);
+    let texts =
+        |notes: Option<RefCell<MovedNotes>>| notes.map_or_else(Vec::new, |n| n.into_inner().texts);
+    SectionNotes {
+        footnotes: texts(ctx.footnotes),
+        endnotes: texts(ctx.endnotes),
+        warnings: ctx.warnings.into_inner(),
+    }
+}
+
+/// 섹션의 미주 컨트롤 수
+pub fn count_endnotes(records: &[Record]) -> usize {
+    count_controls(records, control::CTRL_ENDNOTE)
+}
+
+/// 섹션의 각주 컨트롤 수
+pub fn count_footnotes(records: &[Record]) -> usize {
+    count_controls(records, control::CTRL_FOOTNOTE)
+}
+
+fn count_controls(records: &[Record], ctrl_id: u32) -> usize {
+    records
+        .iter()
+        .filter(|r| {
+            r.header.tag_id == record::HWPTAG_CTRL_HEADER
+                && control::read_ctrl_id(&r.data) == Some(ctrl_id)
+        })
+        .count()
+}
+
+/// 최상위 문단 하나의 추출 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paragraph {
+    /// 문단 텍스트 (앵커된 표 등 컨트롤 출력과 끝 줄바꿈 포함)
+    pub text: String,
+    /// 문단 모양의 정렬 (문단 모양을 찾지 못하면 양쪽 정렬)
+    pub alignment: Alignment,
+}
+
+/// [`extract_section_text_with_doc_info`]와 같되, 최상위 문단마다 텍스트와 정렬을
+/// `paragraphs`에도 담는다.
+///
+/// 구역 끝으로 보류된 떠 있는 개체는 `text`에만 덧붙는다.
+pub fn extract_section_paragraphs(
+    records: &[Record],
+    doc_info: &DocInfo,
+    opts: &ExtractOptions,
+    text: &mut String,
+    paragraphs: &mut Vec<Paragraph>,
+) {
+    let ctx = WalkContext::with_doc_info(records, doc_info, opts);
+    let mut pos = 0;
+    while pos < records.len() {
+        let rec = &records[pos];
+        if rec.header.tag_id != record::HWPTAG_PARA_HEADER || rec.header.level != 0 {
+            pos += 1;
+            continue;
+        }
+        let alignment = paragraph::parse_para_header(&rec.data)
+            .and_then(|h| doc_info.para_shapes.get(h.para_shape_id as usize))
+            .map(ParaShape::alignment)
+            .unwrap_or_default();
+        let start = text.len();
+        extract_para(records, &mut pos, 0, &ctx, text);
+        paragraphs.push(Paragraph {
+            text: text[start..].to_string(),
+            alignment,
+        });
+    }
+    text.push_str(&ctx.deferred.take());
+}
+
+/// [`extract_section_text_with_doc_info`]와 같되, 내용이 있는 최상위 문단을
+/// `limit`개 출력하면 나머지 문단은 읽지 않고 멈춘다.
+///
+/// 공백뿐인 문단은 세지 않는다. 출력한 비어 있지 않은 문단 수를 반환하며,
+/// 섹션이 먼저 끝나면 `limit`보다 작다.
+pub fn extract_section_first_paragraphs(
+    records: &[Record],
+    doc_info: &DocInfo,
+    opts: &ExtractOptions,
+    limit: usize,
+    text: &mut String,
+) -> usize {
+    let ctx = WalkContext::with_doc_info(records, doc_info, opts);
+    let mut count = 0;
+    let mut pos = 0;
+    while pos < records.len() && count < limit {
+        let rec = &records[pos];
+        if rec.header.tag_id != record::HWPTAG_PARA_HEADER || rec.header.level != 0 {
+            pos += 1;
+            continue;
+        }
+        let start = text.len();
+        extract_para(records, &mut pos, 0, &ctx, text);
+        if !text[start..].trim().is_empty() {
+            count += 1;
+        }
+    }
+    text.push_str(&ctx.deferred.take());
+    count
+}
+
+/// 섹션 최상위 문단 목록을 순회하고, 구역 끝으로 보류된 개체를 덧붙인다.
+fn walk_section<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    ctx: &WalkContext,
+    text: &mut String,
+) {
     let mut pos = 0;
-    extract_para_list(records, &mut pos, 0, text);
+    extract_para_list(records, &mut pos, 0, ctx, text);
+    text.push_str(&ctx.deferred.take());
+}
+
+/// 문서 개요(목차) 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineEntry {
+    /// 개요 수준 (1 = 최상위)
+    pub level: u8,
+    /// 제목 문단 텍스트
+    pub text: String,
+}
+
+/// 섹션의 최상위 문단 중 개요 문단(개요 문단 모양 또는 제목 스타일)을 순서대로 모은다.
+pub fn section_outline(records: &[Record], doc_info: &DocInfo) -> Vec<OutlineEntry> {
+    let mut entries = Vec::new();
+    let mut i = 0;
+    while i < records.len() {
+        let rec = &records[i];
+        i += 1;
+        if rec.header.tag_id != record::HWPTAG_PARA_HEADER || rec.header.level != 0 {
+            continue;
+        }
+        let level = paragraph::parse_para_header(&rec.data)
+            .and_then(|h| doc_info.outline_level(h.para_shape_id, h.style_id));
+
+        // 문단 범위: 다음 최상위 PARA_HEADER 전까지
+        let para_end = records[i..]
+            .iter()
+            .position(|r| r.header.tag_id == record::HWPTAG_PARA_HEADER && r.header.level == 0)
+            .map_or(records.len(), |off| i + off);
+
+        if let Some(level) = level {
+            let text = records[i..para_end]
+                .iter()
+                .find(|r| r.header.tag_id == record::HWPTAG_PARA_TEXT && r.header.level == 1)
+                .map(|r| para_text::extract_text(&r.data).0)
+                .unwrap_or_default();
+            let text = text.trim();
+            if !text.is_empty() {
+                entries.push(OutlineEntry {
+                    level,
+                    text: text.to_string(),
+                });
+            }
+        }
+        i = para_end;
+    }
+    entries
+}
+
+/// 구역의 쪽 배치 정보
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectionLayout {
+    /// 구역 첫머리 단 정의의 단 수 (단 정의가 없으면 1)
+    pub column_count: u8,
+}
+
+/// 섹션 최상위 문단의 첫 단 정의(cold)로 구역의 쪽 배치를 읽는다.
+///
+/// 구역 중간에 단 정의가 바뀌는 경우는 반영하지 않는다.
+pub fn section_layout(records: &[Record]) -> SectionLayout {
+    let column_count = records
+        .iter()
+        .filter(|r| r.header.tag_id == record::HWPTAG_CTRL_HEADER && r.header.level == 1)
+        .find_map(|r| control::column_count(&r.data))
+        .unwrap_or(1);
+    SectionLayout { column_count }
+}
+
+/// 섹션 첫 문단의 구역 정의와 새 쪽 번호 컨트롤로 구역의 시작 쪽 번호를 읽는다.
+///
+/// 첫 문단의 새 번호 지정(쪽)이 구역 정의의 쪽 번호보다 우선한다. 0이면 앞 구역에
+/// 이어 매기며, 구역 정의가 없어도 0이다.
+pub fn section_start_page(records: &[Record]) -> u16 {
+    let first_para_end = records
+        .iter()
+        .skip(1)
+        .position(|r| r.header.tag_id == record::HWPTAG_PARA_HEADER && r.header.level == 0)
+        .map_or(records.len(), |i| i + 1);
+    let controls = || {
+        records[..first_para_end]
+            .iter()
+            .filter(|r| r.header.tag_id == record::HWPTAG_CTRL_HEADER && r.header.level == 1)
+    };
+    controls()
+        .find_map(|r| control::new_page_number(&r.data))
+        .or_else(|| controls().find_map(|r| control::section_start_page(&r.data)))
+        .unwrap_or(0)
+}
+
+/// 섹션에서 글 뒤로 배치된 첫 그리기 개체(워터마크·배경 그림)를 찾는다.
+///
+/// 바탕쪽 등 모든 레벨의 컨트롤을 살핀다. 개체가 없으면 `None`, 있으면 그 안의
+/// 텍스트(글맵시·글상자)를 돌려주며 그림처럼 텍스트가 없으면 빈 문자열이다.
+pub fn find_watermark(records: &[Record], doc_info: &DocInfo) -> Option<String> {
+    let start = records.iter().position(|r| {
+        r.header.tag_id == record::HWPTAG_CTRL_HEADER && control::is_behind_text(&r.data)
+    })?;
+    let level = records[start].header.level;
+    let end = records[start + 1..]
+        .iter()
+        .position(|r| r.header.level <= level)
+        .map_or(records.len(), |i| start + 1 + i);
+    let opts = ExtractOptions::default();
+    let ctx = WalkContext::with_doc_info(records, doc_info, &opts);
+    let mut text = String::new();
+    extract_ctrl_subtree(records, start, end, &ctx, &mut text);
+    Some(text.trim().to_string())
+}
+
+/// 섹션 순회 중 공유되는 추출 설정
+struct WalkContext<'a> {
+    opts: &'a ExtractOptions,
+    /// 폭이 기록되지 않은 탭에 쓰는 기본 탭 간격 (HWPUNIT)
+    default_tab_width: u32,
+    /// 구역 끝에 출력할 떠 있는 개체 텍스트 ([`FloatingObjects::SectionEnd`])
+    deferred: RefCell<String>,
+    /// 최상위 문단 범위 기록 (기록하지 않으면 `None`)
+    spans: Option<RefCell<Vec<ParaSpan>>>,
+    /// DocInfo의 문단 모양 목록 (들여쓰기 계산용, 없으면 빈 목록)
+    para_shapes: &'a [ParaShape],
+    /// DocInfo의 글자 모양 목록 (취소선 표시용, 없으면 빈 목록)
+    char_shapes: &'a [CharShape],
+    /// DocInfo의 바이너리 데이터 목록 (그림 포함용)
+    bin_data: &'a [BinData],
+    /// 문단 번호 상태 ([`ExtractOptions::list_markers`])
+    list_counter: RefCell<ListCounter>,
+    /// 머리말·꼬리말·각주를 따로 모을 곳 (본문에 섞으면 `None`)
+    regions: Option<RefCell<DocumentRegions>>,
+    /// 구역·문서 끝으로 옮길 각주 ([`ExtractOptions::footnote_placement`], 본문에 두면 `None`)
+    footnotes: Option<RefCell<MovedNotes>>,
+    /// 문서 끝으로 옮길 미주 ([`ExtractOptions::endnotes_at_end`], 본문에 두면 `None`)
+    endnotes: Option<RefCell<MovedNotes>>,
+    /// 추출 중 생긴 경고
+    warnings: RefCell<Vec<Warning>>,
+}
+
+/// 본문 밖으로 옮기는 각주·미주 목록과 다음 번호
+#[derive(Debug, Default)]
+struct MovedNotes {
+    next_number: usize,
+    texts: Vec<String>,
+}
+
+/// 연속된 번호 문단의 수준별 번호
+///
+/// 같은 번호 ID의 번호 문단이 이어지는 동안 증가하며, 번호가 아닌 문단이나
+/// 다른 번호 ID를 만나면 처음부터 다시 센다. 상위 수준으로 돌아가면
+/// 하위 수준 번호만 초기화한다.
+#[derive(Debug, Default)]
+struct ListCounter {
+    numbering_id: Option<u16>,
+    counts: Vec<u32>,
+}
+
+impl ListCounter {
+    /// 문단 모양에 따른 머리 문자열. 번호·글머리표가 아니면 상태를 초기화하고 `None`.
+    fn marker(&mut self, shape: Option<&ParaShape>) -> Option<String> {
+        let Some(shape) =
+            shape.filter(|s| matches!(s.head(), ParaHead::Numbering | ParaHead::Bullet))
+        else {
+            *self = ListCounter::default();
+            return None;
+        };
+        let level = shape.head_level() as usize;
+        let indent = "   ".repeat(level);
+        if shape.head() == ParaHead::Bullet {
+            return Some(format!("{indent}- "));
+        }
+        if self.numbering_id != Some(shape.numbering_id) {
+            *self = ListCounter {
+                numbering_id: Some(shape.numbering_id),
+                counts: Vec::new(),
+            };
+        }
+        self.counts.resize(level + 1, 0);
+        self.counts[level] += 1;
+        Some(format!("{indent}{}. ", self.counts[level]))
+    }
+}
+
+impl<'a> WalkContext<'a> {
+    /// 구역 정의에 기본 탭 간격이 없으면 한/글 기본값을 쓴다.
+    fn new<D: Deref<Target = [u8]>>(records: &[Record<D>], opts: &'a ExtractOptions) -> Self {
+        let default_tab_width =
+            section_default_tab_width(records).unwrap_or(docinfo::DEFAULT_TAB_WIDTH);
+        WalkContext {
+            opts,
+            default_tab_width,
+            deferred: RefCell::new(String::new()),
+            spans: None,
+            para_shapes: &[],
+            char_shapes: &[],
+            bin_data: &[],
+            list_counter: RefCell::default(),
+            regions: None,
+            footnotes: None,
+            endnotes: None,
+            warnings: RefCell::default(),
+        }
+    }
+
+    /// 문서 전역 설정(문단·글자 모양, 바이너리 데이터)을 반영한 컨텍스트
+    fn with_doc_info<D: Deref<Target = [u8]>>(
+        records: &[Record<D>],
+        doc_info: &'a DocInfo,
+        opts: &'a ExtractOptions,
+    ) -> Self {
+        WalkContext {
+            para_shapes: &doc_info.para_shapes,
+            char_shapes: &doc_info.char_shapes,
+            bin_data: &doc_info.bin_data,
+            ..WalkContext::new(records, opts)
+        }
+    }
+
+    /// 컨트롤이 따로 모으는 영역이면 그 텍스트를 해당 목록에 넣고 `true`를 반환한다.
+    ///
+    /// 머리말·꼬리말은 적용 쪽도 함께 기록한다.
+    fn route_region(&self, ctrl_header: &[u8], extract: impl FnOnce(&mut String)) -> bool {
+        let Some(regions) = &self.regions else {
+            return false;
+        };
+        let pages = HeaderFooterPages::from_ctrl_header(ctrl_header);
+        let push: fn(&mut DocumentRegions, String, HeaderFooterPages) =
+            match control::read_ctrl_id(ctrl_header) {
+                Some(control::CTRL_HEADER) => |r, text, pages| {
+                    r.headers.push(text);
+                    r.header_pages.push(pages);
+                },
+                Some(control::CTRL_FOOTER) => |r, text, pages| {
+                    r.footers.push(text);
+                    r.footer_pages.push(pages);
+                },
+                Some(control::CTRL_FOOTNOTE | control::CTRL_ENDNOTE) => {
+                    |r, text, _| r.footnotes.push(text)
+                }
+                _ => return false,
+            };
+        let mut region_text = String::new();
+        extract(&mut region_text);
+        let region_text = region_text.trim_end_matches('\n');
+        if !region_text.is_empty() {
+            push(&mut regions.borrow_mut(), region_text.to_string(), pages);
+        }
+        true
+    }
+
+    /// 범위 기록을 시작하고 그 위치를 돌려준다. 기록하지 않으면 `None`.
+    fn begin_span(&self, start: usize, kind: ParaKind) -> Option<usize> {
+        let mut spans = self.spans.as_ref()?.borrow_mut();
+        spans.push(ParaSpan {
+            start,
+            end: start,
+            kind,
+        });
+        Some(spans.len() - 1)
+    }
+
+    /// 범위 끝을 기록한다. 아무것도 출력하지 않은 범위는 버린다.
+    fn end_span(&self, index: Option<usize>, end: usize) {
+        let (Some(index), Some(spans)) = (index, &self.spans) else {
+            return;
+        };
+        let mut spans = spans.borrow_mut();
+        if spans[index].start == end && index + 1 == spans.len() {
+            spans.pop();
+        } else {
+            spans[index].end = end;
+        }
+    }
+}
+
+/// 구역 정의(secd) CTRL_HEADER에서 기본 탭 간격을 읽는다.
+///
+/// 구조: ctrl_id(4) + 속성(4) + 단 간격(2) + 세로/가로 줄격자(2+2) + 기본 탭 간격(4)
+fn section_default_tab_width<D: Deref<Target = [u8]>>(records: &[Record<D>]) -> Option<u32> {
+    let secd = records.iter().find(|r| {
+        r.header.tag_id == record::HWPTAG_CTRL_HEADER
+            && control::read_ctrl_id(&r.data) == Some(control::CTRL_SECTION_DEF)
+    })?;
+    let width = (secd.data.get(14..18)?).read_u32::<LittleEndian>().ok()?;
+    (width > 0).then_some(width)
 }
 
 /// 주어진 base_level의 PARA_HEADER 시퀀스를 처리한다.
-fn extract_para_list(records: &[Record], pos: &mut usize, base_level: u16, text: &mut String) {
+fn extract_para_list<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    pos: &mut usize,
+    base_level: u16,
+    ctx: &WalkContext,
+    text: &mut String,
+) {
     while *pos < records.len() {
         let rec = &records[*pos];
         if rec.header.level < base_level {
             break;
         }
         if rec.header.tag_id == record::HWPTAG_PARA_HEADER && rec.header.level == base_level {
-            extract_para(records, pos, base_level, text);
+            extract_para(records, pos, base_level, ctx, text);
         } else {
             *pos += 1;
         }
@@ -39,14 +651,33 @@ fn extract_para_list(records: &[Record], pos: &mut usize, base_level: u16, text:
 ///       TABLE level=L+2
 ///       LIST_HEADER level=L+2
 ///       PARA_HEADER level=L+2 (셀 내부)
-fn extract_para(records: &[Record], pos: &mut usize, level: u16, text: &mut String) {
+fn extract_para<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    pos: &mut usize,
+    level: u16,
+    ctx: &WalkContext,
+    text: &mut String,
+) {
+    let header_data = &records[*pos].data;
     // PARA_HEADER 스킵
     *pos += 1;
 
+    let top_level = level == 0;
+    let span = if top_level {
+        ctx.begin_span(text.len(), ParaKind::Body)
+    } else {
+        None
+    };
+
     let para_start = *pos;
     let child_level = level + 1; // PARA_TEXT, CTRL_HEADER 등의 레벨
 
     let mut para_text_data: Option<&[u8]> = None;
+    // 레벨이 둘 이상 건너뛴 PARA_TEXT (제 레벨의 PARA_TEXT가 없을 때만 사용)
+    let mut jumped_text_data: Option<&[u8]> = None;
+    let mut char_shape_data: Option<&[u8]> = None;
+    let mut line_seg_data: Option<&[u8]> = None;
+    let mut range_tag_data: Option<&[u8]> = None;
     // 모든 CTRL_HEADER 서브트리 (ControlExtend 순서와 1:1 대응)
     let mut all_ctrl_subtrees: Vec<(usize, usize)> = Vec::new();
     let mut eqedit_texts: Vec<String> = Vec::new();
@@ -67,6 +698,20 @@ fn extract_para(records: &[Record], pos: &mut usize, level: u16, text: &mut Stri
 
         if rec.header.tag_id == record::HWPTAG_PARA_TEXT && rec.header.level == child_level {
             para_text_data = Some(&rec.data);
+        } else if rec.header.tag_id == record::HWPTAG_PARA_TEXT && rec.header.level > child_level {
+            jumped_text_data.get_or_insert(&rec.data);
+        } else if rec.header.tag_id == record::HWPTAG_PARA_CHAR_SHAPE
+            && rec.header.level == child_level
+        {
+            char_shape_data = Some(&rec.data);
+        } else if rec.header.tag_id == record::HWPTAG_PARA_LINE_SEG
+            && rec.header.level == child_level
+        {
+            line_seg_data = Some(&rec.data);
+        } else if rec.header.tag_id == record::HWPTAG_PARA_RANGE_TAG
+            && rec.header.level == child_level
+        {
+            range_tag_data = Some(&rec.data);
         } else if rec.header.tag_id == record::HWPTAG_CTRL_HEADER && rec.header.level == child_level
         {
             // CTRL_HEADER 서브트리 범위 기록
@@ -93,51 +738,369 @@ fn extract_para(records: &[Record], pos: &mut usize, level: u16, text: &mut Stri
     *pos = scan;
 
     // PARA_TEXT가 없으면 빈 문단
-    let Some(pt_data) = para_text_data else {
+    let Some(pt_data) = para_text_data.or(jumped_text_data) else {
         text.push_str("\n\n");
+        ctx.end_span(span, text.len());
         return;
     };
 
+    if top_level && (ctx.opts.indent_paragraphs || ctx.opts.list_markers) {
+        let shape = paragraph::parse_para_header(header_data)
+            .and_then(|h| ctx.para_shapes.get(h.para_shape_id as usize));
+        if ctx.opts.indent_paragraphs {
+            let indent = shape.map_or(0, |shape| indent_spaces(shape.left_margin));
+            text.extend(std::iter::repeat_n(' ', indent));
+        }
+        if ctx.opts.list_markers {
+            if let Some(marker) = ctx.list_counter.borrow_mut().marker(shape) {
+                text.push_str(&marker);
+            }
+        }
+    }
+
     // 세그먼트 분할 (모든 ControlExtend에서 분할 → CTRL_HEADER와 1:1 대응)
-    let segments = para_text::extract_text_segments(pt_data);
+    let tab_width = ctx.opts.expand_tabs.then_some(ctx.default_tab_width);
+    let run_marks = char_shape_data.map_or_else(Vec::new, |data| format_marks(data, ctx));
+    let marks: Vec<(u32, &str, &str)> = run_marks
+        .iter()
+        .map(|(start, open, close)| (*start, open.as_str(), close.as_str()))
+        .collect();
+    let breaks = match line_seg_data {
+        Some(data) if ctx.opts.visual_line_breaks => paragraph::parse_line_starts(data),
+        _ => Vec::new(),
+    };
+    let segments =
+        para_text::extract_text_segments_with_breaks(pt_data, tab_width, &marks, &breaks);
+    let range_tags = match range_tag_data {
+        Some(data) if ctx.opts.include_change_comments => paragraph::parse_range_tags(data),
+        _ => Vec::new(),
+    };
+
+    // 컨트롤 문자만 있는 문단 (예: 표 하나만 담은 문단)
+    let control_only = segments.iter().all(|seg| seg.text.is_empty());
+    let body_start = text.len();
 
     // 교차 출력: segment[0] → ctrl_subtree[0] → segment[1] → ctrl_subtree[1] → ...
     let mut ctrl_idx = 0;
-    for seg in &segments {
-        if !seg.text.is_empty() {
-            text.push_str(&seg.text);
+    // 직전 컨트롤이 하이퍼링크 필드이면 그 연결 대상
+    let mut link_target: Option<String> = None;
+    // 직전 컨트롤이 아무것도 출력하지 않았으면 그 직후 위치
+    let mut silent_joint: Option<usize> = None;
+    // 직전 컨트롤이 변경 내용에 달린 메모이면 그 출력 문자열
+    let mut change_comment: Option<String> = None;
+    // 필드 끝을 다음 세그먼트에서 찾지 못해 문단 끝에 붙일 메모
+    let mut trailing_comments = String::new();
+    for (seg_idx, seg) in segments.iter().enumerate() {
+        match (link_target.take(), change_comment.take(), seg.field_end) {
+            (Some(target), _, Some(end)) if !seg.text[..end].trim().is_empty() => {
+                text.push('[');
+                text.push_str(&seg.text[..end]);
+                text.push_str("](");
+                text.push_str(&target);
+                text.push(')');
+                text.push_str(&seg.text[end..]);
+            }
+            (_, Some(comment), Some(end)) => {
+                text.push_str(&seg.text[..end]);
+                text.push_str(&comment);
+                text.push_str(&seg.text[end..]);
+            }
+            (_, Some(comment), None) => {
+                text.push_str(&seg.text);
+                trailing_comments.push_str(&comment);
+            }
+            _ if silent_joint == Some(text.len()) => {
+                let rest = rejoin_marks(text, &seg.text, &marks);
+                text.push_str(rest);
+            }
+            _ => text.push_str(&seg.text),
+        }
+        silent_joint = None;
+        if let Some(code) = seg.control_code.filter(|_| ctx.opts.debug_control_markers) {
+            text.push_str(&format!("⟦ctrl:{code}⟧"));
         }
         if seg.has_control_after && ctrl_idx < all_ctrl_subtrees.len() {
             let (sub_start, sub_end) = all_ctrl_subtrees[ctrl_idx];
-            extract_ctrl_subtree(records, sub_start, sub_end, text);
+            let before_ctrl = text.len();
+            emit_ctrl_subtree(records, sub_start, sub_end, top_level, ctx, text);
+            link_target = field_link_target(&records[sub_start].data, ctx.opts);
+            change_comment = memo_comment(records, sub_start, sub_end, &range_tags, ctx);
+            if ctx.opts.expand_fields {
+                // 필드 표시 문자열: 다음 세그먼트의 필드 끝 이전 부분
+                let shown = segments
+                    .get(seg_idx + 1)
+                    .and_then(|next| next.field_end.map(|end| next.text[..end].trim().is_empty()));
+                if shown == Some(true) {
+                    if let Some(cached) = field_cached_text(records, sub_start, sub_end) {
+                        text.push_str(&cached);
+                    }
+                }
+            }
+            if text.len() == before_ctrl {
+                silent_joint = Some(text.len());
+            }
             ctrl_idx += 1;
         }
     }
 
+    text.extend(change_comment);
+    text.push_str(&trailing_comments);
+
     // 남은 ctrl_subtrees 처리
     while ctrl_idx < all_ctrl_subtrees.len() {
         let (sub_start, sub_end) = all_ctrl_subtrees[ctrl_idx];
-        extract_ctrl_subtree(records, sub_start, sub_end, text);
+        emit_ctrl_subtree(records, sub_start, sub_end, top_level, ctx, text);
         ctrl_idx += 1;
     }
 
     // 수식 텍스트 출력
     for eq in &eqedit_texts {
-        text.push_str(eq);
+        push_equation(eq, ctx.opts, text);
+    }
+
+    // 컨트롤 출력이 이미 줄바꿈으로 끝나면 문단 끝 줄바꿈을 더하지 않는다
+    // (표 뒤에 빈 문단이 생기지 않도록)
+    let ends_line = text.len() > body_start && text.ends_with('\n');
+    if !(control_only && ends_line) {
         text.push('\n');
     }
+    ctx.end_span(span, text.len());
+}
 
-    text.push('\n');
+/// PARA_CHAR_SHAPE 글자 모양 구간을 취소선·밑줄·음영 표시 문자열로 바꾼다.
+///
+/// 여는 표시는 취소선 → 밑줄 → 음영 순, 닫는 표시는 그 반대 순으로 겹쳐 중첩이
+/// 어긋나지 않게 한다. 켜진 표시 옵션이 없으면 빈 목록을 돌려준다.
+fn format_marks(data: &[u8], ctx: &WalkContext) -> Vec<(u32, String, String)> {
+    let opts = ctx.opts;
+    let strike = opts.strikethrough_markers.then_some(("~~", "~~"));
+    let wrappers = [
+        (strike, CharShape::strikethrough as fn(&CharShape) -> bool),
+        (
+            opts.underline_markers
+                .as_ref()
+                .map(|(o, c)| (o.as_str(), c.as_str())),
+            CharShape::underline,
+        ),
+        (
+            opts.highlight_markers
+                .as_ref()
+                .map(|(o, c)| (o.as_str(), c.as_str())),
+            CharShape::highlight,
+        ),
+    ];
+    if wrappers.iter().all(|(markers, _)| markers.is_none()) {
+        return Vec::new();
+    }
+    paragraph::parse_char_shape_runs(data)
+        .into_iter()
+        .map(|(start, id)| {
+            let shape = ctx.char_shapes.get(id as usize);
+            let mut open = String::new();
+            let mut close = String::new();
+            for (markers, applies) in &wrappers {
+                if let (Some((o, c)), Some(shape)) = (markers, shape) {
+                    if applies(shape) {
+                        open.push_str(o);
+                        close.insert_str(0, c);
+                    }
+                }
+            }
+            (start, open, close)
+        })
+        .collect()
+}
+
+/// 왼쪽 여백(HWPUNIT)을 들여쓰기 공백 수로 환산한다 (반올림, 음수는 0).
+fn indent_spaces(left_margin: i32) -> usize {
+    let unit = para_text::HWPUNIT_PER_SPACE as i32;
+    ((left_margin + unit / 2) / unit).max(0) as usize
+}
+
+/// 문단의 컨트롤 서브트리를 출력한다.
+///
+/// [`FloatingObjects::SectionEnd`]이면 쪽/종이 기준으로 떠 있는 개체는
+/// 앵커 위치 대신 구역 끝 보류 버퍼에 모은다.
+/// 최상위 문단의 컨트롤이면 출력 범위를 기록한다.
+fn emit_ctrl_subtree<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    start: usize,
+    end: usize,
+    top_level: bool,
+    ctx: &WalkContext,
+    text: &mut String,
+) {
+    let ctrl_id = control::read_ctrl_id(&records[start].data);
+    if ctx.route_region(&records[start].data, |out| {
+        extract_ctrl_subtree(records, start, end, ctx, out)
+    }) {
+        return;
+    }
+    let moved = match ctrl_id {
+        Some(control::CTRL_FOOTNOTE) => ctx.footnotes.as_ref().map(|notes| (notes, "fn")),
+        Some(control::CTRL_ENDNOTE) => ctx.endnotes.as_ref().map(|notes| (notes, "en")),
+        _ => None,
+    };
+    if let Some((notes, prefix)) = moved {
+        let mut note = String::new();
+        extract_ctrl_subtree(records, start, end, ctx, &mut note);
+        let mut notes = notes.borrow_mut();
+        text.push_str(&format!("[{prefix}:{}]", notes.next_number));
+        notes.next_number += 1;
+        notes.texts.push(note.trim_end_matches('\n').to_string());
+        return;
+    }
+    if ctx.opts.floating_objects == FloatingObjects::SectionEnd
+        && control::is_page_anchored(&records[start].data)
+    {
+        let mut object_text = String::new();
+        extract_ctrl_subtree(records, start, end, ctx, &mut object_text);
+        ctx.deferred.borrow_mut().push_str(&object_text);
+        return;
+    }
+    let span = if top_level {
+        let kind = ParaKind::from_ctrl_id(ctrl_id);
+        ctx.begin_span(text.len(), kind)
+    } else {
+        None
+    };
+    extract_ctrl_subtree(records, start, end, ctx, text);
+    ctx.end_span(span, text.len());
+}
+
+/// 필드 컨트롤 서브트리의 CTRL_DATA에서 캐시된 결과 문자열을 읽는다.
+///
+/// 날짜 필드는 명령 문자열이 날짜 형식 코드이면 캐시 값의 날짜를 그 형식으로 다시
+/// 표시한다 (예: `yyyy년 M월 d일` → "2024년 5월 1일"). 캐시 값을 날짜로 읽지 못하면
+/// 캐시 값 그대로 쓴다.
+fn field_cached_text<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    start: usize,
+    end: usize,
+) -> Option<String> {
+    let ctrl_id = control::read_ctrl_id(&records[start].data)?;
+    if !control::is_field(ctrl_id) {
+        return None;
+    }
+    let cached = records[start + 1..end]
+        .iter()
+        .find(|r| r.header.tag_id == record::HWPTAG_CTRL_DATA)
+        .and_then(|r| field::cached_result(&r.data))?;
+    if matches!(
+        ctrl_id,
+        control::CTRL_FIELD_DATE | control::CTRL_FIELD_DOC_DATE
+    ) {
+        let formatted = field::command(&records[start].data)
+            .and_then(|format| field::format_date(&format, &field::parse_date(&cached)?));
+        if let Some(formatted) = formatted {
+            return Some(formatted);
+        }
+    }
+    Some(cached)
+}
+
+/// 링크로 표시할 필드 CTRL_HEADER에서 연결 대상을 읽는다.
+///
+/// 하이퍼링크는 URL 그대로, 상호 참조는 `#대상`으로 돌려준다.
+/// 해당 옵션이 꺼져 있거나 다른 컨트롤이면 `None`.
+fn field_link_target(ctrl_header: &[u8], opts: &ExtractOptions) -> Option<String> {
+    match control::read_ctrl_id(ctrl_header)? {
+        control::CTRL_FIELD_HYPERLINK if opts.include_hyperlinks => {
+            field::hyperlink_target(&field::command(ctrl_header)?)
+        }
+        control::CTRL_FIELD_CROSS_REF if opts.include_cross_references => {
+            let target = field::cross_ref_target(&field::command(ctrl_header)?)?;
+            Some(format!("#{target}"))
+        }
+        _ => None,
+    }
+}
+
+/// 변경 추적 구간에 달린 메모 필드이면 `[검토자: 메모]` 문자열을 만든다.
+///
+/// 문단 영역 태그 가운데 데이터(하위 24비트)가 메모 필드 ID와 같은 것이 있으면 그
+/// 구간에 달린 메모로 본다. 검토자는 필드 명령 문자열, 메모 내용은 서브트리의
+/// MEMO_LIST 뒤 문단들이며 여러 문단이면 공백으로 잇는다. 내용이 비었으면 `None`.
+fn memo_comment<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    start: usize,
+    end: usize,
+    range_tags: &[paragraph::RangeTag],
+    ctx: &WalkContext,
+) -> Option<String> {
+    let ctrl_header = &records[start].data;
+    if range_tags.is_empty() || control::read_ctrl_id(ctrl_header) != Some(control::CTRL_FIELD_MEMO)
+    {
+        return None;
+    }
+    let id = field::field_id(ctrl_header)? & 0x00FF_FFFF;
+    if !range_tags.iter().any(|tag| tag.data == id) {
+        return None;
+    }
+    let memo_list = records[start + 1..end]
+        .iter()
+        .position(|r| r.header.tag_id == record::HWPTAG_MEMO_LIST)?;
+    let mut i = start + 1 + memo_list + 1;
+    let mut memo = String::new();
+    if i < end && records[i].header.tag_id == record::HWPTAG_PARA_HEADER {
+        let para_level = records[i].header.level;
+        extract_para_list_bounded(records, &mut i, para_level, end, ctx, &mut memo);
+    }
+    let memo = memo.split_whitespace().collect::<Vec<_>>().join(" ");
+    if memo.is_empty() {
+        return None;
+    }
+    Some(match field::command(ctrl_header) {
+        Some(reviewer) if !reviewer.trim().is_empty() => format!("[{}: {memo}]", reviewer.trim()),
+        _ => format!("[{memo}]"),
+    })
+}
+
+/// 출력 없는 컨트롤 양옆의 세그먼트를 한 줄기로 잇는다.
+///
+/// 세그먼트는 컨트롤마다 글자 표시(취소선 등)를 닫고 다시 열므로, 컨트롤이 아무것도
+/// 출력하지 않으면 "~~앞~~~~뒤~~"처럼 닫고 여는 표시가 맞붙는다. `text` 끝의 닫는
+/// 표시를 지우고, 다음 세그먼트에서 여는 표시를 뺀 나머지를 돌려준다.
+fn rejoin_marks<'s>(text: &mut String, next: &'s str, marks: &[(u32, &str, &str)]) -> &'s str {
+    for &(_, open, close) in marks {
+        if !open.is_empty() && text.ends_with(close) && next.starts_with(open) {
+            text.truncate(text.len() - close.len());
+            return &next[open.len()..];
+        }
+    }
+    next
 }
 
 /// 컨트롤 서브트리 내의 텍스트 추출 (표 셀, 각주, 텍스트박스 등)
-fn extract_ctrl_subtree(records: &[Record], start: usize, end: usize, text: &mut String) {
+fn extract_ctrl_subtree<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    start: usize,
+    end: usize,
+    ctx: &WalkContext,
+    text: &mut String,
+) {
     // 표 컨트롤이면 마크다운 테이블로 출력
     if let Some(ctrl_id) = control::read_ctrl_id(&records[start].data) {
         if ctrl_id == control::CTRL_TABLE {
-            extract_table_subtree(records, start, end, text);
+            extract_table_subtree(records, start, end, ctx, text);
+            return;
+        }
+        if ctrl_id == control::CTRL_EQUATION {
+            extract_equation_subtree(records, start, end, ctx, text);
             return;
         }
+        if ctrl_id == control::CTRL_FORM && ctx.opts.include_form_values {
+            let field = records[start + 1..end]
+                .iter()
+                .find(|r| r.header.tag_id == record::HWPTAG_FORM_OBJECT)
+                .and_then(|r| form::parse_form_object(&r.data));
+            if let Some(field) = field {
+                text.push_str(&field.label);
+                text.push_str(": ");
+                text.push_str(&field.value);
+                text.push('\n');
+            }
+        }
     }
 
     let mut i = start + 1; // CTRL_HEADER 스킵
@@ -149,22 +1112,44 @@ fn extract_ctrl_subtree(records: &[Record], start: usize, end: usize, text: &mut
             // LIST_HEADER 다음에 PARA_HEADER가 오면 재귀 처리
             if i < end && records[i].header.tag_id == record::HWPTAG_PARA_HEADER {
                 let para_level = records[i].header.level;
-                extract_para_list_bounded(records, &mut i, para_level, end, text);
+                extract_para_list_bounded(records, &mut i, para_level, end, ctx, text);
             }
         } else if rec.header.tag_id == record::HWPTAG_EQEDIT {
             if let Some(script) = extract_eqedit_script(&rec.data) {
                 if !script.is_empty() {
-                    text.push_str(&script);
-                    text.push('\n');
+                    push_equation(&script, ctx.opts, text);
                 }
             }
             i += 1;
+        } else if rec.header.tag_id == record::HWPTAG_SHAPE_COMPONENT_TEXTART {
+            if let Some(art) = extract_textart_text(&rec.data) {
+                text.push_str(&art);
+                text.push('\n');
+            }
+            i += 1;
+        } else if rec.header.tag_id == record::HWPTAG_SHAPE_COMPONENT_PICTURE
+            && ctx.opts.embed_images
+        {
+            push_embedded_image(&rec.data, ctx.bin_data, text);
+            i += 1;
         } else {
             i += 1;
         }
     }
 }
 
+/// 그림 개체가 가리키는 BinData를 `<img>` 태그 한 줄로 덧붙인다.
+fn push_embedded_image(picture: &[u8], bin_data: &[BinData], text: &mut String) {
+    let uri = bindata::picture_bin_id(picture)
+        .and_then(|id| bin_data.iter().find(|b| b.id == id))
+        .and_then(BinData::data_uri);
+    if let Some(uri) = uri {
+        text.push_str("<img src=\"");
+        text.push_str(&uri);
+        text.push_str("\">\n");
+    }
+}
+
 /// TABLE 레코드에서 행/열 수를 파싱한다.
 fn parse_table_dimensions(data: &[u8]) -> Option<(u16, u16)> {
     if data.len() < 8 {
@@ -187,14 +1172,35 @@ fn parse_cell_position(data: &[u8]) -> Option<(u16, u16, u16, u16)> {
     Some((col, row, col_span, row_span))
 }
 
-/// 마크다운 셀 텍스트 이스케이프: 줄바꿈 → 공백, | → \|
-fn escape_markdown_cell(s: &str) -> String {
-    s.replace('|', "\\|").replace('\n', " ")
+/// 셀 LIST_HEADER들의 위치·병합 범위가 덮는 (행 수, 열 수)
+///
+/// 위치를 읽을 수 없는 셀은 세지 않으며, 위치 있는 셀이 없으면 (0, 0)이다.
+fn infer_table_dimensions<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    list_headers: &[usize],
+) -> (u16, u16) {
+    list_headers
+        .iter()
+        .filter_map(|&idx| parse_cell_position(&records[idx].data))
+        .fold((0, 0), |(rows, cols), (col, row, col_span, row_span)| {
+            (
+                rows.max(row.saturating_add(row_span.max(1))),
+                cols.max(col.saturating_add(col_span.max(1))),
+            )
+        })
 }
 
-/// 셀 데이터를 마크다운 테이블 문자열로 포맷한다.
-fn format_markdown_table(cells: &[(u16, u16, String)], rows: u16, cols: u16) -> String {
-    // 2D grid 구성
+/// 셀 내부 줄바꿈을 [`CellNewlines`] 정책에 따라 바꾼다.
+pub(crate) fn replace_cell_newlines(s: &str, newlines: CellNewlines) -> String {
+    match newlines {
+        CellNewlines::Space => s.replace('\n', " "),
+        CellNewlines::LiteralBackslashN => s.replace('\n', "\\n"),
+        CellNewlines::Preserve => s.to_string(),
+    }
+}
+
+/// 셀 데이터를 행 × 열 격자로 배치한다. 범위를 벗어난 셀은 버린다.
+fn cell_grid(cells: &[(u16, u16, String)], rows: u16, cols: u16) -> Vec<Vec<String>> {
     let rows = rows as usize;
     let cols = cols as usize;
     let mut grid: Vec<Vec<String>> = vec![vec![String::new(); cols]; rows];
@@ -206,41 +1212,100 @@ fn format_markdown_table(cells: &[(u16, u16, String)], rows: u16, cols: u16) ->
             grid[r][c] = content.clone();
         }
     }
+    grid
+}
 
-    let mut result = String::new();
-    for (i, row) in grid.iter().enumerate() {
-        result.push('|');
-        for cell in row {
-            let escaped = escape_markdown_cell(cell.trim_end_matches('\n'));
-            result.push(' ');
-            result.push_str(&escaped);
-            result.push_str(" |");
-        }
-        result.push('\n');
+/// 셀 데이터를 마크다운 테이블 문자열로 포맷한다.
+fn format_markdown_table(
+    cells: &[(u16, u16, String)],
+    rows: u16,
+    cols: u16,
+    newlines: CellNewlines,
+) -> String {
+    let mut grid = cell_grid(cells, rows, cols);
+    for cell in grid.iter_mut().flatten() {
+        let trimmed = cell.trim_end_matches('\n').len();
+        cell.truncate(trimmed);
+    }
+    markdown::format_table(&grid, newlines)
+}
 
-        // 첫 행 뒤에 구분선
-        if i == 0 {
-            result.push('|');
-            for _ in 0..cols {
-                result.push_str(" --- |");
+/// 행 목록을 탭 구분 형식(한 행에 한 줄)으로 포맷한다.
+///
+/// 짧은 행은 가장 긴 행의 열 수만큼 빈 셀로 채운다. 셀 안의 탭은 공백으로 바꾼다.
+pub(crate) fn format_tab_separated_rows(rows: &[Vec<String>], newlines: CellNewlines) -> String {
+    let cols = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let mut result = String::new();
+    for row in rows {
+        for j in 0..cols {
+            if j > 0 {
+                result.push('\t');
             }
-            result.push('\n');
+            let cell = row.get(j).map_or("", |s| s.trim_end_matches('\n'));
+            result.push_str(&replace_cell_newlines(&cell.replace('\t', " "), newlines));
         }
+        result.push('\n');
     }
+    result
+}
+
+/// 셀 데이터를 좌표 형식(`R{row}C{col}: text`, 1부터 시작)으로 포맷한다.
+///
+/// 빈 셀은 생략하고 행 → 열 순으로 정렬하여 한 줄에 한 셀씩 출력한다.
+/// 셀 내부 줄바꿈은 정책에 따라 바꾼다 (기본은 공백으로 바꾸어 한 줄을 유지).
+pub(crate) fn format_coordinate_cells(
+    cells: &[(u16, u16, String)],
+    newlines: CellNewlines,
+) -> String {
+    let mut sorted: Vec<&(u16, u16, String)> = cells.iter().collect();
+    sorted.sort_by_key(|(col, row, _)| (*row, *col));
 
+    let mut result = String::new();
+    for (col, row, content) in sorted {
+        let content = content.trim_end_matches('\n');
+        if content.trim().is_empty() {
+            continue;
+        }
+        result.push_str(&format!(
+            "R{}C{}: {}\n",
+            *row as u32 + 1,
+            *col as u32 + 1,
+            replace_cell_newlines(content, newlines)
+        ));
+    }
     result
 }
 
 /// 표 컨트롤 서브트리에서 마크다운 테이블을 추출한다.
-fn extract_table_subtree(records: &[Record], start: usize, end: usize, text: &mut String) {
+fn extract_table_subtree<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    start: usize,
+    end: usize,
+    ctx: &WalkContext,
+    text: &mut String,
+) {
     let mut i = start + 1; // CTRL_HEADER 스킵
 
-    // TABLE 레코드 찾기
+    // TABLE 레코드 찾기 (그 앞의 LIST_HEADER는 캡션)
     let mut rows: u16 = 0;
     let mut cols: u16 = 0;
     let mut found_table = false;
+    let mut caption: Option<(String, u32)> = None;
 
     while i < end {
+        if records[i].header.tag_id == record::HWPTAG_LIST_HEADER
+            && caption.is_none()
+            && ctx.opts.include_table_captions
+        {
+            caption = Some(extract_caption(
+                records,
+                &mut i,
+                end,
+                record::HWPTAG_TABLE,
+                ctx,
+            ));
+            continue;
+        }
         if records[i].header.tag_id == record::HWPTAG_TABLE {
             if let Some((r, c)) = parse_table_dimensions(&records[i].data) {
                 rows = r;
@@ -253,9 +1318,8 @@ fn extract_table_subtree(records: &[Record], start: usize, end: usize, text: &mu
         i += 1;
     }
 
-    if !found_table || rows == 0 || cols == 0 {
-        // fallback: 기존 선형 출력
-        extract_ctrl_subtree_linear(records, start, end, text);
+    if !found_table {
+        extract_ctrl_subtree_linear(records, start, end, ctx, text);
         return;
     }
 
@@ -263,7 +1327,7 @@ fn extract_table_subtree(records: &[Record], start: usize, end: usize, text: &mu
     let list_header_level = if i < end && records[i].header.tag_id == record::HWPTAG_LIST_HEADER {
         records[i].header.level
     } else {
-        extract_ctrl_subtree_linear(records, start, end, text);
+        extract_ctrl_subtree_linear(records, start, end, ctx, text);
         return;
     };
 
@@ -281,6 +1345,32 @@ fn extract_table_subtree(records: &[Record], start: usize, end: usize, text: &mu
         j += 1;
     }
 
+    // 손상된 TABLE 레코드가 행·열 수를 0으로 기록했으면 셀 위치에서 격자 크기를 구한다
+    if rows == 0 || cols == 0 {
+        let (cell_rows, cell_cols) = infer_table_dimensions(records, &list_header_indices);
+        if rows == 0 {
+            rows = cell_rows;
+        }
+        if cols == 0 {
+            cols = cell_cols;
+        }
+    }
+
+    let too_large = ctx
+        .opts
+        .max_table_cells
+        .is_some_and(|max| rows as usize * cols as usize > max);
+    if too_large {
+        ctx.warnings
+            .borrow_mut()
+            .push(Warning::TableTooLarge { rows, cols });
+    }
+    if rows == 0 || cols == 0 || too_large {
+        // fallback: 기존 선형 출력 (손상된 크기로 거대한 격자를 만들지 않도록)
+        extract_ctrl_subtree_linear(records, start, end, ctx, text);
+        return;
+    }
+
     // 각 LIST_HEADER의 셀 범위 결정: 현재 LIST_HEADER ~ 다음 LIST_HEADER (또는 end)
     for (idx, &lh_idx) in list_header_indices.iter().enumerate() {
         let cell_end = if idx + 1 < list_header_indices.len() {
@@ -302,25 +1392,155 @@ fn extract_table_subtree(records: &[Record], start: usize, end: usize, text: &mu
         let mut ci = *lh_idx + 1;
         if ci < *cell_end && records[ci].header.tag_id == record::HWPTAG_PARA_HEADER {
             let para_level = records[ci].header.level;
-            extract_para_list_bounded(records, &mut ci, para_level, *cell_end, &mut cell_text);
+            extract_para_list_bounded(records, &mut ci, para_level, *cell_end, ctx, &mut cell_text);
         }
 
         if let Some((col, row, _, _)) = cell_pos {
             cells.push((col, row, cell_text));
         } else {
             let idx = cells.len() as u16;
-            let row_idx = if cols > 0 { idx / cols } else { 0 };
-            let col_idx = if cols > 0 { idx % cols } else { 0 };
+            let row_idx = idx.checked_div(cols).unwrap_or(0);
+            let col_idx = idx.checked_rem(cols).unwrap_or(0);
             cells.push((col_idx, row_idx, cell_text));
         }
     }
 
-    let table_str = format_markdown_table(&cells, rows, cols);
+    let table_str = match ctx.opts.table_mode {
+        TableMode::Markdown => format_markdown_table(&cells, rows, cols, ctx.opts.cell_newlines),
+        TableMode::Coordinates => format_coordinate_cells(&cells, ctx.opts.cell_newlines),
+        TableMode::TabSeparated => {
+            format_tab_separated_rows(&cell_grid(&cells, rows, cols), ctx.opts.cell_newlines)
+        }
+    };
+    let caption = caption.filter(|(c, _)| !c.is_empty());
+    let above = |direction: &u32| *direction == CAPTION_TOP;
+    if let Some((c, _)) = caption.as_ref().filter(|(_, d)| above(d)) {
+        text.push_str(c);
+        text.push('\n');
+    }
     text.push_str(&table_str);
+    if let Some((c, _)) = caption.as_ref().filter(|(_, d)| !above(d)) {
+        text.push_str(c);
+        text.push('\n');
+    }
+}
+
+/// 캡션 방향: 왼쪽
+const CAPTION_LEFT: u32 = 0;
+/// 캡션 방향: 위
+const CAPTION_TOP: u32 = 2;
+
+/// 표·수식 캡션 문단 리스트를 추출한다. `pos`는 캡션 LIST_HEADER를 가리키며,
+/// 개체 본문 레코드(`body_tag`: TABLE, EQEDIT 등, 없으면 `end`) 앞으로 이동한다.
+///
+/// 캡션 LIST_HEADER 구조: 문단 리스트 공통 헤더(8) + 캡션 속성(4, bit 0~1 방향:
+/// 0 = 왼쪽, 1 = 오른쪽, 2 = 위, 3 = 아래) + ...
+/// 반환: (줄바꿈을 공백으로 합친 캡션 텍스트, 방향)
+fn extract_caption<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    pos: &mut usize,
+    end: usize,
+    body_tag: u16,
+    ctx: &WalkContext,
+) -> (String, u32) {
+    let header = &records[*pos];
+    let direction = header
+        .data
+        .get(8..12)
+        .map_or(0, |b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) & 0x3);
+    let caption_end = records[*pos..end]
+        .iter()
+        .position(|r| r.header.tag_id == body_tag)
+        .map_or(end, |off| *pos + off);
+
+    *pos += 1;
+    let mut caption = String::new();
+    if *pos < caption_end && records[*pos].header.tag_id == record::HWPTAG_PARA_HEADER {
+        let para_level = records[*pos].header.level;
+        extract_para_list_bounded(records, pos, para_level, caption_end, ctx, &mut caption);
+    }
+    *pos = caption_end;
+    let caption = caption
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (caption, direction)
+}
+
+/// 수식 컨트롤 서브트리에서 스크립트와 캡션(보통 "(3.1)" 같은 수식 번호)을 한 줄로 출력한다.
+///
+/// 캡션은 왼쪽·위 캡션이면 스크립트 앞에, 오른쪽·아래 캡션이면 뒤에 공백을 두고 붙인다.
+fn extract_equation_subtree<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    start: usize,
+    end: usize,
+    ctx: &WalkContext,
+    text: &mut String,
+) {
+    let mut caption: Option<(String, u32)> = None;
+    let mut script: Option<String> = None;
+    let mut i = start + 1; // CTRL_HEADER 스킵
+    while i < end {
+        let rec = &records[i];
+        if rec.header.tag_id == record::HWPTAG_LIST_HEADER && caption.is_none() {
+            caption = Some(extract_caption(
+                records,
+                &mut i,
+                end,
+                record::HWPTAG_EQEDIT,
+                ctx,
+            ));
+            continue;
+        }
+        if rec.header.tag_id == record::HWPTAG_EQEDIT && script.is_none() {
+            script = extract_eqedit_script(&rec.data).filter(|s| !s.is_empty());
+        }
+        i += 1;
+    }
+
+    let caption = caption.filter(|(c, _)| !c.is_empty());
+    let Some(script) = script else {
+        if let Some((c, _)) = caption {
+            text.push_str(&c);
+            text.push('\n');
+        }
+        return;
+    };
+    let before = |direction: &u32| matches!(*direction, CAPTION_LEFT | CAPTION_TOP);
+    if let Some((c, _)) = caption.as_ref().filter(|(_, d)| before(d)) {
+        text.push_str(c);
+        text.push(' ');
+    }
+    let (open, close) = &ctx.opts.equation_delimiters;
+    text.push_str(open);
+    text.push_str(&script);
+    text.push_str(close);
+    if let Some((c, _)) = caption.as_ref().filter(|(_, d)| !before(d)) {
+        text.push(' ');
+        text.push_str(c);
+    }
+    text.push('\n');
+}
+
+/// 수식 스크립트를 [`ExtractOptions::equation_delimiters`]로 감싸 한 줄로 출력한다.
+fn push_equation(script: &str, opts: &ExtractOptions, text: &mut String) {
+    let (open, close) = &opts.equation_delimiters;
+    text.push_str(open);
+    text.push_str(script);
+    text.push_str(close);
+    text.push('\n');
 }
 
 /// 표가 아닌 컨트롤의 선형 텍스트 추출 (fallback)
-fn extract_ctrl_subtree_linear(records: &[Record], start: usize, end: usize, text: &mut String) {
+fn extract_ctrl_subtree_linear<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
+    start: usize,
+    end: usize,
+    ctx: &WalkContext,
+    text: &mut String,
+) {
     let mut i = start + 1;
     while i < end {
         let rec = &records[i];
@@ -328,13 +1548,12 @@ fn extract_ctrl_subtree_linear(records: &[Record], start: usize, end: usize, tex
             i += 1;
             if i < end && records[i].header.tag_id == record::HWPTAG_PARA_HEADER {
                 let para_level = records[i].header.level;
-                extract_para_list_bounded(records, &mut i, para_level, end, text);
+                extract_para_list_bounded(records, &mut i, para_level, end, ctx, text);
             }
         } else if rec.header.tag_id == record::HWPTAG_EQEDIT {
             if let Some(script) = extract_eqedit_script(&rec.data) {
                 if !script.is_empty() {
-                    text.push_str(&script);
-                    text.push('\n');
+                    push_equation(&script, ctx.opts, text);
                 }
             }
             i += 1;
@@ -345,11 +1564,12 @@ fn extract_ctrl_subtree_linear(records: &[Record], start: usize, end: usize, tex
 }
 
 /// extract_para_list의 bounded 버전: end 인덱스까지만 처리
-fn extract_para_list_bounded(
-    records: &[Record],
+fn extract_para_list_bounded<D: Deref<Target = [u8]>>(
+    records: &[Record<D>],
     pos: &mut usize,
     base_level: u16,
     end: usize,
+    ctx: &WalkContext,
     text: &mut String,
 ) {
     while *pos < end {
@@ -358,7 +1578,7 @@ fn extract_para_list_bounded(
             break;
         }
         if rec.header.tag_id == record::HWPTAG_PARA_HEADER && rec.header.level == base_level {
-            extract_para(records, pos, base_level, text);
+            extract_para(records, pos, base_level, ctx, text);
             if *pos > end {
                 *pos = end;
             }
@@ -368,6 +1588,17 @@ fn extract_para_list_bounded(
     }
 }
 
+/// 글맵시(SHAPE_COMPONENT_TEXTART) 레코드에서 표시 문자열을 추출한다.
+///
+/// 구조: 내용(WORD 길이 + WCHAR) + 글꼴 이름 + 글꼴 스타일 + 글꼴 형식(4) + 모양(4) + ...
+/// 줄바꿈은 그대로 두고, 앞뒤 공백뿐이면 `None`.
+fn extract_textart_text(data: &[u8]) -> Option<String> {
+    let mut cursor = data;
+    let text = docinfo::read_wstring(&mut cursor)?;
+    let text = text.trim_matches(|c: char| c.is_whitespace() || c == '\0');
+    (!text.is_empty()).then(|| text.replace("\r\n", "\n"))
+}
+
 /// EQEDIT 레코드에서 수식 스크립트 텍스트를 추출한다.
 fn extract_eqedit_script(data: &[u8]) -> Option<String> {
     if data.len() < 6 {
@@ -401,6 +1632,7 @@ fn extract_eqedit_script(data: &[u8]) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::{rec, utf16le};
 
     #[test]
     fn test_extract_eqedit_script() {
@@ -536,37 +1768,1227 @@ mod tests {
     }
 
     #[test]
-    fn test_escape_markdown_cell_pipe() {
-        assert_eq!(escape_markdown_cell("a|b"), "a\\|b");
+    fn test_format_markdown_table_basic() {
+        let cells = vec![
+            (0u16, 0u16, "A".to_string()),
+            (1, 0, "B".to_string()),
+            (0, 1, "C".to_string()),
+            (1, 1, "D".to_string()),
+        ];
+        let table = format_markdown_table(&cells, 2, 2, CellNewlines::Space);
+        assert!(table.contains("| A |"));
+        assert!(table.contains("| --- |"));
+        assert!(table.contains("| C |"));
+    }
+
+    /// 표 컨트롤 하나를 담은 문단 레코드 생성: cells = (col, row, 텍스트)
+    fn table_records(rows: u16, cols: u16, cells: &[(u16, u16, &str)]) -> Vec<Record> {
+        let mut pt = vec![0x0B, 0x00]; // code 11 (table)
+        pt.extend_from_slice(&[0u8; 14]);
+        let mut table = vec![0u8; 4];
+        table.extend_from_slice(&rows.to_le_bytes());
+        table.extend_from_slice(&cols.to_le_bytes());
+
+        let mut records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, pt),
+            rec(
+                record::HWPTAG_CTRL_HEADER,
+                1,
+                control::CTRL_TABLE.to_le_bytes().to_vec(),
+            ),
+            rec(record::HWPTAG_TABLE, 2, table),
+        ];
+        for &(col, row, cell_text) in cells {
+            let mut lh = vec![0u8; 8];
+            for v in [col, row, 1, 1] {
+                lh.extend_from_slice(&v.to_le_bytes());
+            }
+            records.push(rec(record::HWPTAG_LIST_HEADER, 2, lh));
+            records.push(rec(record::HWPTAG_PARA_HEADER, 2, vec![]));
+            records.push(rec(record::HWPTAG_PARA_TEXT, 3, utf16le(cell_text)));
+        }
+        records
     }
 
     #[test]
-    fn test_escape_markdown_cell_newline() {
-        assert_eq!(escape_markdown_cell("line1\nline2"), "line1 line2");
+    fn test_extract_table_coordinates() {
+        let records = table_records(2, 2, &[(0, 0, "이름"), (1, 0, "값"), (1, 1, "42")]);
+        let opts = ExtractOptions {
+            table_mode: TableMode::Coordinates,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_options(&records, &opts, &mut text);
+        assert!(
+            text.contains("R1C1: 이름\nR1C2: 값\nR2C2: 42\n"),
+            "got: {text:?}"
+        );
+        assert!(!text.contains('|'));
     }
 
     #[test]
-    fn test_escape_markdown_cell_empty() {
-        assert_eq!(escape_markdown_cell(""), "");
+    fn test_oversized_table_warns() {
+        let records = table_records(60_000, 60_000, &[(0, 0, "가"), (1, 0, "나")]);
+        let mut text = String::new();
+        let warnings = extract_section_text_with_warnings(
+            &records,
+            &DocInfo::default(),
+            &ExtractOptions::default(),
+            &mut text,
+        );
+        assert_eq!(text, "가\n나\n");
+        assert_eq!(
+            warnings,
+            [Warning::TableTooLarge {
+                rows: 60_000,
+                cols: 60_000
+            }]
+        );
+
+        // 한도 안의 표는 경고 없음
+        let records = table_records(1, 2, &[(0, 0, "가"), (1, 0, "나")]);
+        let mut text = String::new();
+        let warnings = extract_section_text_with_warnings(
+            &records,
+            &DocInfo::default(),
+            &ExtractOptions::default(),
+            &mut text,
+        );
+        assert!(warnings.is_empty());
     }
 
     #[test]
-    fn test_escape_markdown_cell_combined() {
-        assert_eq!(escape_markdown_cell("a|b\nc"), "a\\|b c");
+    fn test_oversized_table_falls_back_to_linear() {
+        let records = table_records(60_000, 60_000, &[(0, 0, "가"), (1, 0, "나")]);
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert_eq!(text, "가\n나\n");
+
+        // 한도를 넘지 않으면 격자로 출력
+        let opts = ExtractOptions {
+            max_table_cells: Some(4),
+            ..Default::default()
+        };
+        let records = table_records(1, 2, &[(0, 0, "가"), (1, 0, "나")]);
+        let mut text = String::new();
+        extract_section_text_with_options(&records, &opts, &mut text);
+        assert!(text.starts_with("| 가 | 나 |"), "got: {text:?}");
     }
 
     #[test]
-    fn test_format_markdown_table_basic() {
+    fn test_zero_dimension_table_infers_grid() {
+        let cells = [(0, 0, "이름"), (1, 0, "값"), (0, 1, "가"), (1, 1, "42")];
+        let mut text = String::new();
+        extract_section_text(&table_records(0, 0, &cells), &mut text);
+        assert_eq!(text, "| 이름 | 값 |\n| --- | --- |\n| 가 | 42 |\n");
+
+        // 한쪽만 0이면 그쪽만 셀 위치로 채운다
+        let mut text = String::new();
+        extract_section_text(&table_records(2, 0, &cells), &mut text);
+        assert_eq!(text, "| 이름 | 값 |\n| --- | --- |\n| 가 | 42 |\n");
+
+        // 셀도 없으면 선형 출력 (빈 표)
+        let mut text = String::new();
+        extract_section_text(&table_records(0, 0, &[]), &mut text);
+        assert_eq!(text, "\n");
+    }
+
+    #[test]
+    fn test_cell_newline_policies() {
+        let records = table_records(2, 2, &[(0, 0, "가\n나"), (1, 0, "다"), (0, 1, "라")]);
+        let render = |table_mode, cell_newlines| {
+            let opts = ExtractOptions {
+                table_mode,
+                cell_newlines,
+                ..Default::default()
+            };
+            let mut text = String::new();
+            extract_section_text_with_options(&records, &opts, &mut text);
+            text
+        };
+
+        let tsv = TableMode::TabSeparated;
+        assert_eq!(render(tsv, CellNewlines::Space), "가 나\t다\n라\t\n");
+        assert_eq!(
+            render(tsv, CellNewlines::LiteralBackslashN),
+            "가\\n나\t다\n라\t\n"
+        );
+        assert_eq!(render(tsv, CellNewlines::Preserve), "가\n나\t다\n라\t\n");
+
+        let md = render(TableMode::Markdown, CellNewlines::LiteralBackslashN);
+        assert!(md.starts_with("| 가\\n나 | 다 |"), "got: {md:?}");
+        let coords = render(TableMode::Coordinates, CellNewlines::LiteralBackslashN);
+        assert!(coords.starts_with("R1C1: 가\\n나\n"), "got: {coords:?}");
+    }
+
+    #[test]
+    fn test_table_caption() {
+        let captioned = |direction: u32| {
+            let mut records = table_records(1, 2, &[(0, 0, "가"), (1, 0, "나")]);
+            let mut lh = vec![0u8; 8];
+            lh.extend_from_slice(&direction.to_le_bytes());
+            records.splice(
+                3..3,
+                [
+                    rec(record::HWPTAG_LIST_HEADER, 2, lh),
+                    rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+                    rec(record::HWPTAG_PARA_TEXT, 3, utf16le("표 1. 매출")),
+                ],
+            );
+            records
+        };
+        let opts = ExtractOptions {
+            include_table_captions: true,
+            ..Default::default()
+        };
+        let table = "| 가 | 나 |\n| --- | --- |\n";
+
+        let mut text = String::new();
+        extract_section_text_with_options(&captioned(3), &opts, &mut text);
+        assert_eq!(text, format!("{table}표 1. 매출\n"));
+
+        let mut text = String::new();
+        extract_section_text_with_options(&captioned(2), &opts, &mut text);
+        assert_eq!(text, format!("표 1. 매출\n{table}"));
+
+        // 옵션이 꺼져 있으면 캡션 생략
+        let mut text = String::new();
+        extract_section_text(&captioned(3), &mut text);
+        assert_eq!(text, table);
+    }
+
+    #[test]
+    fn test_control_only_paragraph_has_no_blank_line() {
+        let mut records = table_records(1, 1, &[(0, 0, "셀")]);
+        records.push(rec(record::HWPTAG_PARA_HEADER, 0, vec![]));
+        records.push(rec(record::HWPTAG_PARA_TEXT, 1, utf16le("다음")));
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert_eq!(text, "| 셀 |\n| --- |\n다음\n");
+
+        // 텍스트가 있는 문단은 그대로 문단 끝 줄바꿈을 붙인다
+        let mut records = table_records(1, 1, &[(0, 0, "셀")]);
+        let mut pt = utf16le("앞");
+        pt.extend_from_slice(&records[1].data);
+        records[1] = rec(record::HWPTAG_PARA_TEXT, 1, pt);
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert_eq!(text, "앞| 셀 |\n| --- |\n\n");
+    }
+
+    #[test]
+    fn test_spans_line_up_with_text() {
+        let mut records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le("첫 문단")),
+        ];
+        records.extend(table_records(1, 2, &[(0, 0, "가"), (1, 0, "나")]));
+        records.push(rec(record::HWPTAG_PARA_HEADER, 0, vec![]));
+        records.push(rec(record::HWPTAG_PARA_TEXT, 1, utf16le("끝")));
+
+        let mut text = String::from("앞\n");
+        let mut spans = Vec::new();
+        extract_section_text_with_spans(
+            &records,
+            &DocInfo::default(),
+            &ExtractOptions::default(),
+            &mut text,
+            &mut spans,
+        );
+
+        let kinds: Vec<ParaKind> = spans.iter().map(|s| s.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                ParaKind::Body,
+                ParaKind::Body,
+                ParaKind::Table,
+                ParaKind::Body
+            ]
+        );
+        // 오프셋은 기존 내용 뒤부터, 본문 범위는 끝까지 이어진다
+        assert_eq!(spans[0].start, "앞\n".len());
+        assert_eq!(&text[spans[0].start..spans[0].end], "첫 문단\n");
+        assert_eq!(spans[1].end, spans[3].start);
+        assert_eq!(&text[spans[3].start..spans[3].end], "끝\n");
+        assert_eq!(spans[3].end, text.len());
+
+        let table = &text[spans[2].start..spans[2].end];
+        assert!(table.starts_with("| 가 | 나 |"), "got: {table:?}");
+        assert!(spans[1].start <= spans[2].start && spans[2].end <= spans[1].end);
+    }
+
+    /// "A" + 폭 없는 탭 + "B" 문단. `secd_tab`이 있으면 구역 정의 컨트롤을 앞에 둔다.
+    fn tab_paragraph(secd_tab: Option<u32>) -> Vec<Record> {
+        let mut pt = vec![];
+        let mut records = vec![rec(record::HWPTAG_PARA_HEADER, 0, vec![])];
+        let mut ctrls = vec![];
+        if let Some(width) = secd_tab {
+            pt.extend_from_slice(&[0x02, 0x00]); // code 2 (구역/단 정의)
+            pt.extend_from_slice(&[0u8; 14]);
+            let mut secd = control::CTRL_SECTION_DEF.to_le_bytes().to_vec();
+            secd.extend_from_slice(&[0u8; 10]);
+            secd.extend_from_slice(&width.to_le_bytes());
+            ctrls.push(rec(record::HWPTAG_CTRL_HEADER, 1, secd));
+        }
+        pt.extend(utf16le("A"));
+        pt.extend_from_slice(&[0x09, 0x00]);
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend(utf16le("B"));
+        records.push(rec(record::HWPTAG_PARA_TEXT, 1, pt));
+        records.extend(ctrls);
+        records
+    }
+
+    #[test]
+    fn test_expand_tabs_uses_default_tab_width() {
+        let opts = ExtractOptions {
+            expand_tabs: true,
+            ..Default::default()
+        };
+        let doc_info = DocInfo {
+            section_count: 1,
+            ..Default::default()
+        };
+
+        // 구역 정의가 없으면 한/글 기본값 (40pt)
+        let mut text = String::new();
+        extract_section_text_with_doc_info(&tab_paragraph(None), &doc_info, &opts, &mut text);
+        assert_eq!(text, "A    B\n");
+
+        // 구역 정의의 기본 탭 간격이 우선
+        let mut text = String::new();
+        extract_section_text_with_doc_info(&tab_paragraph(Some(6000)), &doc_info, &opts, &mut text);
+        assert_eq!(text, "A      B\n");
+
+        // 옵션이 꺼져 있으면 탭 유지
+        let mut text = String::new();
+        extract_section_text(&tab_paragraph(Some(6000)), &mut text);
+        assert_eq!(text, "A\tB\n");
+    }
+
+    /// "작성일: " + 날짜 필드(캐시 값 "2024-05-01") 문단. `shown`은 필드 구간에 표시된 문자열.
+    fn date_field_paragraph(shown: &str) -> Vec<Record> {
+        date_field_paragraph_with_format(shown, None)
+    }
+
+    /// [`date_field_paragraph`]에 필드 명령(날짜 형식 코드)을 더한 문단
+    fn date_field_paragraph_with_format(shown: &str, format: Option<&str>) -> Vec<Record> {
+        let mut header = control::CTRL_FIELD_DATE.to_le_bytes().to_vec();
+        if let Some(format) = format {
+            header.extend_from_slice(&[0u8; 5]);
+            header.extend_from_slice(&(format.encode_utf16().count() as u16).to_le_bytes());
+            header.extend(utf16le(format));
+        }
+        let mut pt = utf16le("작성일: ");
+        pt.extend_from_slice(&[0x03, 0x00]); // 필드 시작
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend(utf16le(shown));
+        pt.extend_from_slice(&[0x04, 0x00]); // 필드 끝
+        pt.extend_from_slice(&[0u8; 14]);
+
+        let mut param_set = vec![0u8, 0, 1, 0]; // ID 0, 아이템 1개
+        param_set.extend_from_slice(&[0x00, 0x40, 0x01, 0x00]); // PIT_BSTR
+        param_set.extend_from_slice(&10u16.to_le_bytes());
+        param_set.extend(utf16le("2024-05-01"));
+
+        vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, pt),
+            rec(record::HWPTAG_CTRL_HEADER, 1, header),
+            rec(record::HWPTAG_CTRL_DATA, 2, param_set),
+        ]
+    }
+
+    #[test]
+    fn test_expand_fields_cached_date() {
+        let opts = ExtractOptions {
+            expand_fields: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_options(&date_field_paragraph(""), &opts, &mut text);
+        assert_eq!(text, "작성일: 2024-05-01\n");
+
+        // 옵션이 꺼져 있으면 기존 출력 유지
+        let mut text = String::new();
+        extract_section_text(&date_field_paragraph(""), &mut text);
+        assert_eq!(text, "작성일: \n");
+
+        // 본문에 이미 표시된 값이 있으면 중복 출력하지 않음
+        let mut text = String::new();
+        extract_section_text_with_options(&date_field_paragraph("5월 1일"), &opts, &mut text);
+        assert_eq!(text, "작성일: 5월 1일\n");
+    }
+
+    #[test]
+    fn test_expand_fields_korean_date_format() {
+        let opts = ExtractOptions {
+            expand_fields: true,
+            ..Default::default()
+        };
+        let records = date_field_paragraph_with_format("", Some("yyyy년 M월 d일 dddd"));
+        let mut text = String::new();
+        extract_section_text_with_options(&records, &opts, &mut text);
+        assert_eq!(text, "작성일: 2024년 5월 1일 수요일\n");
+
+        // 형식 코드가 아닌 명령은 캐시 값 그대로
+        let records = date_field_paragraph_with_format("", Some("AUTO"));
+        let mut text = String::new();
+        extract_section_text_with_options(&records, &opts, &mut text);
+        assert_eq!(text, "작성일: 2024-05-01\n");
+    }
+
+    #[test]
+    fn test_include_hyperlinks() {
+        let mut pt = utf16le("참고: ");
+        pt.extend_from_slice(&[0x03, 0x00]); // 필드 시작
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend(utf16le("한컴"));
+        pt.extend_from_slice(&[0x04, 0x00]); // 필드 끝
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend(utf16le(" 끝"));
+
+        let command = "https\\://www.hancom.com;1;0;0;";
+        let mut header = control::CTRL_FIELD_HYPERLINK.to_le_bytes().to_vec();
+        header.extend_from_slice(&[0u8; 5]);
+        header.extend_from_slice(&(command.encode_utf16().count() as u16).to_le_bytes());
+        header.extend(utf16le(command));
+
+        let records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, pt),
+            rec(record::HWPTAG_CTRL_HEADER, 1, header),
+        ];
+        let opts = ExtractOptions {
+            include_hyperlinks: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_options(&records, &opts, &mut text);
+        assert_eq!(text, "참고: [한컴](https://www.hancom.com) 끝\n");
+
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert_eq!(text, "참고: 한컴 끝\n");
+    }
+
+    /// 메모(검토 의견)가 달린 변경 추적 문단. 영역 태그 데이터가 `tag_id`
+    fn change_comment_paragraph(tag_id: u32) -> Vec<Record> {
+        let mut pt = utf16le("문서를 ");
+        pt.extend_from_slice(&[0x03, 0x00]); // 필드 시작
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend(utf16le("고친 문장"));
+        pt.extend_from_slice(&[0x04, 0x00]); // 필드 끝
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend(utf16le(" 끝"));
+
+        // 변경 구간: "문서를 "(4) 뒤 필드 시작(8) + "고친 문장"(5)
+        let range_tag = [4u32, 17, (1 << 24) | tag_id]
+            .map(u32::to_le_bytes)
+            .concat();
+        let mut header = control::CTRL_FIELD_MEMO.to_le_bytes().to_vec();
+        header.extend_from_slice(&[0u8; 5]);
+        header.extend_from_slice(&3u16.to_le_bytes());
+        header.extend(utf16le("김검토"));
+        header.extend_from_slice(&7u32.to_le_bytes());
+
+        vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, pt),
+            rec(record::HWPTAG_PARA_RANGE_TAG, 1, range_tag),
+            rec(record::HWPTAG_CTRL_HEADER, 1, header),
+            rec(record::HWPTAG_MEMO_LIST, 2, vec![]),
+            rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 3, utf16le("근거를")),
+            rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 3, utf16le("보충할 것")),
+        ]
+    }
+
+    #[test]
+    fn test_include_change_comments() {
+        let opts = ExtractOptions {
+            include_change_comments: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_options(&change_comment_paragraph(7), &opts, &mut text);
+        assert_eq!(text, "문서를 고친 문장[김검토: 근거를 보충할 것] 끝\n");
+
+        // 메모 ID와 다른 영역 태그에는 붙이지 않는다
+        let mut text = String::new();
+        extract_section_text_with_options(&change_comment_paragraph(8), &opts, &mut text);
+        assert_eq!(text, "문서를 고친 문장 끝\n");
+
+        let mut text = String::new();
+        extract_section_text(&change_comment_paragraph(7), &mut text);
+        assert_eq!(text, "문서를 고친 문장 끝\n");
+    }
+
+    #[test]
+    fn test_include_cross_references() {
+        let mut pt = utf16le("자세한 내용은 ");
+        pt.extend_from_slice(&[0x03, 0x00]); // 필드 시작
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend(utf16le("그림 3"));
+        pt.extend_from_slice(&[0x04, 0x00]); // 필드 끝
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend(utf16le(" 참고"));
+
+        let command = "?#조직도;0;1;";
+        let mut header = control::CTRL_FIELD_CROSS_REF.to_le_bytes().to_vec();
+        header.extend_from_slice(&[0u8; 5]);
+        header.extend_from_slice(&(command.encode_utf16().count() as u16).to_le_bytes());
+        header.extend(utf16le(command));
+
+        let records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, pt),
+            rec(record::HWPTAG_CTRL_HEADER, 1, header),
+        ];
+        let opts = ExtractOptions {
+            include_cross_references: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_options(&records, &opts, &mut text);
+        assert_eq!(text, "자세한 내용은 [그림 3](#조직도) 참고\n");
+
+        // 하이퍼링크 옵션만으로는 상호 참조를 링크로 만들지 않는다
+        let opts = ExtractOptions {
+            include_hyperlinks: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_options(&records, &opts, &mut text);
+        assert_eq!(text, "자세한 내용은 그림 3 참고\n");
+    }
+
+    #[test]
+    fn test_indent_paragraphs() {
+        let doc_info = DocInfo {
+            para_shapes: vec![
+                ParaShape::default(),
+                ParaShape {
+                    left_margin: 4000,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let records = vec![
+            rec(
+                record::HWPTAG_PARA_HEADER,
+                0,
+                crate::test_util::para_header_bytes(0, 0),
+            ),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le("본문")),
+            rec(
+                record::HWPTAG_PARA_HEADER,
+                0,
+                crate::test_util::para_header_bytes(1, 0),
+            ),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le("인용")),
+        ];
+        let opts = ExtractOptions {
+            indent_paragraphs: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut text);
+        assert_eq!(text, "본문\n    인용\n");
+
+        let mut text = String::new();
+        extract_section_text_with_doc_info(
+            &records,
+            &doc_info,
+            &ExtractOptions::default(),
+            &mut text,
+        );
+        assert_eq!(text, "본문\n인용\n");
+    }
+
+    #[test]
+    fn test_list_markers_continue_numbering() {
+        let numbered = ParaShape {
+            attr1: 2 << 23,
+            numbering_id: 1,
+            ..Default::default()
+        };
+        let doc_info = DocInfo {
+            para_shapes: vec![ParaShape::default(), numbered],
+            ..Default::default()
+        };
+        let para = |shape: u16, s: &str| {
+            vec![
+                rec(
+                    record::HWPTAG_PARA_HEADER,
+                    0,
+                    crate::test_util::para_header_bytes(shape, 0),
+                ),
+                rec(record::HWPTAG_PARA_TEXT, 1, utf16le(s)),
+            ]
+        };
+        let records: Vec<Record> = [
+            para(1, "가"),
+            para(1, "나"),
+            para(1, "다"),
+            para(0, "본문"),
+            para(1, "라"),
+        ]
+        .concat();
+        let opts = ExtractOptions {
+            list_markers: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut text);
+        assert_eq!(text, "1. 가\n2. 나\n3. 다\n본문\n1. 라\n");
+    }
+
+    #[test]
+    fn test_strikethrough_markers() {
+        let doc_info = DocInfo {
+            char_shapes: vec![
+                CharShape::default(),
+                CharShape {
+                    attr: 1 << 18,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let runs = [0u32, 0, 4, 1, 6, 0].map(u32::to_le_bytes).concat();
+        let records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le("계약금 삭제 완료")),
+            rec(record::HWPTAG_PARA_CHAR_SHAPE, 1, runs),
+        ];
+        let opts = ExtractOptions {
+            strikethrough_markers: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut text);
+        assert_eq!(text, "계약금 ~~삭제~~ 완료\n");
+
+        // 옵션이 꺼져 있으면 표시하지 않는다
+        let mut plain = String::new();
+        extract_section_text_with_doc_info(
+            &records,
+            &doc_info,
+            &ExtractOptions::default(),
+            &mut plain,
+        );
+        assert_eq!(plain, "계약금 삭제 완료\n");
+    }
+
+    #[test]
+    fn test_underline_and_highlight_markers() {
+        let doc_info = DocInfo {
+            char_shapes: vec![
+                CharShape::default(),
+                CharShape {
+                    attr: 1 << 2,
+                    ..Default::default()
+                },
+                CharShape {
+                    shade_color: Some(0x0000_FFFF),
+                    ..Default::default()
+                },
+                CharShape {
+                    attr: (1 << 2) | (1 << 18),
+                    shade_color: Some(0x0000_FFFF),
+                },
+            ],
+            ..Default::default()
+        };
+        // "밑줄" 밑줄, "강조" 음영, "모두" 밑줄+음영+취소선
+        let runs = [0u32, 1, 2, 0, 3, 2, 5, 0, 6, 3]
+            .map(u32::to_le_bytes)
+            .concat();
+        let records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le("밑줄 강조 모두")),
+            rec(record::HWPTAG_PARA_CHAR_SHAPE, 1, runs),
+        ];
+        let opts = ExtractOptions {
+            underline_markers: Some(("<u>".into(), "</u>".into())),
+            highlight_markers: Some(("==".into(), "==".into())),
+            strikethrough_markers: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut text);
+        assert_eq!(text, "<u>밑줄</u> ==강조== ~~<u>==모두==</u>~~\n");
+
+        // 켜진 표시만 붙는다
+        let opts = ExtractOptions {
+            underline_markers: Some(("<u>".into(), "</u>".into())),
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut text);
+        assert_eq!(text, "<u>밑줄</u> 강조 <u>모두</u>\n");
+
+        let mut plain = String::new();
+        let opts = ExtractOptions::default();
+        extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut plain);
+        assert_eq!(plain, "밑줄 강조 모두\n");
+    }
+
+    /// 컨트롤 하나를 앵커한 "앞" 문단: 컨트롤 서브트리 레코드는 `children` (레벨 2부터)
+    fn ctrl_paragraph(ctrl_id: u32, children: Vec<Record>) -> Vec<Record> {
+        let mut pt = utf16le("앞");
+        pt.extend_from_slice(&[0x0B, 0x00]);
+        pt.extend_from_slice(&[0u8; 14]);
+        let mut records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, pt),
+            rec(
+                record::HWPTAG_CTRL_HEADER,
+                1,
+                ctrl_id.to_le_bytes().to_vec(),
+            ),
+        ];
+        records.extend(children);
+        records
+    }
+
+    #[test]
+    fn test_visual_line_breaks() {
+        let line_seg = |starts: &[u32]| {
+            starts
+                .iter()
+                .flat_map(|start| {
+                    let mut item = start.to_le_bytes().to_vec();
+                    item.extend_from_slice(&[0u8; 32]);
+                    item
+                })
+                .collect::<Vec<_>>()
+        };
+        // "한 줄에 다 들어가지 않는 문단" 이 "않는" 앞에서 줄바뀜
+        let body = "한 줄에 다 들어가지 않는 문단";
+        let wrap = body[..body.find("않는").unwrap()].encode_utf16().count() as u32;
+        let records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le(body)),
+            rec(record::HWPTAG_PARA_LINE_SEG, 1, line_seg(&[0, wrap])),
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le("줄 정보 없음")),
+        ];
+        let opts = ExtractOptions {
+            visual_line_breaks: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_options(&records, &opts, &mut text);
+        assert_eq!(text, "한 줄에 다 들어가지 \n않는 문단\n줄 정보 없음\n");
+
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert_eq!(text, "한 줄에 다 들어가지 않는 문단\n줄 정보 없음\n");
+    }
+
+    #[test]
+    fn test_level_jumped_para_text() {
+        let records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 2, utf16le("건너뛴 레벨")),
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 3, utf16le("깊은 쪽")),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le("제 레벨")),
+        ];
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert_eq!(text, "건너뛴 레벨\n제 레벨\n");
+    }
+
+    #[test]
+    fn test_silent_control_joins_segments() {
+        let doc_info = DocInfo {
+            char_shapes: vec![
+                CharShape::default(),
+                CharShape {
+                    attr: 1 << 18,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        // "삭제" + 구역 정의 컨트롤(출력 없음) + "문구", 전체 취소선
+        let mut pt = utf16le("삭제");
+        pt.extend_from_slice(&[0x02, 0x00]);
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend(utf16le("문구"));
+        let records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, pt),
+            rec(
+                record::HWPTAG_PARA_CHAR_SHAPE,
+                1,
+                [0u32, 1].map(u32::to_le_bytes).concat(),
+            ),
+            rec(
+                record::HWPTAG_CTRL_HEADER,
+                1,
+                control::CTRL_SECTION_DEF.to_le_bytes().to_vec(),
+            ),
+        ];
+        let opts = ExtractOptions {
+            strikethrough_markers: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut text);
+        assert_eq!(text, "~~삭제문구~~\n");
+
+        let mut plain = String::new();
+        let opts = ExtractOptions::default();
+        extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut plain);
+        assert_eq!(plain, "삭제문구\n");
+    }
+
+    #[test]
+    fn test_section_layout_two_columns() {
+        let mut cold = control::CTRL_COLUMN_DEF.to_le_bytes().to_vec();
+        cold.extend_from_slice(&(2u16 << 2).to_le_bytes());
+        cold.extend_from_slice(&[0u8; 10]);
+        let mut records = ctrl_paragraph(
+            control::CTRL_SECTION_DEF,
+            vec![rec(record::HWPTAG_CTRL_HEADER, 1, cold)],
+        );
+        assert_eq!(section_layout(&records).column_count, 2);
+
+        records.truncate(3);
+        assert_eq!(section_layout(&records).column_count, 1);
+    }
+
+    #[test]
+    fn test_section_start_page() {
+        let secd = |start: u16| {
+            let mut data = control::CTRL_SECTION_DEF.to_le_bytes().to_vec();
+            data.resize(20, 0);
+            data.extend_from_slice(&start.to_le_bytes());
+            data.extend_from_slice(&[0u8; 8]);
+            rec(record::HWPTAG_CTRL_HEADER, 1, data)
+        };
+        let nwno = |number: u16| {
+            let mut data = control::CTRL_NEW_NUMBER.to_le_bytes().to_vec();
+            data.extend_from_slice(&0u32.to_le_bytes());
+            data.extend_from_slice(&number.to_le_bytes());
+            rec(record::HWPTAG_CTRL_HEADER, 1, data)
+        };
+        let para = |controls: Vec<Record>| {
+            let mut records = vec![
+                rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                rec(record::HWPTAG_PARA_TEXT, 1, utf16le("본문")),
+            ];
+            records.extend(controls);
+            records
+        };
+
+        assert_eq!(section_start_page(&para(vec![secd(5)])), 5);
+        assert_eq!(section_start_page(&para(vec![secd(5), nwno(9)])), 9);
+        assert_eq!(section_start_page(&para(vec![secd(0)])), 0);
+        // 둘째 문단의 새 번호 지정은 구역 중간의 변경
+        let mut records = para(vec![secd(3)]);
+        records.extend(para(vec![nwno(9)]));
+        assert_eq!(section_start_page(&records), 3);
+        assert_eq!(section_start_page(&[]), 0);
+    }
+
+    #[test]
+    fn test_paragraph_alignment() {
+        let doc_info = DocInfo {
+            para_shapes: vec![
+                ParaShape::default(),
+                ParaShape {
+                    attr1: 3 << 2,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let records = vec![
+            rec(
+                record::HWPTAG_PARA_HEADER,
+                0,
+                crate::test_util::para_header_bytes(1, 0),
+            ),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le("제목")),
+            rec(
+                record::HWPTAG_PARA_HEADER,
+                0,
+                crate::test_util::para_header_bytes(0, 0),
+            ),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le("본문")),
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+        ];
+        let opts = ExtractOptions::default();
+        let mut text = String::new();
+        let mut paragraphs = Vec::new();
+        extract_section_paragraphs(&records, &doc_info, &opts, &mut text, &mut paragraphs);
+
+        let mut expected = String::new();
+        extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut expected);
+        assert_eq!(text, expected);
+        let got: Vec<_> = paragraphs
+            .iter()
+            .map(|p| (p.text.as_str(), p.alignment))
+            .collect();
+        assert_eq!(
+            got,
+            [
+                ("제목\n", Alignment::Center),
+                ("본문\n", Alignment::Justify),
+                ("\n\n", Alignment::Justify),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_textart_text() {
+        let mut art = 6u16.to_le_bytes().to_vec();
+        art.extend(utf16le("연간 보고서"));
+        art.extend_from_slice(&4u16.to_le_bytes());
+        art.extend(utf16le("궁서체"));
+        let records = ctrl_paragraph(
+            control::CTRL_GSO,
+            vec![
+                rec(record::HWPTAG_SHAPE_COMPONENT, 2, vec![0; 8]),
+                rec(record::HWPTAG_SHAPE_COMPONENT_TEXTART, 3, art),
+            ],
+        );
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert_eq!(text, "앞연간 보고서\n\n");
+
+        assert_eq!(extract_textart_text(&[1, 0, b' ', 0]), None);
+        assert_eq!(extract_textart_text(&[5, 0]), None);
+    }
+
+    #[test]
+    fn test_form_object_text() {
+        let records = ctrl_paragraph(
+            control::CTRL_FORM,
+            vec![
+                rec(record::HWPTAG_LIST_HEADER, 2, vec![0u8; 8]),
+                rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+                rec(record::HWPTAG_PARA_TEXT, 3, utf16le("입력란")),
+            ],
+        );
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert_eq!(text, "앞입력란\n\n");
+    }
+
+    #[test]
+    fn test_regions_label_header_pages() {
+        let mut pt = utf16le("본문");
+        for _ in 0..3 {
+            pt.extend_from_slice(&[16, 0x00]);
+            pt.extend_from_slice(&[0u8; 14]);
+        }
+        let mut records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, pt),
+        ];
+        for (ctrl_id, page_type, s) in [
+            (control::CTRL_HEADER, 2u32, "홀수 쪽 머리말"),
+            (control::CTRL_HEADER, 1, "짝수 쪽 머리말"),
+            (control::CTRL_FOOTER, 0, "쪽 번호"),
+        ] {
+            let mut header = ctrl_id.to_le_bytes().to_vec();
+            header.extend_from_slice(&page_type.to_le_bytes());
+            records.extend([
+                rec(record::HWPTAG_CTRL_HEADER, 1, header),
+                rec(record::HWPTAG_LIST_HEADER, 2, vec![0u8; 8]),
+                rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+                rec(record::HWPTAG_PARA_TEXT, 3, utf16le(s)),
+            ]);
+        }
+
+        let mut regions = DocumentRegions::default();
+        extract_section_regions(
+            &records,
+            &DocInfo::default(),
+            &ExtractOptions::default(),
+            &mut regions,
+        );
+        assert_eq!(regions.headers, ["홀수 쪽 머리말", "짝수 쪽 머리말"]);
+        assert_eq!(
+            regions.header_pages,
+            [HeaderFooterPages::Odd, HeaderFooterPages::Even]
+        );
+        assert_eq!(regions.footers, ["쪽 번호"]);
+        assert_eq!(regions.footer_pages, [HeaderFooterPages::Both]);
+    }
+
+    #[test]
+    fn test_regions_bucket_header_footer_and_notes() {
+        let mut pt = utf16le("본문");
+        for code in [16u8, 16, 17] {
+            pt.extend_from_slice(&[code, 0x00]);
+            pt.extend_from_slice(&[0u8; 14]);
+        }
+        let mut records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, pt),
+        ];
+        for (ctrl_id, s) in [
+            (control::CTRL_HEADER, "머리말"),
+            (control::CTRL_FOOTER, "꼬리말"),
+            (control::CTRL_FOOTNOTE, "각주"),
+        ] {
+            records.extend([
+                rec(
+                    record::HWPTAG_CTRL_HEADER,
+                    1,
+                    ctrl_id.to_le_bytes().to_vec(),
+                ),
+                rec(record::HWPTAG_LIST_HEADER, 2, vec![0u8; 8]),
+                rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+                rec(record::HWPTAG_PARA_TEXT, 3, utf16le(s)),
+            ]);
+        }
+
+        let mut regions = DocumentRegions::default();
+        extract_section_regions(
+            &records,
+            &DocInfo::default(),
+            &ExtractOptions::default(),
+            &mut regions,
+        );
+        assert_eq!(
+            regions,
+            DocumentRegions {
+                body: "본문\n".into(),
+                headers: vec!["머리말".into()],
+                footers: vec!["꼬리말".into()],
+                footnotes: vec!["각주".into()],
+                header_pages: vec![HeaderFooterPages::Both],
+                footer_pages: vec![HeaderFooterPages::Both],
+            }
+        );
+
+        // 기본 추출은 그대로 본문에 섞는다
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert!(
+            text.contains("머리말") && text.contains("각주"),
+            "got: {text:?}"
+        );
+    }
+
+    #[test]
+    fn test_form_values() {
+        let records = ctrl_paragraph(
+            control::CTRL_FORM,
+            vec![rec(
+                record::HWPTAG_FORM_OBJECT,
+                2,
+                crate::test_util::form_object_bytes(
+                    "TBox CommonSet:set:40: Name:wstring:2:성명 EditSet:set:20: Text:wstring:3:홍길동",
+                ),
+            )],
+        );
+        let opts = ExtractOptions {
+            include_form_values: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_options(&records, &opts, &mut text);
+        assert_eq!(text, "앞성명: 홍길동\n\n");
+
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert_eq!(text, "앞\n");
+    }
+
+    #[test]
+    fn test_equation_unaffected_by_paragraph_lists() {
+        let mut eq = vec![0u8; 4];
+        eq.extend_from_slice(&5u16.to_le_bytes());
+        eq.extend(utf16le("a+b=c"));
+        let records = ctrl_paragraph(
+            control::CTRL_EQUATION,
+            vec![rec(record::HWPTAG_EQEDIT, 2, eq)],
+        );
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert_eq!(text, "앞a+b=c\n\n");
+    }
+
+    #[test]
+    fn test_equation_caption_number() {
+        let numbered = |direction: u32| {
+            let mut lh = vec![0u8; 8];
+            lh.extend_from_slice(&direction.to_le_bytes());
+            let mut eq = vec![0u8; 4];
+            eq.extend_from_slice(&8u16.to_le_bytes());
+            eq.extend(utf16le("E = mc^2"));
+            ctrl_paragraph(
+                control::CTRL_EQUATION,
+                vec![
+                    rec(record::HWPTAG_LIST_HEADER, 2, lh),
+                    rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+                    rec(record::HWPTAG_PARA_TEXT, 3, utf16le("(3.1)")),
+                    rec(record::HWPTAG_EQEDIT, 2, eq),
+                ],
+            )
+        };
+        let mut text = String::new();
+        extract_section_text(&numbered(1), &mut text);
+        assert_eq!(text, "앞E = mc^2 (3.1)\n\n");
+
+        let opts = ExtractOptions {
+            equation_delimiters: ("$".into(), "$".into()),
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_options(&numbered(0), &opts, &mut text);
+        assert_eq!(text, "앞(3.1) $E = mc^2$\n\n");
+    }
+
+    #[test]
+    fn test_equation_delimiters() {
+        let mut eq = vec![0u8; 4];
+        eq.extend_from_slice(&5u16.to_le_bytes());
+        eq.extend(utf16le("a+b=c"));
+        let records = ctrl_paragraph(
+            control::CTRL_EQUATION,
+            vec![rec(record::HWPTAG_EQEDIT, 2, eq)],
+        );
+        let opts = ExtractOptions {
+            equation_delimiters: ("$$\n".into(), "\n$$".into()),
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_options(&records, &opts, &mut text);
+        assert_eq!(text, "앞$$\na+b=c\n$$\n\n");
+    }
+
+    #[test]
+    fn test_embed_images() {
+        let mut picture = vec![0u8; 80];
+        picture[71..73].copy_from_slice(&2u16.to_le_bytes());
+        let records = ctrl_paragraph(
+            control::CTRL_GSO,
+            vec![rec(record::HWPTAG_SHAPE_COMPONENT_PICTURE, 2, picture)],
+        );
+        let doc_info = DocInfo {
+            bin_data: vec![BinData {
+                id: 2,
+                extension: "png".into(),
+                compression: bindata::BinCompression::Default,
+                data: Some(b"foo".to_vec()),
+            }],
+            ..Default::default()
+        };
+        let opts = ExtractOptions {
+            embed_images: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut text);
+        assert!(
+            text.contains("<img src=\"data:image/png;base64,Zm9v\">\n"),
+            "got: {text:?}"
+        );
+
+        let mut text = String::new();
+        let opts = ExtractOptions::default();
+        extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut text);
+        assert!(!text.contains("<img"));
+    }
+
+    #[test]
+    fn test_debug_control_markers() {
+        let mut pt = utf16le("앞");
+        pt.extend_from_slice(&[0x0B, 0x00]);
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend(utf16le("중간"));
+        pt.extend_from_slice(&[0x02, 0x00]);
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend(utf16le("끝"));
+        let records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, pt),
+        ];
+
+        let opts = ExtractOptions {
+            debug_control_markers: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_text_with_options(&records, &opts, &mut text);
+        assert_eq!(text, "앞⟦ctrl:11⟧중간⟦ctrl:2⟧끝\n");
+
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert_eq!(text, "앞중간끝\n");
+    }
+
+    /// "본문" + 글상자(gso, 세로 기준 `vert_rel_to`) 문단과 "다음" 문단
+    fn floating_textbox_section(vert_rel_to: u32) -> Vec<Record> {
+        let mut pt = utf16le("본문");
+        pt.extend_from_slice(&[0x0B, 0x00]); // 그리기 개체
+        pt.extend_from_slice(&[0u8; 14]);
+
+        let mut gso = control::CTRL_GSO.to_le_bytes().to_vec();
+        gso.extend_from_slice(&(vert_rel_to << 3).to_le_bytes());
+
+        vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, pt),
+            rec(record::HWPTAG_CTRL_HEADER, 1, gso),
+            rec(record::HWPTAG_LIST_HEADER, 2, vec![]),
+            rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 3, utf16le("글상자")),
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le("다음")),
+        ]
+    }
+
+    #[test]
+    fn test_floating_objects_section_end() {
+        let opts = ExtractOptions {
+            floating_objects: FloatingObjects::SectionEnd,
+            ..Default::default()
+        };
+
+        // 기본: 앵커 문단 위치에 출력
+        let mut text = String::new();
+        extract_section_text(&floating_textbox_section(1), &mut text);
+        assert_eq!(text, "본문글상자\n\n다음\n");
+
+        // 쪽 기준 개체는 구역 끝으로 이동
+        let mut text = String::new();
+        extract_section_text_with_options(&floating_textbox_section(1), &opts, &mut text);
+        assert_eq!(text, "본문\n다음\n글상자\n");
+
+        // 문단 기준 개체는 그대로
+        let mut text = String::new();
+        extract_section_text_with_options(&floating_textbox_section(2), &opts, &mut text);
+        assert_eq!(text, "본문글상자\n\n다음\n");
+    }
+
+    #[test]
+    fn test_format_coordinate_cells() {
+        // 병합 셀: (0,0)이 2칸 차지, (2,0) 다음에 (0,1)
         let cells = vec![
-            (0u16, 0u16, "A".to_string()),
-            (1, 0, "B".to_string()),
-            (0, 1, "C".to_string()),
-            (1, 1, "D".to_string()),
+            (0u16, 1u16, "C".to_string()),
+            (2, 0, "B".to_string()),
+            (0, 0, "A\nA2".to_string()),
+            (1, 1, " ".to_string()),
         ];
-        let table = format_markdown_table(&cells, 2, 2);
-        assert!(table.contains("| A |"));
-        assert!(table.contains("| --- |"));
-        assert!(table.contains("| C |"));
+        assert_eq!(
+            format_coordinate_cells(&cells, CellNewlines::Space),
+            "R1C1: A A2\nR1C3: B\nR2C1: C\n"
+        );
     }
 
     #[test]
@@ -592,6 +3014,43 @@ mod tests {
         assert!(text.is_empty());
     }
 
+    #[test]
+    fn test_malformed_levels_terminate() {
+        // 레벨이 역행하거나 같은 레벨이 반복되는 손상된 구조
+        let records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_CTRL_HEADER, 1, vec![0x20, 0x6F, 0x67, 0x73]),
+            rec(record::HWPTAG_LIST_HEADER, 3, vec![]),
+            rec(record::HWPTAG_PARA_HEADER, 3, vec![]),
+            rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 4, utf16le("X")),
+            rec(record::HWPTAG_PARA_HEADER, 3, vec![]),
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le("끝")),
+        ];
+        let mut text = String::new();
+        extract_section_text(&records, &mut text);
+        assert!(text.contains("끝"), "got: {text:?}");
+    }
+
+    #[test]
+    fn test_bounded_list_stops_at_end() {
+        // end 이후의 레코드는 처리하지 않아야 한다
+        let records = vec![
+            rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 3, utf16le("A")),
+            rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 3, utf16le("B")),
+        ];
+        let opts = ExtractOptions::default();
+        let ctx = WalkContext::new(&records, &opts);
+        let mut pos = 0;
+        let mut text = String::new();
+        extract_para_list_bounded(&records, &mut pos, 2, 2, &ctx, &mut text);
+        assert_eq!(pos, 2);
+        assert_eq!(text, "A\n");
+    }
+
     #[test]
     fn test_extract_section_text_para_header_only() {
         // PARA_HEADER만 있고 PARA_TEXT가 없는 경우
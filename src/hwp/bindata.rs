@@ -0,0 +1,156 @@
+use crate::hwp::docinfo::read_wstring;
+
+/// BIN_DATA 종류 (속성 bit 0~3)
+const BIN_TYPE_LINK: u16 = 0;
+const BIN_TYPE_EMBEDDING: u16 = 1;
+
+/// 바이너리 데이터 압축 방식 (속성 bit 4~5)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinCompression {
+    /// 스토리지(문서)의 압축 설정을 따른다.
+    Default,
+    /// 압축한다.
+    Compressed,
+    /// 압축하지 않는다.
+    Uncompressed,
+}
+
+/// 문서에 포함된 바이너리 데이터 (DocInfo의 BIN_DATA)
+#[derive(Debug, Clone)]
+pub struct BinData {
+    /// BinData 스토리지의 항목 ID (`BIN{id:04X}.{extension}`)
+    pub id: u16,
+    /// 확장자 (예: "png", "jpg")
+    pub extension: String,
+    pub compression: BinCompression,
+    /// 스트림 내용 (압축 해제 후). 필요할 때만 호출자가 채운다.
+    pub data: Option<Vec<u8>>,
+}
+
+impl BinData {
+    /// BinData 스토리지 안의 스트림 경로
+    pub fn stream_path(&self) -> String {
+        format!("/BinData/BIN{:04X}.{}", self.id, self.extension)
+    }
+
+    /// 확장자로 정한 MIME 형식 (그림이 아니면 `None`)
+    pub fn mime_type(&self) -> Option<&'static str> {
+        Some(match self.extension.to_ascii_lowercase().as_str() {
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            "gif" => "image/gif",
+            "bmp" => "image/bmp",
+            "tif" | "tiff" => "image/tiff",
+            "svg" => "image/svg+xml",
+            "wmf" => "image/wmf",
+            "emf" => "image/emf",
+            _ => return None,
+        })
+    }
+
+    /// 그림 내용을 `data:` URI로 만든다. 내용이 없거나 그림이 아니면 `None`.
+    pub fn data_uri(&self) -> Option<String> {
+        let mime = self.mime_type()?;
+        let data = self.data.as_deref()?;
+        Some(format!("data:{mime};base64,{}", base64_encode(data)))
+    }
+}
+
+/// BIN_DATA 레코드 파싱
+///
+/// 구조: 속성(2) + [LINK: 절대 경로 + 상대 경로 (WORD 길이 + WCHAR)]
+/// | [EMBEDDING/STORAGE: BinData ID(2) + (EMBEDDING이면) 확장자 (WORD 길이 + WCHAR)].
+/// 외부 파일을 가리키는 LINK 항목은 내용이 문서에 없으므로 `None`.
+pub fn parse_bin_data(data: &[u8]) -> Option<BinData> {
+    let attr = u16::from_le_bytes([*data.first()?, *data.get(1)?]);
+    let kind = attr & 0xF;
+    if kind == BIN_TYPE_LINK {
+        return None;
+    }
+    let mut cursor = data.get(2..)?;
+    let id = u16::from_le_bytes([*cursor.first()?, *cursor.get(1)?]);
+    cursor = &cursor[2..];
+    let extension = if kind == BIN_TYPE_EMBEDDING {
+        read_wstring(&mut cursor)?
+    } else {
+        String::new()
+    };
+    let compression = match (attr >> 4) & 0x3 {
+        1 => BinCompression::Compressed,
+        2 => BinCompression::Uncompressed,
+        _ => BinCompression::Default,
+    };
+    Some(BinData {
+        id,
+        extension,
+        compression,
+        data: None,
+    })
+}
+
+/// 그림 개체(SHAPE_COMPONENT_PICTURE)가 가리키는 BinData ID
+///
+/// 구조: 테두리 색(4) + 테두리 두께(4) + 테두리 속성(4) + 이미지 사각형(32)
+/// + 자르기(16) + 안쪽 여백(8) + 밝기(1) + 명암(1) + 효과(1) + BinItem ID(2) + ...
+pub fn picture_bin_id(data: &[u8]) -> Option<u16> {
+    let b = data.get(71..73)?;
+    Some(u16::from_le_bytes([b[0], b[1]]))
+}
+
+/// 표준 base64 인코딩 (패딩 포함)
+pub fn base64_encode(data: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let n = (chunk[0] as u32) << 16
+            | (chunk.get(1).copied().unwrap_or(0) as u32) << 8
+            | chunk.get(2).copied().unwrap_or(0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(TABLE[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::utf16le;
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64_encode(&[0xFF, 0xFE]), "//4=");
+    }
+
+    #[test]
+    fn test_parse_bin_data() {
+        // EMBEDDING, 압축 안 함, ID 3, 확장자 "png"
+        let mut data = (1u16 | (2 << 4)).to_le_bytes().to_vec();
+        data.extend_from_slice(&3u16.to_le_bytes());
+        data.extend_from_slice(&3u16.to_le_bytes());
+        data.extend(utf16le("png"));
+        let mut bin = parse_bin_data(&data).unwrap();
+        assert_eq!(bin.id, 3);
+        assert_eq!(bin.compression, BinCompression::Uncompressed);
+        assert_eq!(bin.stream_path(), "/BinData/BIN0003.png");
+        assert_eq!(bin.data_uri(), None);
+        bin.data = Some(b"foo".to_vec());
+        assert_eq!(
+            bin.data_uri().as_deref(),
+            Some("data:image/png;base64,Zm9v")
+        );
+
+        // LINK 항목은 문서에 내용이 없다
+        assert!(parse_bin_data(&0u16.to_le_bytes()).is_none());
+        assert!(parse_bin_data(&[1]).is_none());
+    }
+}
@@ -10,6 +10,22 @@ pub const CTRL_FOOTNOTE: u32 = make_ctrl_id(b"fn  ");
 pub const CTRL_ENDNOTE: u32 = make_ctrl_id(b"en  ");
 pub const CTRL_HIDDEN_COMMENT: u32 = make_ctrl_id(b"tcmt");
 pub const CTRL_FORM: u32 = make_ctrl_id(b"form");
+pub const CTRL_SECTION_DEF: u32 = make_ctrl_id(b"secd");
+pub const CTRL_COLUMN_DEF: u32 = make_ctrl_id(b"cold");
+/// 새 번호 지정 (쪽·각주·그림 등 번호를 임의 값으로 바꿈)
+pub const CTRL_NEW_NUMBER: u32 = make_ctrl_id(b"nwno");
+
+/// 필드 컨트롤 ID ('%'로 시작)
+pub const CTRL_FIELD_DATE: u32 = make_ctrl_id(b"%dte");
+pub const CTRL_FIELD_DOC_DATE: u32 = make_ctrl_id(b"%ddt");
+pub const CTRL_FIELD_PATH: u32 = make_ctrl_id(b"%pat");
+pub const CTRL_FIELD_SUMMARY: u32 = make_ctrl_id(b"%smr");
+pub const CTRL_FIELD_USER_INFO: u32 = make_ctrl_id(b"%usr");
+pub const CTRL_FIELD_HYPERLINK: u32 = make_ctrl_id(b"%hlk");
+/// 상호 참조 필드 (제목·그림·표 등 책갈피 대상을 가리킴)
+pub const CTRL_FIELD_CROSS_REF: u32 = make_ctrl_id(b"%xrf");
+/// 메모 필드 (메모 본문은 필드 컨트롤 아래 MEMO_LIST 이후 문단)
+pub const CTRL_FIELD_MEMO: u32 = make_ctrl_id(b"%%me");
 
 /// 4바이트 ASCII → u32 (big-endian)
 const fn make_ctrl_id(id: &[u8; 4]) -> u32 {
@@ -36,11 +52,110 @@ pub fn ctrl_name(id: u32) -> &'static str {
         CTRL_ENDNOTE => "Endnote",
         CTRL_HIDDEN_COMMENT => "HiddenComment",
         CTRL_FORM => "Form",
+        CTRL_SECTION_DEF => "SectionDef",
+        CTRL_COLUMN_DEF => "ColumnDef",
+        CTRL_NEW_NUMBER => "NewNumber",
+        CTRL_FIELD_DATE => "FieldDate",
+        CTRL_FIELD_DOC_DATE => "FieldDocDate",
+        CTRL_FIELD_PATH => "FieldPath",
+        CTRL_FIELD_SUMMARY => "FieldSummary",
+        CTRL_FIELD_USER_INFO => "FieldUserInfo",
+        CTRL_FIELD_HYPERLINK => "FieldHyperlink",
+        CTRL_FIELD_CROSS_REF => "FieldCrossRef",
+        CTRL_FIELD_MEMO => "FieldMemo",
         _ => "Unknown",
     }
 }
 
-/// 텍스트를 포함하는 컨트롤인지 확인
+/// 필드 컨트롤인지 확인 (ID 첫 글자가 '%')
+pub fn is_field(id: u32) -> bool {
+    (id >> 24) as u8 == b'%'
+}
+
+/// 개체 공통 속성이 페이지/종이에 고정된 떠 있는 개체인지 확인
+///
+/// CTRL_HEADER 구조: ctrl_id(4) + 속성(4). 속성 bit 0은 "글자처럼 취급",
+/// bit 3~4는 세로 기준 위치(0 = 종이, 1 = 쪽, 2 = 문단).
+/// 글자처럼 취급하지 않고 종이/쪽 기준으로 배치된 표·그리기 개체·수식이 대상이다.
+pub fn is_page_anchored(data: &[u8]) -> bool {
+    let Some(id) = read_ctrl_id(data) else {
+        return false;
+    };
+    if !matches!(id, CTRL_TABLE | CTRL_GSO | CTRL_EQUATION) || data.len() < 8 {
+        return false;
+    }
+    let attr = u32::from_le_bytes([data[4], data[5], data[6], data[7]]);
+    let treat_as_char = attr & 1 != 0;
+    let vert_rel_to = (attr >> 3) & 0x3;
+    !treat_as_char && vert_rel_to <= 1
+}
+
+/// 글 뒤로 배치된 그리기 개체인지 확인 (워터마크·배경 그림)
+///
+/// 개체 공통 속성 bit 21~23은 본문과의 배치로, 4가 "글 뒤로"다.
+pub fn is_behind_text(data: &[u8]) -> bool {
+    if read_ctrl_id(data) != Some(CTRL_GSO) || data.len() < 8 {
+        return false;
+    }
+    let attr = u32::from_le_bytes([data[4], data[5], data[6], data[7]]);
+    (attr >> 21) & 0x7 == 4
+}
+
+/// 머리말·꼬리말 CTRL_HEADER의 적용 쪽 종류 (0 = 양쪽, 1 = 짝수 쪽, 2 = 홀수 쪽)
+///
+/// 구조: ctrl_id(4) + 속성(4, bit 0~1 적용 쪽). 머리말·꼬리말이 아니거나 속성이
+/// 잘린 레코드는 `None`.
+pub fn header_footer_page_type(data: &[u8]) -> Option<u8> {
+    if !matches!(read_ctrl_id(data)?, CTRL_HEADER | CTRL_FOOTER) {
+        return None;
+    }
+    let attr = u32::from_le_bytes(data.get(4..8)?.try_into().ok()?);
+    Some((attr & 0x3) as u8)
+}
+
+/// 단 정의(cold) CTRL_HEADER의 단 수
+///
+/// 구조: ctrl_id(4) + 속성(2) + ... 속성 bit 0~1은 단 종류, bit 2~9는 단 수.
+/// 단 정의가 아니거나 잘린 레코드는 `None`, 단 수 0은 1단으로 본다.
+pub fn column_count(data: &[u8]) -> Option<u8> {
+    if read_ctrl_id(data)? != CTRL_COLUMN_DEF {
+        return None;
+    }
+    let attr = u16::from_le_bytes([*data.get(4)?, *data.get(5)?]);
+    Some((((attr >> 2) & 0xFF) as u8).max(1))
+}
+
+/// 구역 정의(secd) CTRL_HEADER의 시작 쪽 번호
+///
+/// 구조: ctrl_id(4) + 속성(4) + 단 사이 간격(2) + 세로 줄맞춤(2) + 가로 줄맞춤(2) +
+/// 기본 탭 간격(4) + 번호 문단 모양 ID(2) + 쪽 번호(2) + ...
+///
+/// 쪽 번호 0은 앞 구역에 이어 매긴다는 뜻이다. 구역 정의가 아니거나 잘린 레코드는 `None`.
+pub fn section_start_page(data: &[u8]) -> Option<u16> {
+    if read_ctrl_id(data)? != CTRL_SECTION_DEF {
+        return None;
+    }
+    Some(u16::from_le_bytes([*data.get(20)?, *data.get(21)?]))
+}
+
+/// 새 번호 지정(nwno) CTRL_HEADER가 쪽 번호를 바꾸면 그 번호
+///
+/// 구조: ctrl_id(4) + 속성(4, bit 0~3 번호 종류: 0 = 쪽) + 번호(2).
+/// 쪽 번호가 아닌 번호(각주, 그림 등)를 바꾸거나 잘린 레코드는 `None`.
+pub fn new_page_number(data: &[u8]) -> Option<u16> {
+    if read_ctrl_id(data)? != CTRL_NEW_NUMBER {
+        return None;
+    }
+    let attr = u32::from_le_bytes(data.get(4..8)?.try_into().ok()?);
+    if attr & 0xF != 0 {
+        return None;
+    }
+    Some(u16::from_le_bytes([*data.get(8)?, *data.get(9)?]))
+}
+
+/// 텍스트를 문단 리스트(LIST_HEADER + PARA_HEADER)로 포함하는 컨트롤인지 확인
+///
+/// 수식은 문단 리스트가 아니라 EQEDIT 레코드에 스크립트를 두므로 제외한다.
 pub fn has_paragraph_list(id: u32) -> bool {
     matches!(
         id,
@@ -51,6 +166,7 @@ pub fn has_paragraph_list(id: u32) -> bool {
             | CTRL_FOOTNOTE
             | CTRL_ENDNOTE
             | CTRL_HIDDEN_COMMENT
+            | CTRL_FORM
     )
 }
 
@@ -65,10 +181,107 @@ mod tests {
         assert_eq!(CTRL_TABLE, 0x74626C20);
     }
 
+    #[test]
+    fn test_is_field() {
+        assert!(is_field(CTRL_FIELD_DATE));
+        assert!(is_field(CTRL_FIELD_PATH));
+        assert!(is_field(CTRL_FIELD_MEMO));
+        assert!(!is_field(CTRL_TABLE));
+        assert!(!is_field(CTRL_SECTION_DEF));
+    }
+
+    fn object_header(id: u32, attr: u32) -> Vec<u8> {
+        let mut data = id.to_le_bytes().to_vec();
+        data.extend_from_slice(&attr.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_is_page_anchored() {
+        // 쪽 기준 세로 위치
+        assert!(is_page_anchored(&object_header(CTRL_GSO, 1 << 3)));
+        // 종이 기준
+        assert!(is_page_anchored(&object_header(CTRL_TABLE, 0)));
+        // 문단 기준
+        assert!(!is_page_anchored(&object_header(CTRL_GSO, 2 << 3)));
+        // 글자처럼 취급
+        assert!(!is_page_anchored(&object_header(CTRL_GSO, 1 | (1 << 3))));
+        // 개체가 아닌 컨트롤, 속성 누락
+        assert!(!is_page_anchored(&object_header(CTRL_FOOTNOTE, 0)));
+        assert!(!is_page_anchored(&CTRL_GSO.to_le_bytes()));
+    }
+
+    #[test]
+    fn test_header_footer_page_type() {
+        assert_eq!(
+            header_footer_page_type(&object_header(CTRL_HEADER, 0)),
+            Some(0)
+        );
+        assert_eq!(
+            header_footer_page_type(&object_header(CTRL_FOOTER, 2)),
+            Some(2)
+        );
+        assert_eq!(header_footer_page_type(&object_header(CTRL_GSO, 1)), None);
+        assert_eq!(header_footer_page_type(&CTRL_HEADER.to_le_bytes()), None);
+    }
+
+    #[test]
+    fn test_is_behind_text() {
+        assert!(is_behind_text(&object_header(CTRL_GSO, 4 << 21)));
+        // 글 앞으로, 어울림
+        assert!(!is_behind_text(&object_header(CTRL_GSO, 5 << 21)));
+        assert!(!is_behind_text(&object_header(CTRL_GSO, 0)));
+        // 표는 그리기 개체가 아니다
+        assert!(!is_behind_text(&object_header(CTRL_TABLE, 4 << 21)));
+        assert!(!is_behind_text(&CTRL_GSO.to_le_bytes()));
+    }
+
+    #[test]
+    fn test_has_paragraph_list() {
+        assert!(has_paragraph_list(CTRL_TABLE));
+        assert!(has_paragraph_list(CTRL_FORM));
+        assert!(!has_paragraph_list(CTRL_EQUATION));
+        assert!(!has_paragraph_list(CTRL_SECTION_DEF));
+    }
+
     #[test]
     fn test_read_ctrl_id() {
         // HWP 바이너리는 리틀엔디안: "tbl " = 0x74626C20 → LE bytes [0x20, 0x6C, 0x62, 0x74]
         let data = [0x20, 0x6C, 0x62, 0x74];
         assert_eq!(read_ctrl_id(&data), Some(CTRL_TABLE));
     }
+
+    #[test]
+    fn test_column_count() {
+        let cold = |attr: u16| {
+            let mut data = CTRL_COLUMN_DEF.to_le_bytes().to_vec();
+            data.extend_from_slice(&attr.to_le_bytes());
+            data
+        };
+        assert_eq!(column_count(&cold(2 << 2)), Some(2));
+        assert_eq!(column_count(&cold(3 << 2 | 1)), Some(3));
+        assert_eq!(column_count(&cold(0)), Some(1));
+        assert_eq!(column_count(&CTRL_COLUMN_DEF.to_le_bytes()), None);
+        assert_eq!(column_count(&CTRL_SECTION_DEF.to_le_bytes()), None);
+    }
+
+    #[test]
+    fn test_start_page_numbers() {
+        let mut secd = CTRL_SECTION_DEF.to_le_bytes().to_vec();
+        secd.resize(20, 0);
+        secd.extend_from_slice(&7u16.to_le_bytes());
+        assert_eq!(section_start_page(&secd), Some(7));
+        assert_eq!(section_start_page(&secd[..21]), None);
+        assert_eq!(section_start_page(&CTRL_COLUMN_DEF.to_le_bytes()), None);
+
+        let nwno = |kind: u32, number: u16| {
+            let mut data = CTRL_NEW_NUMBER.to_le_bytes().to_vec();
+            data.extend_from_slice(&kind.to_le_bytes());
+            data.extend_from_slice(&number.to_le_bytes());
+            data
+        };
+        assert_eq!(new_page_number(&nwno(0, 12)), Some(12));
+        assert_eq!(new_page_number(&nwno(1, 12)), None);
+        assert_eq!(new_page_number(&secd), None);
+    }
 }
@@ -2,6 +2,7 @@ use aes::cipher::{block_padding::NoPadding, BlockDecryptMut, KeyInit};
 use aes::Aes128;
 
 use crate::error::{HwpError, Result};
+use crate::hwp::record;
 
 type Aes128EcbDec = ecb::Decryptor<Aes128>;
 
@@ -12,7 +13,8 @@ type Aes128EcbDec = ecb::Decryptor<Aes128>;
 ///
 /// 핵심: i=0부터 반복하되 XOR는 i>=4에서만 적용.
 /// 0~3번 바이트는 seed로 사용되지만 number 카운터도 소비한다.
-fn deobfuscate(data: &mut [u8; 256]) {
+/// seed 바이트는 변하지 않는 XOR 변환이므로 난독화에도 그대로 쓰인다.
+pub(crate) fn deobfuscate(data: &mut [u8; 256]) {
     // Java의 LittleEndian.getInt() → signed int32
     let mut random_seed: i32 = i32::from_le_bytes([data[0], data[1], data[2], data[3]]);
 
@@ -37,40 +39,59 @@ fn deobfuscate(data: &mut [u8; 256]) {
     }
 }
 
+/// 레코드 헤더 뒤 암호화 메타데이터 길이
+const META_LEN: usize = 256;
+
+/// 암호화 본문 끝에서 잘라 낼 수 있는 비암호화 바이트의 최대 수
+///
+/// 일부 배포문서는 암호화 본문 뒤에 레코드 종결자 등 몇 바이트를 덧붙인다.
+/// 이보다 긴 나머지는 덧붙은 바이트로 보기 어려우므로 잘라 내지 않는다.
+const MAX_TRAILING_BYTES: usize = 8;
+
 /// 배포문서 스트림을 복호화한다.
 ///
 /// 스트림 구조:
-/// 1. 4바이트 레코드 헤더 (스킵)
+/// 1. DISTRIBUTE_DOC_DATA 레코드 헤더 (4바이트, 확장 크기면 8바이트, 스킵)
 /// 2. 256바이트 암호화 메타데이터 (LCG XOR 디옵퓨스케이션)
 /// 3. 나머지: AES/ECB/NoPadding 암호화된 데이터
+///
+/// 암호화 본문이 16바이트 블록에 맞지 않으면, 나머지가 `MAX_TRAILING_BYTES`(8) 이하이고
+/// 온전한 블록이 하나 이상 남을 때만 나머지를 덧붙은 바이트로 보고 잘라 낸 뒤 복호화한다.
+/// 그 밖의 경우는 손상된 스트림이므로 실패한다.
 pub fn decrypt_distribution_stream(data: &[u8]) -> Result<Vec<u8>> {
-    if data.len() < 260 {
-        return Err(HwpError::DecryptFailed(
-            "Distribution stream too short".into(),
-        ));
-    }
+    let too_short = || HwpError::DecryptFailed("Distribution stream too short".into());
+    let (_, header_len) = record::read_record_header(data).map_err(|_| too_short())?;
+    let meta_end = header_len + META_LEN;
+    let meta_bytes = data.get(header_len..meta_end).ok_or_else(too_short)?;
 
-    // 4바이트 레코드 헤더 스킵 + 256바이트 메타데이터
-    let mut meta = [0u8; 256];
-    meta.copy_from_slice(&data[4..260]);
+    let mut meta = [0u8; META_LEN];
+    meta.copy_from_slice(meta_bytes);
 
     // LCG XOR 디옵퓨스케이션
     deobfuscate(&mut meta);
 
     // AES 키 추출: offset = 4 + (meta[0] & 0xF), 16바이트
     let key_offset = 4 + (meta[0] & 0xF) as usize;
-    if key_offset + 16 > 256 {
+    if key_offset + 16 > META_LEN {
         return Err(HwpError::DecryptFailed("Key offset out of range".into()));
     }
     let key = &meta[key_offset..key_offset + 16];
 
     // 나머지 데이터를 AES/ECB/PKCS7로 복호화
-    let encrypted = &data[260..];
+    let encrypted = &data[meta_end..];
     if encrypted.is_empty() {
         return Ok(Vec::new());
     }
 
-    let mut buf = encrypted.to_vec();
+    let remainder = encrypted.len() % 16;
+    let aligned = encrypted.len() - remainder;
+    if remainder > MAX_TRAILING_BYTES || aligned == 0 {
+        return Err(HwpError::DecryptFailed(format!(
+            "Encrypted data is not block-aligned ({} bytes)",
+            encrypted.len()
+        )));
+    }
+    let mut buf = encrypted[..aligned].to_vec();
 
     let decryptor = Aes128EcbDec::new_from_slice(key)
         .map_err(|e| HwpError::DecryptFailed(format!("AES key init failed: {}", e)))?;
@@ -112,12 +133,48 @@ mod tests {
 
     #[test]
     fn test_decrypt_not_block_aligned() {
-        // 260 + 15바이트 (16의 배수가 아님) → AES 복호화 실패
+        // 260 + 15바이트 (16의 배수가 아니고 온전한 블록 없음) → 실패
         let data = vec![0u8; 275];
         let result = decrypt_distribution_stream(&data);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_decrypt_trims_trailing_bytes() {
+        use crate::test_util::distribution_stream;
+        let key = *b"0123456789abcdef";
+        let plain = [7u8; 32];
+        let mut data = distribution_stream(&plain, 3, key, key);
+        data.extend_from_slice(&[0xFF, 0xFF]);
+        assert_eq!(decrypt_distribution_stream(&data).unwrap(), plain);
+
+        // 덧붙은 바이트로 보기에는 긴 나머지는 잘라 내지 않는다
+        data.extend_from_slice(&[0xFF; 7]);
+        assert!(matches!(
+            decrypt_distribution_stream(&data),
+            Err(HwpError::DecryptFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_decrypt_extended_size_header() {
+        use crate::test_util::distribution_stream;
+        let key = *b"0123456789abcdef";
+        let plain = [9u8; 16];
+        let short = distribution_stream(&plain, 5, key, key);
+
+        // 같은 레코드를 확장 크기(size 필드 0xFFF + 4바이트 크기) 헤더로 다시 쓴다
+        let mut extended = (0x1Cu32 | (0xFFF << 20)).to_le_bytes().to_vec();
+        extended.extend_from_slice(&256u32.to_le_bytes());
+        extended.extend_from_slice(&short[4..]);
+
+        assert_eq!(decrypt_distribution_stream(&short).unwrap(), plain);
+        assert_eq!(decrypt_distribution_stream(&extended).unwrap(), plain);
+
+        // 확장 헤더에 메타데이터가 모자라면 실패
+        assert!(decrypt_distribution_stream(&extended[..8 + 255]).is_err());
+    }
+
     #[test]
     fn test_decrypt_one_block() {
         // 260 + 16바이트 → AES ECB 1블록 복호화 (키가 유효하므로 성공)
@@ -1,35 +1,386 @@
+use std::fmt;
+use std::ops::ControlFlow;
+
 use byteorder::{LittleEndian, ReadBytesExt};
 
 use crate::error::{HwpError, Result};
+use crate::hwp::bindata::{self, BinData};
 use crate::hwp::record::{self, Record};
 
+/// 한/글 기본 탭 간격: 40pt (HWPUNIT, 1pt = 100)
+///
+/// DocInfo에는 문서 기본 탭 간격이 기록되지 않는다. 구역 정의에 기본 탭 간격이
+/// 없을 때 이 값을 쓴다.
+pub const DEFAULT_TAB_WIDTH: u32 = 4000;
+
+/// 문단 모양 (PARA_SHAPE)
+#[derive(Debug, Clone, Default)]
+pub struct ParaShape {
+    /// 속성 1 (정렬, 문단 머리 종류·수준 등 비트 필드)
+    pub attr1: u32,
+    /// 왼쪽 여백 (HWPUNIT)
+    pub left_margin: i32,
+    /// 문단 번호/글머리표 ID (문단 머리가 번호·글머리표일 때 의미 있음)
+    pub numbering_id: u16,
+}
+
+/// 문단 머리 종류 (PARA_SHAPE 속성 1의 bit 23~24)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParaHead {
+    None,
+    Outline,
+    Numbering,
+    Bullet,
+}
+
+impl ParaShape {
+    /// 문단 머리 종류
+    pub fn head(&self) -> ParaHead {
+        match (self.attr1 >> 23) & 0x3 {
+            1 => ParaHead::Outline,
+            2 => ParaHead::Numbering,
+            3 => ParaHead::Bullet,
+            _ => ParaHead::None,
+        }
+    }
+
+    /// 문단 머리 수준 (bit 25~27, 0부터)
+    pub fn head_level(&self) -> u8 {
+        ((self.attr1 >> 25) & 0x7) as u8
+    }
+
+    /// 개요 문단이면 개요 수준 (1~7)
+    pub fn outline_level(&self) -> Option<u8> {
+        (self.head() == ParaHead::Outline).then_some(self.head_level() + 1)
+    }
+
+    /// 문단 정렬 (bit 2~4)
+    pub fn alignment(&self) -> Alignment {
+        match (self.attr1 >> 2) & 0x7 {
+            1 => Alignment::Left,
+            2 => Alignment::Right,
+            3 => Alignment::Center,
+            4 => Alignment::Distribute,
+            5 => Alignment::Split,
+            _ => Alignment::Justify,
+        }
+    }
+}
+
+/// 문단 정렬 (PARA_SHAPE 속성 1의 bit 2~4)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Alignment {
+    /// 양쪽 정렬 (한/글 기본값)
+    #[default]
+    Justify,
+    Left,
+    Right,
+    Center,
+    /// 배분 정렬 (글자 사이를 늘려 줄을 채움)
+    Distribute,
+    /// 나눔 정렬 (마지막 줄까지 배분)
+    Split,
+}
+
+/// 글자 모양 (CHAR_SHAPE)
+#[derive(Debug, Clone, Default)]
+pub struct CharShape {
+    /// 속성 (기울임, 진하게, 밑줄, 취소선 등 비트 필드)
+    pub attr: u32,
+    /// 음영(형광펜) 색 (0x00BBGGRR), 없으면 `None`
+    pub shade_color: Option<u32>,
+}
+
+impl CharShape {
+    /// 밑줄이 있는지 (속성 bit 2~3 밑줄 위치가 0이 아니면)
+    pub fn underline(&self) -> bool {
+        (self.attr >> 2) & 0x3 != 0
+    }
+
+    /// 음영(형광펜)이 칠해져 있는지
+    pub fn highlight(&self) -> bool {
+        self.shade_color.is_some()
+    }
+
+    /// 취소선이 있는지 (속성 bit 18~20이 0이 아니면)
+    pub fn strikethrough(&self) -> bool {
+        (self.attr >> 18) & 0x7 != 0
+    }
+}
+
+/// 스타일 (STYLE)
+#[derive(Debug, Clone, Default)]
+pub struct Style {
+    /// 한글 스타일 이름 (예: "개요 1")
+    pub name: String,
+    /// 영문 스타일 이름 (예: "Outline 1")
+    pub english_name: String,
+    pub para_shape_id: u16,
+    pub char_shape_id: u16,
+    /// 언어 ID (Windows LANGID, 예: 0x0412 = 한국어)
+    pub lang_id: u16,
+}
+
+impl Style {
+    /// 제목 스타일("개요 N", "Outline N", "Heading N")이면 수준 N
+    pub fn heading_level(&self) -> Option<u8> {
+        [self.name.as_str(), self.english_name.as_str()]
+            .iter()
+            .find_map(|name| {
+                let rest = name
+                    .strip_prefix("개요")
+                    .or_else(|| name.strip_prefix("Outline"))
+                    .or_else(|| name.strip_prefix("Heading"))?;
+                rest.trim()
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|n| (1..=10).contains(n))
+            })
+    }
+}
+
+/// 금칙 문자 (FORBIDDEN_CHAR)
+///
+/// 줄 나눔 시 줄 머리·줄 끝에 올 수 없는 문자 목록. 텍스트 추출에는 영향이 없고
+/// DocInfo를 빠짐없이 보고하는 도구를 위해 보존한다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForbiddenChars {
+    /// 줄 머리에 올 수 없는 문자 (예: "!%),.:;?]}")
+    pub line_start: String,
+    /// 줄 끝에 올 수 없는 문자 (예: "$([{")
+    pub line_end: String,
+}
+
 /// DocInfo에서 필요한 최소 정보
-#[derive(Debug)]
+#[derive(Debug, Clone, Default)]
 pub struct DocInfo {
     pub section_count: u16,
+    /// 문단 모양 목록 (인덱스 = PARA_HEADER의 para_shape_id)
+    pub para_shapes: Vec<ParaShape>,
+    /// 스타일 목록 (인덱스 = PARA_HEADER의 style_id)
+    pub styles: Vec<Style>,
+    /// 글자 모양 목록 (인덱스 = PARA_CHAR_SHAPE의 글자 모양 ID)
+    pub char_shapes: Vec<CharShape>,
+    /// 문서에 포함된 바이너리 데이터 목록 (외부 연결 항목 제외)
+    pub bin_data: Vec<BinData>,
+    /// 금칙 문자 (FORBIDDEN_CHAR 레코드가 있을 때만)
+    pub forbidden_chars: Option<ForbiddenChars>,
+    /// 글꼴(FACE_NAME) 수 (모든 언어 합계)
+    pub font_count: usize,
 }
 
-/// DocInfo 레코드 시퀀스에서 section_count를 추출한다.
-/// DOCUMENT_PROPERTIES (첫 번째 레코드)의 첫 u16이 section_count.
-pub fn parse_doc_info(records: &[Record]) -> Result<DocInfo> {
-    let first = records
-        .first()
-        .ok_or_else(|| HwpError::Parse("Empty DocInfo records".into()))?;
+/// 진단용 요약: `sections: 2, fonts: 14, styles: 20, paragraph shapes: 31`
+impl fmt::Display for DocInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "sections: {}, fonts: {}, styles: {}, paragraph shapes: {}",
+            self.section_count,
+            self.font_count,
+            self.styles.len(),
+            self.para_shapes.len()
+        )
+    }
+}
 
-    if first.header.tag_id != record::HWPTAG_DOCUMENT_PROPERTIES {
+/// Windows LANGID → 로캘 문자열 (한/글이 쓰는 주요 언어만, 그 밖에는 `None`)
+pub fn lang_id_locale(lang_id: u16) -> Option<&'static str> {
+    Some(match lang_id {
+        0x0412 => "ko-KR",
+        0x0409 => "en-US",
+        0x0809 => "en-GB",
+        0x0411 => "ja-JP",
+        0x0804 => "zh-CN",
+        0x0404 => "zh-TW",
+        0x0C04 => "zh-HK",
+        0x0407 => "de-DE",
+        0x040C => "fr-FR",
+        0x0C0A => "es-ES",
+        0x0419 => "ru-RU",
+        0x042A => "vi-VN",
+        _ => return None,
+    })
+}
+
+impl DocInfo {
+    /// 문서 기본 언어: 기본 스타일(첫 스타일, 보통 "바탕글")의 언어
+    ///
+    /// DocInfo에는 문서 언어 필드가 따로 없으므로 기본 스타일의 언어 ID를 쓴다.
+    /// 스타일이 없거나 알 수 없는 언어 ID이면 `None`.
+    pub fn default_language(&self) -> Option<&'static str> {
+        lang_id_locale(self.styles.first()?.lang_id)
+    }
+
+    /// 문단의 개요 수준: 문단 모양의 개요 수준, 없으면 제목 스타일 이름에서 결정
+    pub fn outline_level(&self, para_shape_id: u16, style_id: u8) -> Option<u8> {
+        self.para_shapes
+            .get(para_shape_id as usize)
+            .and_then(ParaShape::outline_level)
+            .or_else(|| self.styles.get(style_id as usize)?.heading_level())
+    }
+}
+
+/// DOCUMENT_PROPERTIES를 찾아볼 DocInfo 앞쪽 레코드 수
+///
+/// 보통 첫 레코드지만, 앞에 크기 0인 채움 레코드나 ID_MAPPINGS가 오는 문서가 있다.
+const DOCUMENT_PROPERTIES_SCAN: usize = 4;
+
+/// DocInfo 스트림 데이터(압축 해제 후)에서 구역 수만 읽는다.
+///
+/// DOCUMENT_PROPERTIES까지만 파싱하고 나머지 레코드는 보지 않는다.
+pub fn read_section_count(data: &[u8]) -> Result<u16> {
+    let mut first_tag = None;
+    let mut found = None;
+    let mut seen = 0;
+    record::walk_records(data, |header, body| {
+        first_tag.get_or_insert(header.tag_id);
+        if header.tag_id == record::HWPTAG_DOCUMENT_PROPERTIES {
+            found = Some(document_section_count(header.tag_id, body));
+            return ControlFlow::Break(());
+        }
+        seen += 1;
+        if seen < DOCUMENT_PROPERTIES_SCAN {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    })?;
+    match (found, first_tag) {
+        (Some(count), _) => count,
+        (None, Some(tag_id)) => document_section_count(tag_id, &[]),
+        (None, None) => Err(HwpError::Parse("Empty DocInfo records".into())),
+    }
+}
+
+/// DOCUMENT_PROPERTIES 레코드의 첫 u16(구역 수)을 읽는다.
+fn document_section_count(tag_id: u16, data: &[u8]) -> Result<u16> {
+    if tag_id != record::HWPTAG_DOCUMENT_PROPERTIES {
         return Err(HwpError::Parse(format!(
             "Expected DOCUMENT_PROPERTIES, got tag 0x{:X}",
-            first.header.tag_id
+            tag_id
         )));
     }
 
-    if first.data.len() < 2 {
+    if data.len() < 2 {
         return Err(HwpError::Parse("DOCUMENT_PROPERTIES too short".into()));
     }
 
-    let section_count = (&first.data[..2]).read_u16::<LittleEndian>()?;
+    Ok((&data[..2]).read_u16::<LittleEndian>()?)
+}
+
+/// DocInfo 레코드 시퀀스를 파싱한다.
+/// DOCUMENT_PROPERTIES (앞쪽 몇 레코드 안, 보통 첫 번째)의 첫 u16이 section_count.
+/// PARA_SHAPE·STYLE 레코드는 등장 순서가 곧 ID이며, 손상된 레코드는 기본값으로 채운다.
+pub fn parse_doc_info(records: &[Record]) -> Result<DocInfo> {
+    let first = records
+        .first()
+        .ok_or_else(|| HwpError::Parse("Empty DocInfo records".into()))?;
+    let properties = records
+        .iter()
+        .take(DOCUMENT_PROPERTIES_SCAN)
+        .find(|r| r.header.tag_id == record::HWPTAG_DOCUMENT_PROPERTIES)
+        .unwrap_or(first);
+    let section_count = document_section_count(properties.header.tag_id, &properties.data)?;
+
+    let mut info = DocInfo {
+        section_count,
+        ..Default::default()
+    };
+    for rec in records {
+        match rec.header.tag_id {
+            record::HWPTAG_PARA_SHAPE => {
+                info.para_shapes.push(parse_para_shape(&rec.data));
+            }
+            record::HWPTAG_STYLE => {
+                info.styles.push(parse_style(&rec.data).unwrap_or_default());
+            }
+            record::HWPTAG_FACE_NAME => info.font_count += 1,
+            record::HWPTAG_CHAR_SHAPE => info.char_shapes.push(parse_char_shape(&rec.data)),
+            record::HWPTAG_BIN_DATA => info.bin_data.extend(bindata::parse_bin_data(&rec.data)),
+            record::HWPTAG_FORBIDDEN_CHAR => {
+                info.forbidden_chars = parse_forbidden_chars(&rec.data);
+            }
+            _ => {}
+        }
+    }
+    Ok(info)
+}
+
+/// PARA_SHAPE 레코드 파싱
+///
+/// 구조: 속성 1(4) + 왼쪽 여백(4) + 오른쪽 여백(4) + 들여쓰기(4) +
+/// 문단 간격 위/아래(4+4) + 줄 간격(4) + 탭 정의 ID(2) + 번호/글머리표 ID(2) + ...
+/// 잘린 레코드는 읽을 수 있는 필드까지만 채운다.
+fn parse_para_shape(data: &[u8]) -> ParaShape {
+    let mut cursor = data;
+    ParaShape {
+        attr1: cursor.read_u32::<LittleEndian>().unwrap_or_default(),
+        left_margin: cursor.read_i32::<LittleEndian>().unwrap_or_default(),
+        numbering_id: data
+            .get(30..32)
+            .map_or(0, |b| u16::from_le_bytes([b[0], b[1]])),
+    }
+}
+
+/// CHAR_SHAPE 레코드 파싱
+///
+/// 구조: 언어별 글꼴 ID(2×7) + 장평(7) + 자간(7) + 상대 크기(7) + 글자 위치(7)
+/// + 기준 크기(4) + 속성(4) + ...
+///
+/// 음영 색은 오프셋 60의 COLORREF로, 0xFFFFFFFF는 음영 없음이다.
+fn parse_char_shape(data: &[u8]) -> CharShape {
+    let read_u32 = |range: std::ops::Range<usize>| {
+        data.get(range)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+    CharShape {
+        attr: read_u32(46..50).unwrap_or(0),
+        shade_color: read_u32(60..64).filter(|&color| color != 0xFFFF_FFFF),
+    }
+}
+
+/// FORBIDDEN_CHAR 레코드 파싱
+///
+/// 구조: 줄 머리 금칙 문자(WORD 길이 + WCHAR) + 줄 끝 금칙 문자(WORD 길이 + WCHAR).
+/// 줄 끝 목록이 잘린 레코드는 줄 머리 목록만 채운다.
+fn parse_forbidden_chars(data: &[u8]) -> Option<ForbiddenChars> {
+    let mut cursor = data;
+    let line_start = read_wstring(&mut cursor)?;
+    let line_end = read_wstring(&mut cursor).unwrap_or_default();
+    Some(ForbiddenChars {
+        line_start,
+        line_end,
+    })
+}
 
-    Ok(DocInfo { section_count })
+/// STYLE 레코드 파싱
+///
+/// 구조: 한글 이름(WORD 길이 + WCHAR) + 영문 이름(WORD 길이 + WCHAR) + 속성(1)
+/// + 다음 스타일 ID(1) + 언어 ID(2) + 문단 모양 ID(2) + 글자 모양 ID(2)
+fn parse_style(data: &[u8]) -> Option<Style> {
+    let mut cursor = data;
+    let name = read_wstring(&mut cursor)?;
+    let english_name = read_wstring(&mut cursor)?;
+    let rest = cursor.get(2..8)?;
+    Some(Style {
+        name,
+        english_name,
+        lang_id: u16::from_le_bytes([rest[0], rest[1]]),
+        para_shape_id: u16::from_le_bytes([rest[2], rest[3]]),
+        char_shape_id: u16::from_le_bytes([rest[4], rest[5]]),
+    })
+}
+
+/// WORD 길이 + UTF-16LE 문자열
+pub(crate) fn read_wstring(cursor: &mut &[u8]) -> Option<String> {
+    let len = cursor.read_u16::<LittleEndian>().ok()? as usize;
+    let bytes = cursor.get(..len * 2)?;
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+    *cursor = &cursor[len * 2..];
+    Some(String::from_utf16_lossy(&units))
 }
 
 #[cfg(test)]
@@ -74,4 +425,171 @@ mod tests {
         let info = parse_doc_info(&records).unwrap();
         assert_eq!(info.section_count, 3);
     }
+
+    #[test]
+    fn test_display_summary() {
+        use crate::test_util::{rec, style_bytes};
+        let records = vec![
+            rec(record::HWPTAG_DOCUMENT_PROPERTIES, 0, vec![2, 0]),
+            rec(record::HWPTAG_FACE_NAME, 1, vec![]),
+            rec(record::HWPTAG_FACE_NAME, 1, vec![]),
+            rec(record::HWPTAG_FACE_NAME, 1, vec![]),
+            rec(record::HWPTAG_STYLE, 1, style_bytes("바탕글", "Normal", 0)),
+        ];
+        let info = parse_doc_info(&records).unwrap();
+        let summary = info.to_string();
+        assert!(summary.contains("sections: 2"), "{summary}");
+        assert!(summary.contains("fonts: 3"), "{summary}");
+        assert!(summary.contains("styles: 1"), "{summary}");
+        assert!(format!("{info:?}").contains("font_count: 3"));
+    }
+
+    #[test]
+    fn test_parse_para_shapes_and_styles() {
+        use crate::test_util::{outline_attr1, rec, style_bytes};
+        let records = vec![
+            rec(record::HWPTAG_DOCUMENT_PROPERTIES, 0, vec![1, 0]),
+            rec(
+                record::HWPTAG_PARA_SHAPE,
+                1,
+                [0u32, 2000].map(u32::to_le_bytes).concat(),
+            ),
+            rec(
+                record::HWPTAG_PARA_SHAPE,
+                1,
+                outline_attr1(3).to_le_bytes().to_vec(),
+            ),
+            rec(record::HWPTAG_STYLE, 1, style_bytes("바탕글", "Normal", 0)),
+            rec(
+                record::HWPTAG_STYLE,
+                1,
+                style_bytes("개요 2", "Outline 2", 0),
+            ),
+            rec(record::HWPTAG_STYLE, 1, vec![0xFF]), // 손상
+        ];
+        let info = parse_doc_info(&records).unwrap();
+        assert_eq!(info.para_shapes.len(), 2);
+        assert_eq!(info.para_shapes[0].outline_level(), None);
+        assert_eq!(info.para_shapes[1].outline_level(), Some(3));
+        assert_eq!(info.para_shapes[0].left_margin, 2000);
+        assert_eq!(info.para_shapes[1].left_margin, 0); // 잘린 레코드
+        assert_eq!(info.styles.len(), 3);
+        assert_eq!(info.styles[1].name, "개요 2");
+        assert_eq!(info.styles[1].heading_level(), Some(2));
+        assert_eq!(info.styles[0].heading_level(), None);
+        assert_eq!(info.styles[0].lang_id, 0x0412);
+        assert_eq!(info.default_language(), Some("ko-KR"));
+
+        // 문단 모양이 우선, 없으면 스타일 이름
+        assert_eq!(info.outline_level(1, 0), Some(3));
+        assert_eq!(info.outline_level(0, 1), Some(2));
+        assert_eq!(info.outline_level(0, 0), None);
+        assert_eq!(info.outline_level(99, 99), None);
+    }
+
+    #[test]
+    fn test_parse_char_shapes() {
+        use crate::test_util::{char_shape_bytes, rec};
+        let records = vec![
+            rec(record::HWPTAG_DOCUMENT_PROPERTIES, 0, vec![1, 0]),
+            rec(record::HWPTAG_CHAR_SHAPE, 1, char_shape_bytes(0b11)),
+            rec(record::HWPTAG_CHAR_SHAPE, 1, char_shape_bytes(1 << 18)),
+            rec(record::HWPTAG_CHAR_SHAPE, 1, vec![0; 10]), // 잘린 레코드
+        ];
+        let info = parse_doc_info(&records).unwrap();
+        assert_eq!(info.char_shapes.len(), 3);
+        assert!(!info.char_shapes[0].strikethrough());
+        assert!(info.char_shapes[1].strikethrough());
+        assert!(!info.char_shapes[2].strikethrough());
+
+        // 밑줄: 속성 bit 2~3, 음영: 오프셋 60 (0xFFFFFFFF는 없음)
+        assert!(!info.char_shapes[0].underline());
+        assert!(!info.char_shapes[0].highlight());
+        let mut shaded = char_shape_bytes(1 << 2);
+        shaded[60..64].copy_from_slice(&0x0000_FFFFu32.to_le_bytes());
+        let records = vec![
+            rec(record::HWPTAG_DOCUMENT_PROPERTIES, 0, vec![1, 0]),
+            rec(record::HWPTAG_CHAR_SHAPE, 1, shaded),
+        ];
+        let info = parse_doc_info(&records).unwrap();
+        let shape = &info.char_shapes[0];
+        assert!(shape.underline() && shape.highlight());
+        assert_eq!(shape.shade_color, Some(0x0000_FFFF));
+        assert!(!shape.strikethrough());
+    }
+
+    #[test]
+    fn test_parse_forbidden_chars() {
+        use crate::test_util::{rec, utf16le};
+        let mut data = Vec::new();
+        for s in ["!),.?", "([{"] {
+            data.extend_from_slice(&(s.len() as u16).to_le_bytes());
+            data.extend(utf16le(s));
+        }
+        let records = vec![
+            rec(record::HWPTAG_DOCUMENT_PROPERTIES, 0, vec![1, 0]),
+            rec(record::HWPTAG_FORBIDDEN_CHAR, 1, data),
+        ];
+        let info = parse_doc_info(&records).unwrap();
+        assert_eq!(
+            info.forbidden_chars,
+            Some(ForbiddenChars {
+                line_start: "!),.?".into(),
+                line_end: "([{".into(),
+            })
+        );
+
+        let info = parse_doc_info(&records[..1]).unwrap();
+        assert_eq!(info.forbidden_chars, None);
+    }
+
+    #[test]
+    fn test_read_section_count() {
+        use crate::test_util::{doc_info_bytes, rec, records_bytes};
+        let mut data = doc_info_bytes(3);
+        // 뒤따르는 손상된 레코드는 보지 않는다
+        data.extend_from_slice(&[0xFF; 3]);
+        assert_eq!(read_section_count(&data).unwrap(), 3);
+
+        assert!(read_section_count(&[]).is_err());
+        let wrong = records_bytes(&[rec(record::HWPTAG_STYLE, 0, vec![1, 0])]);
+        assert!(read_section_count(&wrong).is_err());
+    }
+
+    #[test]
+    fn test_document_properties_after_padding() {
+        use crate::test_util::{doc_info_bytes, rec, records_bytes};
+        // 크기 0 채움 레코드 + ID_MAPPINGS 뒤에 DOCUMENT_PROPERTIES
+        let leading = [
+            rec(0, 0, vec![]),
+            rec(record::HWPTAG_BEGIN + 1, 0, vec![0; 8]),
+        ];
+        let mut data = records_bytes(&leading);
+        data.extend(doc_info_bytes(2));
+        data.extend(records_bytes(&[rec(
+            record::HWPTAG_STYLE,
+            0,
+            crate::test_util::style_bytes("바탕글", "Normal", 0),
+        )]));
+        assert_eq!(read_section_count(&data).unwrap(), 2);
+
+        let records = record::read_records(&data).unwrap();
+        let info = parse_doc_info(&records).unwrap();
+        assert_eq!(info.section_count, 2);
+        assert_eq!(info.styles.len(), 1);
+
+        // 찾아보는 범위 밖이면 여전히 오류
+        let mut far = records_bytes(&vec![rec(0, 0, vec![]); DOCUMENT_PROPERTIES_SCAN]);
+        far.extend(doc_info_bytes(2));
+        assert!(read_section_count(&far).is_err());
+        assert!(parse_doc_info(&record::read_records(&far).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_lang_id_locale() {
+        assert_eq!(lang_id_locale(0x0409), Some("en-US"));
+        assert_eq!(lang_id_locale(0x0411), Some("ja-JP"));
+        assert_eq!(lang_id_locale(0), None);
+        assert_eq!(DocInfo::default().default_language(), None);
+    }
 }
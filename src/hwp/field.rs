@@ -0,0 +1,354 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// 파라미터 아이템 종류 (PIT_*)
+const PIT_NULL: u16 = 0;
+const PIT_BSTR: u16 = 1;
+const PIT_I1: u16 = 2;
+const PIT_UI: u16 = 9;
+const PIT_SET: u16 = 0x8000;
+
+/// 중첩 파라미터 셋 최대 깊이 (손상된 데이터의 무한 재귀 방지)
+const MAX_SET_DEPTH: usize = 8;
+
+/// 필드 CTRL_DATA(파라미터 셋)에서 캐시된 결과 문자열을 읽는다.
+///
+/// 날짜·파일 이름 등 자동 필드는 마지막으로 계산된 표시 문자열을
+/// 파라미터 셋의 문자열(BSTR) 아이템으로 보관한다.
+/// 비어 있지 않은 첫 문자열 아이템을 반환한다.
+///
+/// 파라미터 셋 구조: ID(2) + 아이템 수(2) + 아이템들
+/// 아이템 구조: ID(2) + 종류(2) + 값 (BSTR은 WORD 길이 + WCHAR 배열, 정수형은 4바이트)
+pub fn cached_result(ctrl_data: &[u8]) -> Option<String> {
+    let mut cursor = ctrl_data;
+    read_param_set(&mut cursor, 0)
+}
+
+/// 필드 CTRL_HEADER에서 명령 문자열을 읽는다.
+///
+/// 구조: ctrl_id(4) + 속성(4) + 기타 속성(1) + 명령 길이(2) + WCHAR 배열
+pub fn command(ctrl_header: &[u8]) -> Option<String> {
+    let mut cursor = ctrl_header.get(9..)?;
+    read_bstr(&mut cursor)
+}
+
+/// 필드 CTRL_HEADER에서 문서 안의 고유 필드 ID를 읽는다.
+///
+/// 구조: ctrl_id(4) + 속성(4) + 기타 속성(1) + 명령(WORD 길이 + WCHAR 배열) + ID(4)
+pub fn field_id(ctrl_header: &[u8]) -> Option<u32> {
+    let mut cursor = ctrl_header.get(9..)?;
+    read_bstr(&mut cursor)?;
+    cursor.read_u32::<LittleEndian>().ok()
+}
+
+/// 하이퍼링크 필드 명령에서 연결 대상(URL 등)을 읽는다.
+///
+/// 명령은 `대상;종류;...` 형식이며 대상 안의 `:`·`;` 등은 `\`로 이스케이프된다.
+/// (예: `http\://example.com;1;0;0;`)
+pub fn hyperlink_target(command: &str) -> Option<String> {
+    let mut target = String::new();
+    let mut chars = command.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => target.extend(chars.next()),
+            ';' => break,
+            c => target.push(c),
+        }
+    }
+    let target = target.trim();
+    (!target.is_empty()).then(|| target.to_string())
+}
+
+/// 상호 참조 필드 명령에서 참조 대상(책갈피 이름 등)을 읽는다.
+///
+/// 명령은 하이퍼링크와 같은 `대상;...` 형식이며, 대상 앞의 `?`·`#` 표시는 뗀다.
+pub fn cross_ref_target(command: &str) -> Option<String> {
+    let target = hyperlink_target(command)?;
+    let target = target.trim_start_matches(['?', '#']).trim();
+    (!target.is_empty()).then(|| target.to_string())
+}
+
+/// 날짜 필드의 날짜·시각
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FieldDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl FieldDate {
+    /// 요일 (0 = 일요일)
+    ///
+    /// 그레고리력 날짜를 1970-01-01(목요일) 기준 일수로 바꿔 계산한다.
+    fn weekday(&self) -> usize {
+        let (month, year) = (i64::from(self.month), i64::from(self.year));
+        let y = if month <= 2 { year - 1 } else { year };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let doy = (153 * ((month + 9) % 12) + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146_097 + doe - 719_468;
+        (days + 4).rem_euclid(7) as usize
+    }
+}
+
+/// 캐시된 날짜 표시 문자열에서 날짜·시각을 읽는다.
+///
+/// "2024-05-01", "2024년 5월 1일 오후 3:05"처럼 숫자 묶음을 차례로 연·월·일·시·분·초로
+/// 본다. 첫 묶음이 네 자리 연도가 아니거나 월·일이 범위를 벗어나면 `None`.
+/// "오후"·"PM"이 있으면 12시 전 시각을 오후로 바꾼다.
+pub fn parse_date(s: &str) -> Option<FieldDate> {
+    let numbers: Vec<&str> = s
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|n| !n.is_empty())
+        .collect();
+    if numbers.len() < 3 || numbers[0].len() != 4 {
+        return None;
+    }
+    let field = |i: usize| numbers.get(i).map_or(Some(0), |n| n.parse::<u8>().ok());
+    let mut date = FieldDate {
+        year: numbers[0].parse().ok()?,
+        month: field(1)?,
+        day: field(2)?,
+        hour: field(3)?,
+        minute: field(4)?,
+        second: field(5)?,
+    };
+    if !(1..=12).contains(&date.month) || !(1..=31).contains(&date.day) {
+        return None;
+    }
+    if date.hour < 12 && (s.contains("오후") || s.contains("PM")) {
+        date.hour += 12;
+    } else if date.hour == 12 && (s.contains("오전") || s.contains("AM")) {
+        date.hour = 0;
+    }
+    Some(date)
+}
+
+/// 요일 이름 (일요일부터)
+const WEEKDAYS: [&str; 7] = ["일", "월", "화", "수", "목", "금", "토"];
+
+/// 날짜 형식 코드로 날짜를 표시한다.
+///
+/// 같은 글자가 이어진 묶음이 하나의 코드다: `yyyy`/`yy` 연도, `M`/`MM` 월, `d`/`dd` 일,
+/// `ddd`/`dddd` 요일("수"/"수요일"), `H`/`HH` 24시간제 시, `h`/`hh` 12시간제 시,
+/// `m`/`mm` 분, `s`/`ss` 초, `tt` 오전·오후. 두 글자 코드는 0을 채워 두 자리로 쓴다.
+/// 그 밖의 글자("년", "월", 구두점 등)는 그대로 둔다. 연·월·일 코드가 하나도 없으면
+/// 형식 코드가 아닌 것으로 보고 `None`.
+pub fn format_date(format: &str, date: &FieldDate) -> Option<String> {
+    let mut out = String::new();
+    let mut has_date_code = false;
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut run = 1;
+        while chars.peek() == Some(&c) {
+            chars.next();
+            run += 1;
+        }
+        let number = |value: u8| {
+            if run >= 2 {
+                format!("{value:02}")
+            } else {
+                value.to_string()
+            }
+        };
+        let hour12 = match date.hour % 12 {
+            0 => 12,
+            h => h,
+        };
+        match c {
+            'y' | 'Y' if run >= 3 => out.push_str(&date.year.to_string()),
+            'y' | 'Y' => out.push_str(&format!("{:02}", date.year % 100)),
+            'M' => out.push_str(&number(date.month)),
+            'd' | 'D' if run >= 4 => {
+                out.push_str(WEEKDAYS[date.weekday()]);
+                out.push_str("요일");
+            }
+            'd' | 'D' if run == 3 => out.push_str(WEEKDAYS[date.weekday()]),
+            'd' | 'D' => out.push_str(&number(date.day)),
+            'H' => out.push_str(&number(date.hour)),
+            'h' => out.push_str(&number(hour12)),
+            'm' => out.push_str(&number(date.minute)),
+            's' | 'S' => out.push_str(&number(date.second)),
+            't' | 'T' => out.push_str(if date.hour < 12 { "오전" } else { "오후" }),
+            _ => {
+                out.extend(std::iter::repeat_n(c, run));
+                continue;
+            }
+        }
+        has_date_code |= matches!(c, 'y' | 'Y' | 'M' | 'd' | 'D');
+    }
+    has_date_code.then_some(out)
+}
+
+fn read_param_set(cursor: &mut &[u8], depth: usize) -> Option<String> {
+    if depth > MAX_SET_DEPTH {
+        return None;
+    }
+    let _set_id = cursor.read_u16::<LittleEndian>().ok()?;
+    let count = cursor.read_i16::<LittleEndian>().ok()?;
+
+    for _ in 0..count.max(0) {
+        let _item_id = cursor.read_u16::<LittleEndian>().ok()?;
+        let kind = cursor.read_u16::<LittleEndian>().ok()?;
+        match kind {
+            PIT_NULL => {}
+            PIT_BSTR => {
+                let s = read_bstr(cursor)?;
+                if !s.is_empty() {
+                    return Some(s);
+                }
+            }
+            PIT_I1..=PIT_UI => {
+                cursor.read_u32::<LittleEndian>().ok()?;
+            }
+            PIT_SET => {
+                if let Some(s) = read_param_set(cursor, depth + 1) {
+                    return Some(s);
+                }
+            }
+            // 배열/바이너리 데이터는 길이를 알 수 없으므로 중단
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// WORD 길이 + UTF-16LE 문자열
+fn read_bstr(cursor: &mut &[u8]) -> Option<String> {
+    let len = cursor.read_u16::<LittleEndian>().ok()? as usize;
+    let bytes = cursor.get(..len * 2)?;
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+    *cursor = &cursor[len * 2..];
+    Some(String::from_utf16_lossy(&units))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::utf16le;
+
+    fn bstr_item(id: u16, s: &str) -> Vec<u8> {
+        let mut out = id.to_le_bytes().to_vec();
+        out.extend_from_slice(&PIT_BSTR.to_le_bytes());
+        out.extend_from_slice(&(s.encode_utf16().count() as u16).to_le_bytes());
+        out.extend(utf16le(s));
+        out
+    }
+
+    #[test]
+    fn test_field_id() {
+        let mut header = b"em%%".to_vec();
+        header.extend_from_slice(&[0u8; 5]);
+        header.extend_from_slice(&2u16.to_le_bytes());
+        header.extend(utf16le("검토"));
+        assert_eq!(field_id(&header), None);
+        header.extend_from_slice(&0x1234u32.to_le_bytes());
+        assert_eq!(field_id(&header), Some(0x1234));
+        assert_eq!(command(&header).as_deref(), Some("검토"));
+    }
+
+    #[test]
+    fn test_cross_ref_target() {
+        assert_eq!(cross_ref_target("?#그림3;0;1;").as_deref(), Some("그림3"));
+        assert_eq!(cross_ref_target("fig\\;1;0").as_deref(), Some("fig;1"));
+        assert_eq!(cross_ref_target("?;0;"), None);
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let date = parse_date("2024-05-01").unwrap();
+        assert_eq!((date.year, date.month, date.day), (2024, 5, 1));
+        let date = parse_date("2024년 5월 1일 오후 3:05").unwrap();
+        assert_eq!((date.hour, date.minute), (15, 5));
+        // 12시는 오후면 그대로, 오전이면 자정 직후
+        let date = parse_date("2024년 5월 1일 오후 12:30").unwrap();
+        assert_eq!((date.hour, date.minute), (12, 30));
+        let date = parse_date("2024년 5월 1일 오전 12:30").unwrap();
+        assert_eq!((date.hour, date.minute), (0, 30));
+        let date = parse_date("2024-05-01 12:05 AM").unwrap();
+        assert_eq!(
+            format_date("yyyy-MM-dd H:mm tt", &date).as_deref(),
+            Some("2024-05-01 0:05 오전")
+        );
+        assert_eq!(parse_date("5월 1일"), None);
+        assert_eq!(parse_date("2024-13-01"), None);
+        assert_eq!(parse_date("report.hwp"), None);
+    }
+
+    #[test]
+    fn test_format_date() {
+        let date = parse_date("2024-01-07 09:03:00").unwrap();
+        let format = |code: &str| format_date(code, &date);
+        assert_eq!(format("yyyy년 M월 d일").as_deref(), Some("2024년 1월 7일"));
+        assert_eq!(format("yy.MM.dd (ddd)").as_deref(), Some("24.01.07 (일)"));
+        assert_eq!(format("dddd").as_deref(), Some("일요일"));
+        assert_eq!(
+            format("yyyy-MM-dd tt h:mm").as_deref(),
+            Some("2024-01-07 오전 9:03")
+        );
+        // 날짜 코드가 없으면 형식 코드가 아니다
+        assert_eq!(format("HH:mm"), None);
+        assert_eq!(format(""), None);
+
+        // 요일: 2000-02-29 화요일, 1970-01-01 목요일
+        assert_eq!(parse_date("2000-02-29").unwrap().weekday(), 2);
+        assert_eq!(parse_date("1970-01-01").unwrap().weekday(), 4);
+    }
+
+    #[test]
+    fn test_cached_result_bstr() {
+        let mut data = 0x021Bu16.to_le_bytes().to_vec();
+        data.extend_from_slice(&2i16.to_le_bytes());
+        // 정수 아이템 다음 문자열 아이템
+        data.extend_from_slice(&[0x01, 0x40, 0x04, 0x00, 7, 0, 0, 0]);
+        data.extend(bstr_item(0x4000, "2024년 5월 1일"));
+        assert_eq!(cached_result(&data).as_deref(), Some("2024년 5월 1일"));
+    }
+
+    #[test]
+    fn test_cached_result_nested_set() {
+        let mut inner = 0x0001u16.to_le_bytes().to_vec();
+        inner.extend_from_slice(&1i16.to_le_bytes());
+        inner.extend(bstr_item(0, "report.hwp"));
+
+        let mut data = 0x0002u16.to_le_bytes().to_vec();
+        data.extend_from_slice(&2i16.to_le_bytes());
+        data.extend(bstr_item(1, ""));
+        data.extend_from_slice(&[0x02, 0x00]);
+        data.extend_from_slice(&PIT_SET.to_le_bytes());
+        data.extend(inner);
+        assert_eq!(cached_result(&data).as_deref(), Some("report.hwp"));
+    }
+
+    #[test]
+    fn test_hyperlink_target() {
+        assert_eq!(
+            hyperlink_target("http\\://example.com/a\\;b;1;0;0;").as_deref(),
+            Some("http://example.com/a;b")
+        );
+        assert_eq!(hyperlink_target(";1;0;0;"), None);
+
+        let mut header = crate::hwp::control::CTRL_FIELD_HYPERLINK
+            .to_le_bytes()
+            .to_vec();
+        header.extend_from_slice(&[0u8; 5]);
+        header.extend_from_slice(&3u16.to_le_bytes());
+        header.extend(utf16le("a;1"));
+        assert_eq!(command(&header).as_deref(), Some("a;1"));
+    }
+
+    #[test]
+    fn test_cached_result_truncated() {
+        let mut data = 0u16.to_le_bytes().to_vec();
+        data.extend_from_slice(&1i16.to_le_bytes());
+        data.extend_from_slice(&[0, 0, 1, 0, 10, 0]); // BSTR 길이 10, 데이터 없음
+        assert_eq!(cached_result(&data), None);
+        assert_eq!(cached_result(&[]), None);
+    }
+}
@@ -0,0 +1,130 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// 양식 개체의 표시 이름과 값
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormField {
+    /// 캡션, 없으면 개체 이름
+    pub label: String,
+    /// 입력값 (입력 상자·콤보 상자의 `Text`, 확인란·라디오 단추의 `Value`)
+    pub value: String,
+}
+
+/// FORM_OBJECT 레코드에서 양식 개체의 이름과 값을 읽는다.
+///
+/// 구조: 형식 ID(4) + 형식 ID(4) + 속성 문자열 길이(2) + WCHAR 배열
+///
+/// 속성 문자열은 `이름:형식:값`을 공백으로 구분해 나열한 것이다.
+/// 문자열 값은 `Name:wstring:5:Edit1`처럼 글자 수가 앞에 오고,
+/// 중첩 집합은 `CommonSet:set:120:` 뒤에 하위 속성이 이어진다.
+pub fn parse_form_object(data: &[u8]) -> Option<FormField> {
+    let mut cursor = data.get(8..)?;
+    let len = cursor.read_u16::<LittleEndian>().ok()? as usize;
+    let bytes = cursor.get(..len * 2)?;
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+    let props = parse_properties(&String::from_utf16_lossy(&units));
+    let get = |key: &str| {
+        props
+            .iter()
+            .find(|(k, v)| *k == key && !v.is_empty())
+            .map(|(_, v)| v.clone())
+    };
+
+    let label = get("Caption").or_else(|| get("Name"))?;
+    let value = get("Text").or_else(|| get("Value")).unwrap_or_default();
+    Some(FormField { label, value })
+}
+
+/// 속성 문자열을 (이름, 값) 목록으로 펼친다. 중첩 집합은 평탄화한다.
+fn parse_properties(s: &str) -> Vec<(String, String)> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut props = Vec::new();
+    let mut pos = 0;
+
+    // `:`까지 읽기
+    let read_until_colon = |pos: &mut usize| -> Option<String> {
+        let start = *pos;
+        while *pos < chars.len() && chars[*pos] != ':' {
+            *pos += 1;
+        }
+        let token: String = chars.get(start..*pos)?.iter().collect();
+        *pos += 1;
+        (*pos <= chars.len()).then_some(token)
+    };
+
+    while pos < chars.len() {
+        if chars[pos].is_whitespace() {
+            pos += 1;
+            continue;
+        }
+        let Some(key) = read_until_colon(&mut pos) else {
+            break;
+        };
+        let Some(kind) = read_until_colon(&mut pos) else {
+            break;
+        };
+        match kind.as_str() {
+            "wstring" => {
+                let Some(len) = read_until_colon(&mut pos).and_then(|n| n.parse::<usize>().ok())
+                else {
+                    break;
+                };
+                let end = (pos + len).min(chars.len());
+                props.push((key, chars[pos..end].iter().collect()));
+                pos = end;
+            }
+            // 집합 길이는 건너뛰고 하위 속성을 이어서 읽는다
+            "set" => {
+                if read_until_colon(&mut pos).is_none() {
+                    break;
+                }
+            }
+            _ => {
+                let start = pos;
+                while pos < chars.len() && !chars[pos].is_whitespace() {
+                    pos += 1;
+                }
+                props.push((key, chars[start..pos].iter().collect()));
+            }
+        }
+    }
+    props
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::form_object_bytes as form_object;
+
+    #[test]
+    fn test_text_input_value() {
+        let data = form_object(
+            "TBox set:70:CommonSet:set:40: Name:wstring:5:Edit1 ForeColor:int:0 \
+             EditSet:set:20: Text:wstring:3:홍길동 MaxLength:int:20",
+        );
+        assert_eq!(
+            parse_form_object(&data),
+            Some(FormField {
+                label: "Edit1".into(),
+                value: "홍길동".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_checkbox_caption_and_value() {
+        let data =
+            form_object("CheckBox Name:wstring:6:Check1 Caption:wstring:4:동의 함 Value:int:1");
+        let field = parse_form_object(&data).unwrap();
+        assert_eq!(field.label, "동의 함");
+        assert_eq!(field.value, "1");
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(parse_form_object(&[]), None);
+        assert_eq!(parse_form_object(&form_object("Name:wstring:x")), None);
+    }
+}
@@ -21,7 +21,10 @@ pub struct FileHeader {
     pub flags: u32,
 }
 
-#[derive(Debug, Clone, Copy)]
+/// 파일 버전 (MM.nn.PP.rr)
+///
+/// 비교는 major → minor → build → revision 순의 사전식 순서를 따른다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct FileVersion {
     pub major: u8,
     pub minor: u8,
@@ -38,6 +41,19 @@ impl FileVersion {
             revision: (v & 0xFF) as u8,
         }
     }
+
+    /// 이 버전이 주어진 버전 이상인지 확인
+    ///
+    /// 마이너 버전 간에 레이아웃이 바뀐 레코드를 분기할 때 사용한다.
+    pub fn at_least(&self, major: u8, minor: u8, build: u8, revision: u8) -> bool {
+        *self
+            >= FileVersion {
+                major,
+                minor,
+                build,
+                revision,
+            }
+    }
 }
 
 impl std::fmt::Display for FileVersion {
@@ -99,7 +115,7 @@ mod tests {
 
     #[test]
     fn test_invalid_signature() {
-        let data = vec![0u8; 40];
+        let data = [0u8; 40];
         let result = FileHeader::from_reader(&mut &data[..]);
         assert!(matches!(result, Err(HwpError::InvalidSignature)));
     }
@@ -158,4 +174,34 @@ mod tests {
         let v = FileVersion::from_u32(0x05010207);
         assert_eq!(v.to_string(), "5.1.2.7");
     }
+
+    #[test]
+    fn test_file_version_ordering() {
+        let versions = [
+            FileVersion::from_u32(0x03000000),
+            FileVersion::from_u32(0x05000007),
+            FileVersion::from_u32(0x05000300),
+            FileVersion::from_u32(0x05010000),
+            FileVersion::from_u32(0x05010207),
+        ];
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+        }
+        // 하위 필드가 커도 상위 필드가 우선
+        assert!(FileVersion::from_u32(0x0400FFFF) < FileVersion::from_u32(0x05000000));
+        assert_eq!(
+            FileVersion::from_u32(0x05010207),
+            FileVersion::from_u32(0x05010207)
+        );
+    }
+
+    #[test]
+    fn test_file_version_at_least() {
+        let v = FileVersion::from_u32(0x05000300);
+        assert!(v.at_least(5, 0, 3, 0));
+        assert!(v.at_least(5, 0, 2, 9));
+        assert!(v.at_least(4, 9, 9, 9));
+        assert!(!v.at_least(5, 0, 3, 1));
+        assert!(!v.at_least(5, 1, 0, 0));
+    }
 }
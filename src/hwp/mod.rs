@@ -1,7 +1,14 @@
+pub mod bindata;
 pub mod control;
 pub mod crypto;
 pub mod docinfo;
+pub mod field;
+pub mod form;
 pub mod header;
+pub mod ole;
 pub mod para_text;
+pub mod paragraph;
 pub mod record;
+pub mod script;
 pub mod stream;
+pub mod summary;
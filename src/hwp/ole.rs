@@ -0,0 +1,255 @@
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::{open_file, HwpError, Result};
+
+/// OLE 헤더 크기 (섹터 0은 헤더 다음부터 시작)
+const OLE_HEADER_LEN: usize = 512;
+/// 헤더에 직접 기록되는 DIFAT 항목 수
+const HEADER_DIFAT_ENTRIES: usize = 109;
+/// 이 값 이상의 섹터 번호는 특수 값 (DIFSECT, FATSECT, ENDOFCHAIN, FREESECT)
+const MAX_REGULAR_SECTOR: u32 = 0xFFFF_FFFA;
+/// 사용하지 않는 섹터
+const FREE_SECTOR: u32 = 0xFFFF_FFFF;
+/// 헤더의 미니 스트림 경계 위치
+const MINI_STREAM_CUTOFF_OFFSET: u64 = 56;
+
+/// FILETIME 기준 시각(1601-01-01 UTC)과 유닉스 기준 시각 사이의 초
+const FILETIME_EPOCH_OFFSET_SECS: u64 = 11_644_473_600;
+
+/// 루트 디렉터리 항목의 (작성 시각, 수정 시각)을 읽는다.
+///
+/// FILETIME이 0(기록 안 됨)이면 `None`.
+pub fn root_timestamps<F: Read + Seek>(
+    comp: &cfb::CompoundFile<F>,
+) -> (Option<SystemTime>, Option<SystemTime>) {
+    let root = comp.root_entry();
+    (entry_time(root.created()), entry_time(root.modified()))
+}
+
+/// cfb가 FILETIME에서 변환한 시각을 걸러낸다.
+///
+/// cfb는 0을 1601-01-01로, 그 시각을 표현하지 못하는 플랫폼에서는 유닉스 기준
+/// 시각으로 바꾸므로 둘 다 기록 안 됨으로 본다.
+fn entry_time(time: SystemTime) -> Option<SystemTime> {
+    let zero = UNIX_EPOCH
+        .checked_sub(Duration::from_secs(FILETIME_EPOCH_OFFSET_SECS))
+        .unwrap_or(UNIX_EPOCH);
+    (time > zero && time != UNIX_EPOCH).then_some(time)
+}
+
+/// 루트 스토리지에서 이름이 `name`과 대소문자 구분 없이 같은 스토리지의 실제 이름을 찾는다.
+///
+/// 표준 HWP는 `BodyText`처럼 정해진 대소문자를 쓰지만, 일부 비표준 프로그램은
+/// `bodytext`처럼 다르게 기록한다. 디렉터리 트리 탐색에 기대지 않고 루트 항목을 모두
+/// 훑어 찾는다.
+pub fn find_root_storage<F: Read + Seek>(
+    comp: &cfb::CompoundFile<F>,
+    name: &str,
+) -> Option<String> {
+    comp.read_root_storage()
+        .find(|entry| entry.is_storage() && entry.name().eq_ignore_ascii_case(name))
+        .map(|entry| entry.name().to_string())
+}
+
+/// HWP(OLE) 파일을 열기 전에 잘림 여부를 검사하고 컴파운드 파일을 연다.
+///
+/// 연 뒤에는 디렉터리에 기록된 스트림 크기가 파일 크기를 넘지 않는지 검사한다.
+pub fn open_compound(path: &Path) -> Result<cfb::CompoundFile<File>> {
+    open_compound_reader(open_file(path)?)
+}
+
+/// [`open_compound`]와 같되, 파일 대신 임의의 `Read + Seek` 원본(메모리 버퍼 등)을 연다.
+pub fn open_compound_reader<F: Read + Seek>(mut reader: F) -> Result<cfb::CompoundFile<F>> {
+    check_not_truncated(&mut reader)?;
+    let file_len = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(0))?;
+    let comp = cfb::CompoundFile::open(reader)?;
+    check_stream_sizes(&comp, file_len)?;
+    Ok(comp)
+}
+
+/// 디렉터리 항목이 파일보다 큰 스트림을 선언하면 그 스트림 이름과 함께
+/// 파싱 오류를 반환한다.
+///
+/// 조작된 디렉터리는 스트림 크기를 부풀려 읽기가 엉뚱한 I/O 오류로 끝나거나
+/// 큰 버퍼를 할당하게 만든다. 스트림을 읽기 전에 미리 걸러낸다.
+pub fn check_stream_sizes<F: Read + Seek>(
+    comp: &cfb::CompoundFile<F>,
+    file_len: u64,
+) -> Result<()> {
+    match comp
+        .walk()
+        .find(|entry| entry.is_stream() && entry.len() > file_len)
+    {
+        Some(entry) => Err(HwpError::Parse(format!(
+            "stream {} claims {} bytes but the file is only {} bytes",
+            entry.path().display(),
+            entry.len(),
+            file_len
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// 헤더에 기록된 미니 스트림 경계(이보다 작은 스트림은 미니 섹터에 저장)를 읽는다.
+///
+/// cfb는 이 값을 공개하지 않으므로 헤더를 직접 읽는다. 읽은 뒤 원본 위치는 처음으로 되돌린다.
+pub fn read_mini_stream_cutoff<R: Read + Seek>(reader: &mut R) -> Result<u32> {
+    let mut buf = [0u8; 4];
+    reader.seek(SeekFrom::Start(MINI_STREAM_CUTOFF_OFFSET))?;
+    reader.read_exact(&mut buf)?;
+    reader.seek(SeekFrom::Start(0))?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+/// 파일 크기를 OLE 할당 테이블(FAT)이 사용 중으로 선언한 섹터 범위와 비교한다.
+///
+/// 다운로드가 중간에 끊긴 파일은 헤더는 정상이지만 뒤쪽 섹터가 없어서
+/// 스트림 읽기 도중 일반 I/O 오류로 실패한다. 미리 검사하여
+/// `file appears truncated` 파싱 오류로 구분해 보고한다.
+///
+/// 헤더에 기록된 FAT 섹터(최대 109개)만 검사하며, 헤더가 OLE 형식이 아니면
+/// 판단을 cfb에 맡긴다.
+pub fn check_not_truncated<R: Read + Seek>(reader: &mut R) -> Result<()> {
+    let file_len = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut header = [0u8; OLE_HEADER_LEN];
+    if reader.read_exact(&mut header).is_err() {
+        return Ok(());
+    }
+    let sector_shift = u16::from_le_bytes([header[30], header[31]]);
+    if !(9..=12).contains(&sector_shift) {
+        return Ok(());
+    }
+    let sector_size = 1u64 << sector_shift;
+    let sector_offset = |sector: u32| (sector as u64 + 1) * sector_size;
+
+    let fat_count = u32::from_le_bytes([header[44], header[45], header[46], header[47]]) as usize;
+    let fat_sectors: Vec<u32> = header[76..]
+        .chunks_exact(4)
+        .take(fat_count.min(HEADER_DIFAT_ENTRIES))
+        .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+        .filter(|&s| s < MAX_REGULAR_SECTOR)
+        .collect();
+
+    // FAT 섹터 자체가 파일 밖에 있으면 이미 잘린 것
+    let mut required = fat_sectors
+        .iter()
+        .map(|&s| sector_offset(s) + sector_size)
+        .max()
+        .unwrap_or(0);
+
+    if required <= file_len {
+        // FAT에서 사용 중인 가장 큰 섹터 번호 찾기
+        let mut buf = vec![0u8; sector_size as usize];
+        for (fat_idx, &fat_sector) in fat_sectors.iter().enumerate() {
+            reader.seek(SeekFrom::Start(sector_offset(fat_sector)))?;
+            reader.read_exact(&mut buf)?;
+            let entries_per_sector = buf.len() / 4;
+            let last_used = buf
+                .chunks_exact(4)
+                .rposition(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]) != FREE_SECTOR);
+            if let Some(i) = last_used {
+                let sector = (fat_idx * entries_per_sector + i) as u32;
+                required = required.max(sector_offset(sector) + sector_size);
+            }
+        }
+    }
+
+    if file_len < required {
+        return Err(HwpError::Parse(format!(
+            "file appears truncated ({} of {} bytes)",
+            file_len, required
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    use crate::test_util;
+
+    fn sample() -> Vec<u8> {
+        // 미니 스트림 경계(4096)를 넘는 스트림으로 일반 섹터를 여러 개 사용
+        test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/BodyText/Section0", vec![0xAB; 10_000]),
+        ])
+    }
+
+    #[test]
+    fn test_root_timestamps() {
+        let comp = open_compound_reader(Cursor::new(sample())).unwrap();
+        assert_eq!(root_timestamps(&comp), (None, None));
+
+        let created = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let modified = created + Duration::from_millis(86_400_500);
+        let mut comp = cfb::CompoundFile::open(Cursor::new(sample())).unwrap();
+        comp.set_created_time("/", created).unwrap();
+        comp.set_modified_time("/", modified).unwrap();
+        comp.flush().unwrap();
+        let data = comp.into_inner().into_inner();
+
+        let comp = open_compound_reader(Cursor::new(data)).unwrap();
+        assert_eq!(root_timestamps(&comp), (Some(created), Some(modified)));
+    }
+
+    #[test]
+    fn test_read_mini_stream_cutoff() {
+        let mut reader = Cursor::new(sample());
+        assert_eq!(read_mini_stream_cutoff(&mut reader).unwrap(), 4096);
+        assert_eq!(reader.position(), 0);
+        assert!(read_mini_stream_cutoff(&mut Cursor::new(vec![0u8; 40])).is_err());
+    }
+
+    #[test]
+    fn test_complete_file_passes() {
+        let data = sample();
+        assert!(check_not_truncated(&mut Cursor::new(data)).is_ok());
+    }
+
+    #[test]
+    fn test_truncated_file_detected() {
+        let mut data = sample();
+        data.truncate(data.len() - 4096);
+        match check_not_truncated(&mut Cursor::new(data)) {
+            Err(HwpError::Parse(msg)) => assert!(msg.starts_with("file appears truncated")),
+            other => panic!("expected truncation error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_oversized_stream_entry_detected() {
+        let mut data = sample();
+        assert!(check_stream_sizes(
+            &cfb::CompoundFile::open(Cursor::new(data.clone())).unwrap(),
+            data.len() as u64
+        )
+        .is_ok());
+
+        // Section0 디렉터리 항목(이름 UTF-16 + ... + 크기 @120)의 크기를 부풀린다
+        let name = test_util::utf16le("Section0");
+        let entry = data.windows(name.len()).position(|w| w == name).unwrap();
+        data[entry + 120..entry + 128].copy_from_slice(&10_000_000u64.to_le_bytes());
+
+        let file_len = data.len() as u64;
+        let comp = cfb::CompoundFile::open(Cursor::new(data)).unwrap();
+        match check_stream_sizes(&comp, file_len) {
+            Err(HwpError::Parse(msg)) => assert!(msg.contains("Section0"), "got: {msg}"),
+            other => panic!("expected oversized stream error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_non_ole_data_is_left_to_cfb() {
+        assert!(check_not_truncated(&mut Cursor::new(vec![0u8; 100])).is_ok());
+        assert!(check_not_truncated(&mut Cursor::new(vec![0u8; 1024])).is_ok());
+    }
+}
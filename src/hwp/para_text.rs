@@ -1,3 +1,6 @@
+use crate::hwp::docinfo::DEFAULT_TAB_WIDTH;
+use crate::options::ExtractOptions;
+
 /// 문자 타입
 #[derive(Debug, PartialEq)]
 pub enum CharType {
@@ -38,6 +41,78 @@ pub struct TextSegment {
     pub text: String,
     /// 이 세그먼트 뒤에 ControlExtend가 있는지
     pub has_control_after: bool,
+    /// 세그먼트 뒤 ControlExtend의 코드 (`has_control_after`일 때만 `Some`)
+    pub control_code: Option<u16>,
+    /// 세그먼트 안에서 처음 나오는 필드 끝(code 4)의 `text` 내 바이트 위치
+    ///
+    /// 필드 시작(code 3)은 ControlExtend이므로 필드의 표시 문자열은
+    /// 다음 세그먼트의 `text[..field_end]`가 된다.
+    pub field_end: Option<usize>,
+}
+
+/// 일반 문자 코드 유닛을 문자로 바꾼다.
+///
+/// 상위 서로게이트는 `data[pos..]`의 다음 유닛이 하위 서로게이트이면 합쳐 BMP 밖 문자
+/// (이모지, 확장 한자 등)로 만들고 `pos`를 그만큼 옮긴다. 짝이 없는 서로게이트는 `None`.
+fn decode_unit(code: u16, data: &[u8], pos: &mut usize) -> Option<char> {
+    if !(0xD800..0xDC00).contains(&code) {
+        return char::from_u32(code as u32);
+    }
+    let low = u16::from_le_bytes([*data.get(*pos)?, *data.get(*pos + 1)?]);
+    if !(0xDC00..0xE000).contains(&low) {
+        return None;
+    }
+    *pos += 2;
+    char::from_u32(0x10000 + ((u32::from(code) - 0xD800) << 10) + (u32::from(low) - 0xDC00))
+}
+
+/// PARA_TEXT 레코드 하나의 바이트를 텍스트로 디코딩한다.
+///
+/// 본문 추출과 같은 규칙을 따른다: 서로게이트 쌍은 한 문자로 합치고, 줄바꿈은 `\n`,
+/// 묶음·고정폭 빈칸은 공백, 하이픈은 `-`로 바꾸며, 문단 끝과 짝 없는 서로게이트는
+/// 버린다. 표·각주 등 컨트롤 자리에는 아무것도 넣지 않는다 (컨트롤 내용은 별도
+/// 레코드에 있다). [`ExtractOptions::expand_tabs`]이면 탭을 기록된 폭(없으면 문서 기본
+/// 탭 간격)만큼의 공백으로 펼친다.
+///
+/// # Examples
+///
+/// ```
+/// use hwarang::hwp::para_text;
+/// use hwarang::ExtractOptions;
+///
+/// // "가" + 탭(폭 없음) + "😀" (서로게이트 쌍) + 문단 끝
+/// let mut data: Vec<u8> = "가".encode_utf16().flat_map(u16::to_le_bytes).collect();
+/// data.extend_from_slice(&9u16.to_le_bytes());
+/// data.extend_from_slice(&[0; 14]);
+/// data.extend("😀".encode_utf16().flat_map(u16::to_le_bytes));
+/// data.extend_from_slice(&13u16.to_le_bytes());
+///
+/// assert_eq!(para_text::decode(&data, &ExtractOptions::default()), "가\t😀");
+///
+/// let opts = ExtractOptions { expand_tabs: true, ..Default::default() };
+/// assert_eq!(para_text::decode(&data, &opts), "가    😀");
+/// ```
+pub fn decode(data: &[u8], opts: &ExtractOptions) -> String {
+    let tab_width = opts.expand_tabs.then_some(DEFAULT_TAB_WIDTH);
+    extract_text_segments_with_tabs(data, tab_width)
+        .into_iter()
+        .map(|seg| seg.text)
+        .collect()
+}
+
+/// 탭을 공백으로 펼칠 때 공백 한 칸에 해당하는 폭 (HWPUNIT, 10pt)
+pub const HWPUNIT_PER_SPACE: u32 = 1000;
+
+/// 탭 폭(HWPUNIT)을 공백 개수로 환산한다 (반올림, 최소 1칸).
+pub fn tab_spaces(width: u32) -> usize {
+    ((width + HWPUNIT_PER_SPACE / 2) / HWPUNIT_PER_SPACE).max(1) as usize
+}
+
+/// 탭 컨트롤 부가 정보(코드 뒤 14바이트)의 첫 4바이트에 기록된 탭 폭 (HWPUNIT)
+fn inline_tab_width(addition: &[u8]) -> Option<u32> {
+    let bytes = addition.get(..4)?;
+    let width = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    (width > 0).then_some(width)
 }
 
 /// PARA_TEXT 레코드 데이터를 모든 ControlExtend 위치에서 분할하여 세그먼트 목록을 반환한다.
@@ -45,53 +120,189 @@ pub struct TextSegment {
 /// 모든 ControlExtend에서 분할하여, 대응하는 CTRL_HEADER 서브트리와 1:1 매칭할 수 있게 한다.
 /// 텍스트가 없는 컨트롤(구역정의 등)의 서브트리는 재귀 시 자연스럽게 빈 출력을 생성한다.
 pub fn extract_text_segments(data: &[u8]) -> Vec<TextSegment> {
+    extract_text_segments_with_tabs(data, None)
+}
+
+/// [`extract_text_segments`]와 같되, `default_tab_width`가 주어지면 탭을 공백으로 펼친다.
+///
+/// 탭 컨트롤에 기록된 폭을 우선 사용하고, 폭이 없으면 `default_tab_width`(HWPUNIT)를 쓴다.
+pub fn extract_text_segments_with_tabs(
+    data: &[u8],
+    default_tab_width: Option<u32>,
+) -> Vec<TextSegment> {
+    extract_text_segments_marked(data, default_tab_width, &[])
+}
+
+/// PARA_TEXT의 ControlExtend 개수 (세그먼트 수 - 1)
+fn count_extended_controls(data: &[u8]) -> usize {
+    let mut count = 0;
+    let mut pos = 0;
+    while pos + 1 < data.len() {
+        let code = u16::from_le_bytes([data[pos], data[pos + 1]]);
+        pos += 2;
+        match char_type(code) {
+            CharType::ControlExtend => {
+                count += 1;
+                pos += 14;
+            }
+            CharType::ControlInline => pos += 14,
+            _ => {}
+        }
+    }
+    count
+}
+
+/// 글자 범위 표시 상태: 현재 여는/닫는 표시 문자열과 `current`에 여는 표시를 넣었는지
+struct RunMark<'m> {
+    start: &'m str,
+    end: &'m str,
+    open: bool,
+}
+
+impl RunMark<'_> {
+    /// 표시할 글자를 넣기 전에 호출: 아직 열지 않았으면 여는 표시를 넣는다.
+    fn open(&mut self, current: &mut String) {
+        if !self.open && !self.start.is_empty() {
+            current.push_str(self.start);
+            self.open = true;
+        }
+    }
+
+    /// 열린 표시가 있으면 닫는 표시를 넣는다.
+    fn close(&mut self, current: &mut String) {
+        if self.open {
+            current.push_str(self.end);
+            self.open = false;
+        }
+    }
+}
+
+/// [`extract_text_segments_with_tabs`]와 같되, 글자 범위를 표시 문자열로 감싼다.
+///
+/// `marks`는 (시작 위치, 여는 표시, 닫는 표시) 목록으로, 위치는 PARA_TEXT 안의 UTF-16
+/// 코드 유닛 오프셋(PARA_CHAR_SHAPE와 같은 기준)이며 오름차순이다. 각 범위는 다음 항목
+/// 전까지이고, 여는 표시가 빈 범위는 표시하지 않는다. 표시는 줄바꿈과 세그먼트 경계에서 닫았다가
+/// 다음 글자 앞에서 다시 열어 각 줄이 짝을 이루게 한다.
+pub fn extract_text_segments_marked(
+    data: &[u8],
+    default_tab_width: Option<u32>,
+    marks: &[(u32, &str, &str)],
+) -> Vec<TextSegment> {
+    extract_text_segments_with_breaks(data, default_tab_width, marks, &[])
+}
+
+/// [`extract_text_segments_marked`]와 같되, `breaks`의 각 위치(UTF-16 코드 유닛 오프셋,
+/// 오름차순) 앞에 줄바꿈을 넣는다.
+///
+/// PARA_LINE_SEG의 줄 시작 위치를 넘겨 화면의 줄 나눔을 재현한다. 0 위치와 이미
+/// 줄바꿈 바로 뒤인 위치는 건너뛴다.
+pub fn extract_text_segments_with_breaks(
+    data: &[u8],
+    default_tab_width: Option<u32>,
+    marks: &[(u32, &str, &str)],
+    breaks: &[u32],
+) -> Vec<TextSegment> {
     let len = data.len();
-    let mut segments = Vec::new();
-    let mut current = String::with_capacity(len / 2);
+    let mut segments = Vec::with_capacity(count_extended_controls(data) + 1);
+    // 세그먼트 사이에 재사용하는 버퍼: UTF-16 코드 유닛 하나는 UTF-8로 최대 3바이트라
+    // 표시·탭 펼침이 없으면 다시 할당하지 않는다.
+    let mut current = String::with_capacity(len / 2 * 3);
+    let mut field_end = None;
     let mut pos = 0;
+    let mut next_mark = 0;
+    let mut next_break = 0;
+    let mut run = RunMark {
+        start: "",
+        end: "",
+        open: false,
+    };
 
     while pos + 1 < len {
+        let unit = (pos / 2) as u32;
+        while next_mark < marks.len() && marks[next_mark].0 <= unit {
+            let (_, start, end) = marks[next_mark];
+            if (start, end) != (run.start, run.end) {
+                run.close(&mut current);
+                run.start = start;
+                run.end = end;
+            }
+            next_mark += 1;
+        }
+        while next_break < breaks.len() && breaks[next_break] <= unit {
+            if breaks[next_break] == unit && unit > 0 && !current.ends_with('\n') {
+                run.close(&mut current);
+                current.push('\n');
+            }
+            next_break += 1;
+        }
+
         let code = u16::from_le_bytes([data[pos], data[pos + 1]]);
         pos += 2;
 
         match char_type(code) {
             CharType::Normal => {
-                if let Some(ch) = char::from_u32(code as u32) {
+                if let Some(ch) = decode_unit(code, data, &mut pos) {
+                    run.open(&mut current);
                     current.push(ch);
                 }
             }
             CharType::ControlChar => match code {
-                10 => current.push('\n'),
+                10 => {
+                    run.close(&mut current);
+                    current.push('\n');
+                }
                 13 => {}
-                24 => current.push('-'),
-                30 => current.push(' '),
-                31 => current.push(' '),
+                24 => {
+                    run.open(&mut current);
+                    current.push('-');
+                }
+                30 | 31 => {
+                    run.open(&mut current);
+                    current.push(' ');
+                }
                 _ => {}
             },
             CharType::ControlInline => {
                 let skip = 14.min(len - pos);
-                pos += skip;
                 if code == 9 {
-                    current.push('\t');
+                    run.open(&mut current);
+                    match default_tab_width {
+                        Some(default) => {
+                            let width = inline_tab_width(&data[pos..pos + skip]).unwrap_or(default);
+                            current.extend(std::iter::repeat_n(' ', tab_spaces(width)));
+                        }
+                        None => current.push('\t'),
+                    }
+                } else if code == 4 && field_end.is_none() {
+                    run.close(&mut current);
+                    field_end = Some(current.len());
                 }
+                pos += skip;
             }
             CharType::ControlExtend => {
                 let skip = 14.min(len - pos);
                 pos += skip;
 
                 // 모든 ControlExtend에서 분할
+                run.close(&mut current);
                 segments.push(TextSegment {
-                    text: std::mem::take(&mut current),
+                    text: current.as_str().to_owned(),
                     has_control_after: true,
+                    control_code: Some(code),
+                    field_end: field_end.take(),
                 });
+                current.clear();
             }
         }
     }
+    run.close(&mut current);
 
     // 마지막 세그먼트
     segments.push(TextSegment {
         text: current,
         has_control_after: false,
+        control_code: None,
+        field_end,
     });
 
     segments
@@ -114,8 +325,7 @@ pub fn extract_text(data: &[u8]) -> (String, Vec<u16>) {
 
         match char_type(code) {
             CharType::Normal => {
-                // UTF-16LE 단일 코드 유닛 → char (BMP)
-                if let Some(ch) = char::from_u32(code as u32) {
+                if let Some(ch) = decode_unit(code, data, &mut pos) {
                     text.push(ch);
                 }
             }
@@ -172,6 +382,21 @@ mod tests {
         assert_eq!(char_type(17), CharType::ControlExtend); // footnote/endnote
     }
 
+    #[test]
+    fn test_surrogate_pairs() {
+        use crate::test_util::utf16le;
+        let mut data = utf16le("A😀");
+        data.extend_from_slice(&0xD800u16.to_le_bytes()); // 짝 없는 상위 서로게이트
+        data.extend(utf16le("B𠀀"));
+        data.extend_from_slice(&0xDC00u16.to_le_bytes()); // 짝 없는 하위 서로게이트
+        assert_eq!(extract_text(&data).0, "A😀B𠀀");
+        assert_eq!(extract_text_segments(&data)[0].text, "A😀B𠀀");
+        assert_eq!(decode(&data, &ExtractOptions::default()), "A😀B𠀀");
+
+        // 끝에서 잘린 쌍
+        assert_eq!(extract_text(&utf16le("😀")[..2]).0, "");
+    }
+
     #[test]
     fn test_extract_simple_text() {
         // "AB" in UTF-16LE
@@ -280,4 +505,107 @@ mod tests {
         assert_eq!(segments[1].text, "B");
         assert!(!segments[1].has_control_after);
     }
+
+    #[test]
+    fn test_extract_segments_field_end() {
+        // "A" + 필드 시작(3) + "2024" + 필드 끝(4) + "B"
+        let mut data = vec![0x41, 0x00];
+        data.extend_from_slice(&[0x03, 0x00]);
+        data.extend_from_slice(&[0u8; 14]);
+        data.extend_from_slice(&[0x32, 0x00, 0x30, 0x00, 0x32, 0x00, 0x34, 0x00]);
+        data.extend_from_slice(&[0x04, 0x00]);
+        data.extend_from_slice(&[0u8; 14]);
+        data.extend_from_slice(&[0x42, 0x00]);
+
+        let segments = extract_text_segments(&data);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].field_end, None);
+        assert_eq!(segments[1].text, "2024B");
+        assert_eq!(segments[1].field_end, Some(4));
+    }
+
+    /// 폭(HWPUNIT)이 기록된 탭 컨트롤
+    fn tab_control(width: u32) -> Vec<u8> {
+        let mut data = vec![0x09, 0x00];
+        data.extend_from_slice(&width.to_le_bytes());
+        data.extend_from_slice(&[0u8; 10]);
+        data
+    }
+
+    #[test]
+    fn test_tab_spaces() {
+        assert_eq!(tab_spaces(4000), 4);
+        assert_eq!(tab_spaces(2600), 3);
+        assert_eq!(tab_spaces(100), 1);
+        assert_eq!(tab_spaces(0), 1);
+    }
+
+    #[test]
+    fn test_extract_segments_expand_tabs() {
+        // "A" + tab(폭 2000) + "B" + tab(폭 없음) + "C"
+        let mut data = vec![0x41, 0x00];
+        data.extend(tab_control(2000));
+        data.extend_from_slice(&[0x42, 0x00]);
+        data.extend(tab_control(0));
+        data.extend_from_slice(&[0x43, 0x00]);
+
+        let segments = extract_text_segments_with_tabs(&data, Some(3000));
+        assert_eq!(segments[0].text, "A  B   C");
+
+        // 펼치지 않으면 기존처럼 \t 유지
+        let segments = extract_text_segments(&data);
+        assert_eq!(segments[0].text, "A\tB\tC");
+    }
+
+    #[test]
+    fn test_extract_segments_marked() {
+        // "가나" + 줄바꿈 + "다" + 표(ControlExtend) + "라마"
+        use crate::test_util::utf16le;
+        let mut data = utf16le("가나\n다");
+        data.extend_from_slice(&11u16.to_le_bytes());
+        data.extend_from_slice(&[0u8; 14]);
+        data.extend(utf16le("라마"));
+        // 위치 1부터 취소선, 마지막 글자(위치 13)에서 해제
+        let marks = [(0, "", ""), (1, "~~", "~~"), (13, "", "")];
+        let segs = extract_text_segments_marked(&data, None, &marks);
+        assert_eq!(segs.len(), 2);
+        assert_eq!(segs[0].text, "가~~나~~\n~~다~~");
+        assert_eq!(segs[1].text, "~~라~~마");
+
+        // 표시가 없으면 기존 결과와 같다
+        let plain = extract_text_segments_marked(&data, None, &[(0, "", "")]);
+        assert_eq!(plain[0].text, "가나\n다");
+    }
+
+    #[test]
+    fn test_segments_with_breaks() {
+        let utf16 = |s: &str| -> Vec<u8> { s.encode_utf16().flat_map(u16::to_le_bytes).collect() };
+        // "가나 다라\n마바": 줄 시작 0, 3, 6(강제 줄바꿈 뒤)
+        let data = utf16("가나 다라\n마바");
+        let segments = extract_text_segments_with_breaks(&data, None, &[], &[0, 3, 6]);
+        assert_eq!(segments[0].text, "가나 \n다라\n마바");
+
+        // 표시는 줄 끝에서 닫고 다음 줄에서 다시 연다
+        let segments = extract_text_segments_with_breaks(&data, None, &[(0, "~~", "~~")], &[3]);
+        assert_eq!(segments[0].text, "~~가나 ~~\n~~다라~~\n~~마바~~");
+    }
+
+    #[test]
+    fn test_segments_presized_for_controls() {
+        // 탭(inline) 하나와 표(extend) 세 개: 부가 정보 14바이트는 개수에 영향이 없다
+        let mut data = Vec::new();
+        for code in [9u16, 11, 11, 11] {
+            data.extend("가".encode_utf16().flat_map(u16::to_le_bytes));
+            data.extend_from_slice(&code.to_le_bytes());
+            data.extend_from_slice(&[11, 0].repeat(7));
+        }
+        data.extend("끝".encode_utf16().flat_map(u16::to_le_bytes));
+        assert_eq!(count_extended_controls(&data), 3);
+
+        let segments = extract_text_segments(&data);
+        assert_eq!(segments.capacity(), 4);
+        let texts: Vec<_> = segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, ["가\t가", "가", "가", "끝"]);
+        assert_eq!(segments[0].text.capacity(), segments[0].text.len());
+    }
 }
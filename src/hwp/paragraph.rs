@@ -1 +1,148 @@
-// 문단 레코드 시퀀스 파싱 (Iter hwp06에서 구현)
+/// 문단 헤더 (PARA_HEADER)
+#[derive(Debug, Clone, Default)]
+pub struct ParaHeader {
+    /// 문단 글자 수 (UTF-16 코드 유닛, 컨트롤 포함)
+    pub text_len: u32,
+    /// 문단에 포함된 컨트롤 종류 비트마스크
+    pub control_mask: u32,
+    pub para_shape_id: u16,
+    pub style_id: u8,
+    /// 단 나누기 종류 (구역/다단/쪽/단 나누기 비트)
+    pub break_type: u8,
+}
+
+/// PARA_HEADER 레코드 파싱
+///
+/// 구조: 글자 수(4, 최상위 비트는 플래그) + 컨트롤 마스크(4) + 문단 모양 ID(2)
+/// + 스타일 ID(1) + 단 나누기 종류(1) + ...
+pub fn parse_para_header(data: &[u8]) -> Option<ParaHeader> {
+    let d = data.get(..12)?;
+    Some(ParaHeader {
+        text_len: u32::from_le_bytes([d[0], d[1], d[2], d[3]]) & 0x7FFF_FFFF,
+        control_mask: u32::from_le_bytes([d[4], d[5], d[6], d[7]]),
+        para_shape_id: u16::from_le_bytes([d[8], d[9]]),
+        style_id: d[10],
+        break_type: d[11],
+    })
+}
+
+/// PARA_CHAR_SHAPE 레코드 파싱: (글자 위치, 글자 모양 ID) 목록
+///
+/// 구조: [글자 위치(4, UTF-16 코드 유닛) + 글자 모양 ID(4)] 반복.
+/// 각 항목은 그 위치부터 다음 항목 전까지의 글자 모양을 정한다.
+pub fn parse_char_shape_runs(data: &[u8]) -> Vec<(u32, u32)> {
+    data.chunks_exact(8)
+        .map(|c| {
+            (
+                u32::from_le_bytes([c[0], c[1], c[2], c[3]]),
+                u32::from_le_bytes([c[4], c[5], c[6], c[7]]),
+            )
+        })
+        .collect()
+}
+
+/// PARA_LINE_SEG 레코드에서 줄마다 첫 글자 위치를 읽는다.
+///
+/// 구조: [글자 시작 위치(4, UTF-16 코드 유닛) + 세로 위치(4) + 줄 높이(4) + 텍스트 높이(4) +
+/// 기준선 거리(4) + 줄 간격(4) + 단 시작 위치(4) + 세그먼트 폭(4) + 태그(4)] 반복.
+pub fn parse_line_starts(data: &[u8]) -> Vec<u32> {
+    data.chunks_exact(LINE_SEG_LEN)
+        .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+        .collect()
+}
+
+/// PARA_LINE_SEG 항목 하나의 크기
+const LINE_SEG_LEN: usize = 36;
+
+/// 문단의 영역 태그 (PARA_RANGE_TAG 항목)
+///
+/// 형광펜·변경 추적처럼 문단 텍스트의 한 구간에 붙는 정보다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeTag {
+    /// 시작 위치 (UTF-16 코드 유닛)
+    pub start: u32,
+    /// 끝 위치 (UTF-16 코드 유닛, 제외)
+    pub end: u32,
+    /// 태그 종류 (상위 8비트)
+    pub kind: u8,
+    /// 태그 데이터 (하위 24비트, 종류에 따라 ID 등)
+    pub data: u32,
+}
+
+/// PARA_RANGE_TAG 레코드 파싱
+///
+/// 구조: [시작(4) + 끝(4) + 태그(4, 상위 8비트 종류 + 하위 24비트 데이터)] 반복
+pub fn parse_range_tags(data: &[u8]) -> Vec<RangeTag> {
+    data.chunks_exact(12)
+        .map(|c| {
+            let tag = u32::from_le_bytes([c[8], c[9], c[10], c[11]]);
+            RangeTag {
+                start: u32::from_le_bytes([c[0], c[1], c[2], c[3]]),
+                end: u32::from_le_bytes([c[4], c[5], c[6], c[7]]),
+                kind: (tag >> 24) as u8,
+                data: tag & 0x00FF_FFFF,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_para_header() {
+        let mut data = vec![0u8; 22];
+        data[..4].copy_from_slice(&(0x8000_0005u32).to_le_bytes());
+        data[4..8].copy_from_slice(&(1u32 << 11).to_le_bytes());
+        data[8..10].copy_from_slice(&7u16.to_le_bytes());
+        data[10] = 3;
+        data[11] = 0x04;
+
+        let header = parse_para_header(&data).unwrap();
+        assert_eq!(header.text_len, 5);
+        assert_eq!(header.control_mask, 1 << 11);
+        assert_eq!(header.para_shape_id, 7);
+        assert_eq!(header.style_id, 3);
+        assert_eq!(header.break_type, 0x04);
+    }
+
+    #[test]
+    fn test_parse_para_header_too_short() {
+        assert!(parse_para_header(&[0u8; 11]).is_none());
+        assert!(parse_para_header(&[]).is_none());
+    }
+
+    #[test]
+    fn test_parse_char_shape_runs() {
+        let data = [0u32, 3, 5, 7, 9].map(u32::to_le_bytes).concat();
+        assert_eq!(parse_char_shape_runs(&data), [(0, 3), (5, 7)]);
+    }
+
+    #[test]
+    fn test_parse_line_starts() {
+        let mut data = Vec::new();
+        for start in [0u32, 12, 31] {
+            data.extend_from_slice(&start.to_le_bytes());
+            data.extend_from_slice(&[0u8; 32]);
+        }
+        data.extend_from_slice(&[0u8; 8]); // 잘린 항목은 무시
+        assert_eq!(parse_line_starts(&data), [0, 12, 31]);
+    }
+
+    #[test]
+    fn test_parse_range_tags() {
+        let data = [2u32, 6, (3 << 24) | 0x12, 0]
+            .map(u32::to_le_bytes)
+            .concat();
+        assert_eq!(
+            parse_range_tags(&data),
+            [RangeTag {
+                start: 2,
+                end: 6,
+                kind: 3,
+                data: 0x12,
+            }]
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::{ControlFlow, Deref};
+
 use crate::error::{HwpError, Result};
 
 /// HWP 태그 상수 (BEGIN = 0x10)
@@ -5,6 +7,11 @@ pub const HWPTAG_BEGIN: u16 = 0x10;
 
 // DocInfo 태그
 pub const HWPTAG_DOCUMENT_PROPERTIES: u16 = HWPTAG_BEGIN;
+pub const HWPTAG_BIN_DATA: u16 = HWPTAG_BEGIN + 2;
+pub const HWPTAG_FACE_NAME: u16 = HWPTAG_BEGIN + 3;
+pub const HWPTAG_CHAR_SHAPE: u16 = HWPTAG_BEGIN + 5;
+pub const HWPTAG_PARA_SHAPE: u16 = HWPTAG_BEGIN + 9;
+pub const HWPTAG_STYLE: u16 = HWPTAG_BEGIN + 10;
 
 // BodyText 태그
 pub const HWPTAG_PARA_HEADER: u16 = HWPTAG_BEGIN + 50;
@@ -48,10 +55,40 @@ pub struct RecordHeader {
 }
 
 impl RecordHeader {
+    /// DocInfo 스트림에 나오는 태그인지 (문서 속성, 글꼴, 모양, 스타일 등)
+    pub fn is_docinfo_tag(&self) -> bool {
+        (HWPTAG_BEGIN..=HWPTAG_BEGIN + 16).contains(&self.tag_id)
+            || matches!(self.tag_id, HWPTAG_MEMO_SHAPE | HWPTAG_FORBIDDEN_CHAR)
+    }
+
+    /// 문단 구성 태그인지 (PARA_HEADER/TEXT/CHAR_SHAPE/LINE_SEG/RANGE_TAG)
+    pub fn is_paragraph_tag(&self) -> bool {
+        (HWPTAG_PARA_HEADER..=HWPTAG_PARA_RANGE_TAG).contains(&self.tag_id)
+    }
+
+    /// 그리기 개체 요소 태그인지 (SHAPE_COMPONENT 및 선/사각형/.../묶음/글맵시)
+    pub fn is_shape_component(&self) -> bool {
+        matches!(
+            self.tag_id,
+            HWPTAG_SHAPE_COMPONENT | HWPTAG_SHAPE_COMPONENT_LINE
+                ..=HWPTAG_SHAPE_COMPONENT_CONTAINER | HWPTAG_SHAPE_COMPONENT_TEXTART
+        )
+    }
+
+    /// 컨트롤 태그인지 (CTRL_HEADER, CTRL_DATA)
+    pub fn is_control_tag(&self) -> bool {
+        matches!(self.tag_id, HWPTAG_CTRL_HEADER | HWPTAG_CTRL_DATA)
+    }
+
     /// 태그 이름 (디버그용)
     pub fn tag_name(&self) -> &'static str {
         match self.tag_id {
             HWPTAG_DOCUMENT_PROPERTIES => "DOCUMENT_PROPERTIES",
+            HWPTAG_BIN_DATA => "BIN_DATA",
+            HWPTAG_FACE_NAME => "FACE_NAME",
+            HWPTAG_CHAR_SHAPE => "CHAR_SHAPE",
+            HWPTAG_PARA_SHAPE => "PARA_SHAPE",
+            HWPTAG_STYLE => "STYLE",
             HWPTAG_PARA_HEADER => "PARA_HEADER",
             HWPTAG_PARA_TEXT => "PARA_TEXT",
             HWPTAG_PARA_CHAR_SHAPE => "PARA_CHAR_SHAPE",
@@ -72,35 +109,149 @@ impl RecordHeader {
 }
 
 /// 레코드 = 헤더 + 바디
+///
+/// 바디는 기본으로 레코드마다 따로 소유한다 (`Vec<u8>`). 스트림 버퍼를 빌리는
+/// [`RecordRef`]는 바디를 복사하지 않는다.
 #[derive(Debug, Clone)]
-pub struct Record {
+pub struct Record<D = Vec<u8>> {
     pub header: RecordHeader,
-    pub data: Vec<u8>,
+    pub data: D,
+}
+
+/// 스트림 버퍼를 빌린 레코드 ([`read_records_borrowed`])
+pub type RecordRef<'a> = Record<&'a [u8]>;
+
+impl<D: Deref<Target = [u8]>> Record<D> {
+    /// 컨트롤 서브트리의 시작(CTRL_HEADER)인지
+    pub fn is_control(&self) -> bool {
+        self.header.tag_id == HWPTAG_CTRL_HEADER
+    }
+
+    /// 디버그용 한 줄 요약: `PARA_TEXT level=1 size=6 | 41 00 42 00 …`
+    ///
+    /// 바디는 앞 `max_body`바이트까지만 16진수로 보이고, 잘리면 `…`를 붙인다.
+    /// 이름이 없는 태그는 `UNKNOWN(0x..)`로 표시한다.
+    pub fn debug_line(&self, max_body: usize) -> String {
+        let name = self.header.tag_name();
+        let mut line = if name == "UNKNOWN" {
+            format!("UNKNOWN(0x{:X})", self.header.tag_id)
+        } else {
+            name.to_string()
+        };
+        line.push_str(&format!(
+            " level={} size={}",
+            self.header.level,
+            self.data.len()
+        ));
+        if !self.data.is_empty() && max_body > 0 {
+            line.push_str(" |");
+            for byte in self.data.iter().take(max_body) {
+                line.push_str(&format!(" {byte:02X}"));
+            }
+            if self.data.len() > max_body {
+                line.push_str(" …");
+            }
+        }
+        line
+    }
 }
 
 /// 바이트 슬라이스에서 레코드 시퀀스를 파싱한다.
-/// 직접 인덱싱으로 Cursor 오버헤드 제거
+///
+/// [`walk_records`]로 읽은 레코드를 모두 모은다.
 pub fn read_records(data: &[u8]) -> Result<Vec<Record>> {
-    let len = data.len();
     let mut records = Vec::new();
-    let mut pos = 0;
+    read_records_into(data, &mut records)?;
+    Ok(records)
+}
 
-    while pos + 4 <= len {
-        let value = u32::from_le_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]]);
-        pos += 4;
+/// [`read_records`]와 같되, 호출자가 제공한 벡터를 비우고 재사용한다.
+///
+/// 실패하면 그때까지 읽은 레코드가 남아 있다.
+pub fn read_records_into(data: &[u8], records: &mut Vec<Record>) -> Result<()> {
+    records.clear();
+    walk_records(data, |header, body| {
+        records.push(Record {
+            header: header.clone(),
+            data: body.to_vec(),
+        });
+        ControlFlow::Continue(())
+    })
+}
 
-        let tag_id = (value & 0x3FF) as u16;
-        let level = ((value >> 10) & 0x3FF) as u16;
-        let mut size = (value >> 20) & 0xFFF;
+/// [`read_records`]와 같되, 바디를 복사하지 않고 `data`를 빌린 레코드를 만든다.
+///
+/// 레코드마다 바디를 할당하지 않으므로 큰 섹션에서 압축 해제 결과의 두 번째 사본이
+/// 생기지 않는다. 레코드는 `data`보다 오래 살 수 없다.
+pub fn read_records_borrowed(data: &[u8]) -> Result<Vec<RecordRef<'_>>> {
+    let mut records = Vec::new();
+    read_records_borrowed_into(data, &mut records)?;
+    Ok(records)
+}
 
-        // 확장 크기: size == 4095이면 추가 4바이트
-        if size == 4095 {
-            if pos + 4 > len {
-                return Err(HwpError::InvalidRecordHeader);
-            }
-            size = u32::from_le_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]]);
-            pos += 4;
-        }
+/// [`read_records_borrowed`]와 같되, 호출자가 제공한 벡터를 비우고 재사용한다.
+///
+/// 실패하면 그때까지 읽은 레코드가 남아 있다.
+pub fn read_records_borrowed_into<'a>(
+    data: &'a [u8],
+    records: &mut Vec<RecordRef<'a>>,
+) -> Result<()> {
+    records.clear();
+    walk_records(data, |header, body| {
+        records.push(Record {
+            header: header.clone(),
+            data: body,
+        });
+        ControlFlow::Continue(())
+    })
+}
+
+/// `data` 앞의 레코드 헤더를 읽어 헤더와 그 길이(4, 확장 크기면 8)를 반환한다.
+///
+/// 본문 길이는 검사하지 않는다.
+#[inline]
+pub fn read_record_header(data: &[u8]) -> Result<(RecordHeader, usize)> {
+    let Some(packed) = data.get(..4) else {
+        return Err(HwpError::InvalidRecordHeader);
+    };
+    let value = u32::from_le_bytes([packed[0], packed[1], packed[2], packed[3]]);
+    let tag_id = (value & 0x3FF) as u16;
+    let level = ((value >> 10) & 0x3FF) as u16;
+    let size = (value >> 20) & 0xFFF;
+
+    // 확장 크기: size == 4095이면 추가 4바이트
+    let (size, header_len) = if size == 4095 {
+        let Some(ext) = data.get(4..8) else {
+            return Err(HwpError::InvalidRecordHeader);
+        };
+        (u32::from_le_bytes([ext[0], ext[1], ext[2], ext[3]]), 8)
+    } else {
+        (size, 4)
+    };
+    let header = RecordHeader {
+        tag_id,
+        level,
+        size,
+    };
+    Ok((header, header_len))
+}
+
+/// 레코드를 파싱하는 대로 `visit`에 넘긴다. `ControlFlow::Break`를 반환하면 즉시 멈춘다.
+///
+/// 레코드 벡터를 만들지 않으므로 첫 표 찾기처럼 훑다가 멈추는 용도에 쓴다.
+/// 멈춘 뒤의 데이터는 검사하지 않는다.
+/// 본문은 `data`를 빌린 슬라이스이고, 선언된 크기가 남은 데이터를 넘으면 파싱 오류를 반환한다.
+pub fn walk_records<'a>(
+    data: &'a [u8],
+    mut visit: impl FnMut(&RecordHeader, &'a [u8]) -> ControlFlow<()>,
+) -> Result<()> {
+    let len = data.len();
+    let mut pos = 0;
+
+    while pos + 4 <= len {
+        let (header, header_len) = read_record_header(&data[pos..])?;
+        pos += header_len;
+        let size = header.size;
 
         let body_end = pos + size as usize;
         if body_end > len {
@@ -112,20 +263,13 @@ pub fn read_records(data: &[u8]) -> Result<Vec<Record>> {
             )));
         }
 
-        let body = data[pos..body_end].to_vec();
+        if visit(&header, &data[pos..body_end]).is_break() {
+            break;
+        }
         pos = body_end;
-
-        records.push(Record {
-            header: RecordHeader {
-                tag_id,
-                level,
-                size,
-            },
-            data: body,
-        });
     }
 
-    Ok(records)
+    Ok(())
 }
 
 #[cfg(test)]
@@ -136,11 +280,11 @@ mod tests {
     fn test_record_header_parse() {
         // tag=0x10 (16), level=0, size=26
         // packed: (26 << 20) | (0 << 10) | 16 = 0x01A00010
-        let value: u32 = (26 << 20) | (0 << 10) | 16;
+        let value: u32 = (26 << 20) | 16;
         let bytes = value.to_le_bytes();
         // Add 26 bytes of body
         let mut data = Vec::from(&bytes[..]);
-        data.extend_from_slice(&vec![0u8; 26]);
+        data.extend_from_slice(&[0u8; 26]);
 
         let records = read_records(&data).unwrap();
         assert_eq!(records.len(), 1);
@@ -183,7 +327,7 @@ mod tests {
     #[test]
     fn test_body_overflow() {
         // 헤더에 size=100을 넣지만 바디 데이터가 부족
-        let value: u32 = (100 << 20) | (0 << 10) | 16;
+        let value: u32 = (100 << 20) | 16;
         let data = value.to_le_bytes().to_vec();
         let result = read_records(&data);
         assert!(result.is_err());
@@ -193,7 +337,7 @@ mod tests {
     fn test_multiple_records() {
         let mut data = Vec::new();
         // 첫 번째 레코드: tag=0x10, level=0, size=4
-        let v1: u32 = (4 << 20) | (0 << 10) | 16;
+        let v1: u32 = (4 << 20) | 16;
         data.extend_from_slice(&v1.to_le_bytes());
         data.extend_from_slice(&[1, 2, 3, 4]);
         // 두 번째 레코드: tag=PARA_HEADER, level=1, size=2
@@ -209,10 +353,69 @@ mod tests {
         assert_eq!(records[1].data, vec![5, 6]);
     }
 
+    #[test]
+    fn test_read_records_borrowed() {
+        let mut data = Vec::new();
+        for (tag, body) in [
+            (HWPTAG_PARA_HEADER, &[1u8, 2][..]),
+            (HWPTAG_PARA_TEXT, &[3]),
+        ] {
+            data.extend_from_slice(&(((body.len() as u32) << 20) | tag as u32).to_le_bytes());
+            data.extend_from_slice(body);
+        }
+        let owned = read_records(&data).unwrap();
+        let borrowed = read_records_borrowed(&data).unwrap();
+
+        assert_eq!(borrowed.len(), owned.len());
+        for (b, o) in borrowed.iter().zip(&owned) {
+            assert_eq!(b.header.tag_id, o.header.tag_id);
+            assert_eq!(b.data, &o.data[..]);
+        }
+        // 바디는 입력 버퍼를 그대로 가리킨다
+        assert_eq!(borrowed[0].data.as_ptr(), data[4..].as_ptr());
+
+        let mut records = Vec::new();
+        assert!(read_records_borrowed_into(&data[..data.len() - 1], &mut records).is_err());
+        assert_eq!(records.len(), 1);
+    }
+
+    #[test]
+    fn test_walk_records_stops_early() {
+        let mut data = Vec::new();
+        for tag in [HWPTAG_PARA_HEADER, HWPTAG_TABLE, HWPTAG_PARA_TEXT] {
+            data.extend_from_slice(&((1u32 << 20) | tag as u32).to_le_bytes());
+            data.push(tag as u8);
+        }
+        // 멈춘 뒤의 손상된 레코드는 검사하지 않는다
+        data.extend_from_slice(&((100u32 << 20) | 16).to_le_bytes());
+
+        let mut seen = Vec::new();
+        let mut table_body = None;
+        walk_records(&data, |header, body| {
+            seen.push(header.tag_id);
+            if header.tag_id == HWPTAG_TABLE {
+                table_body = Some(body.to_vec());
+                return ControlFlow::Break(());
+            }
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert_eq!(seen, [HWPTAG_PARA_HEADER, HWPTAG_TABLE]);
+        assert_eq!(table_body, Some(vec![HWPTAG_TABLE as u8]));
+
+        let mut count = 0;
+        let result = walk_records(&data, |_, _| {
+            count += 1;
+            ControlFlow::Continue(())
+        });
+        assert!(result.is_err());
+        assert_eq!(count, 3);
+    }
+
     #[test]
     fn test_zero_size_record() {
         // tag=0x10, level=0, size=0
-        let value: u32 = (0 << 20) | (0 << 10) | 16;
+        let value: u32 = 16;
         let data = value.to_le_bytes().to_vec();
         let records = read_records(&data).unwrap();
         assert_eq!(records.len(), 1);
@@ -236,6 +439,33 @@ mod tests {
         assert_eq!(unknown.tag_name(), "UNKNOWN");
     }
 
+    #[test]
+    fn test_debug_line() {
+        let record = Record {
+            header: RecordHeader {
+                tag_id: HWPTAG_PARA_TEXT,
+                level: 1,
+                size: 6,
+            },
+            data: vec![0x41, 0x00, 0x42, 0x00, 0x0D, 0x00],
+        };
+        assert_eq!(
+            record.debug_line(4),
+            "PARA_TEXT level=1 size=6 | 41 00 42 00 …"
+        );
+        assert_eq!(record.debug_line(0), "PARA_TEXT level=1 size=6");
+
+        let unknown = Record {
+            header: RecordHeader {
+                tag_id: 0xFF,
+                level: 0,
+                size: 1,
+            },
+            data: vec![0xAB],
+        };
+        assert_eq!(unknown.debug_line(16), "UNKNOWN(0xFF) level=0 size=1 | AB");
+    }
+
     #[test]
     fn test_record_clone() {
         let record = Record {
@@ -250,4 +480,78 @@ mod tests {
         assert_eq!(cloned.header.tag_id, record.header.tag_id);
         assert_eq!(cloned.data, record.data);
     }
+
+    fn header(tag_id: u16) -> RecordHeader {
+        RecordHeader {
+            tag_id,
+            level: 0,
+            size: 0,
+        }
+    }
+
+    #[test]
+    fn test_tag_categories() {
+        let docinfo = [
+            HWPTAG_DOCUMENT_PROPERTIES,
+            HWPTAG_PARA_SHAPE,
+            HWPTAG_STYLE,
+            HWPTAG_FORBIDDEN_CHAR,
+        ];
+        let paragraph = [
+            HWPTAG_PARA_HEADER,
+            HWPTAG_PARA_TEXT,
+            HWPTAG_PARA_LINE_SEG,
+            HWPTAG_PARA_RANGE_TAG,
+        ];
+        let shapes = [
+            HWPTAG_SHAPE_COMPONENT,
+            HWPTAG_SHAPE_COMPONENT_LINE,
+            HWPTAG_SHAPE_COMPONENT_PICTURE,
+            HWPTAG_SHAPE_COMPONENT_CONTAINER,
+            HWPTAG_SHAPE_COMPONENT_TEXTART,
+        ];
+        let controls = [HWPTAG_CTRL_HEADER, HWPTAG_CTRL_DATA];
+
+        for tag in docinfo {
+            let h = header(tag);
+            assert!(h.is_docinfo_tag(), "{tag:#x}");
+            assert!(!h.is_paragraph_tag() && !h.is_shape_component() && !h.is_control_tag());
+        }
+        for tag in paragraph {
+            let h = header(tag);
+            assert!(h.is_paragraph_tag(), "{tag:#x}");
+            assert!(!h.is_docinfo_tag() && !h.is_shape_component() && !h.is_control_tag());
+        }
+        for tag in shapes {
+            let h = header(tag);
+            assert!(h.is_shape_component(), "{tag:#x}");
+            assert!(!h.is_docinfo_tag() && !h.is_paragraph_tag() && !h.is_control_tag());
+        }
+        for tag in controls {
+            let h = header(tag);
+            assert!(h.is_control_tag(), "{tag:#x}");
+            assert!(!h.is_docinfo_tag() && !h.is_paragraph_tag() && !h.is_shape_component());
+        }
+
+        // 어느 분류에도 속하지 않는 태그
+        for tag in [HWPTAG_TABLE, HWPTAG_LIST_HEADER, HWPTAG_EQEDIT] {
+            let h = header(tag);
+            assert!(!h.is_docinfo_tag() && !h.is_paragraph_tag());
+            assert!(!h.is_shape_component() && !h.is_control_tag());
+        }
+    }
+
+    #[test]
+    fn test_record_is_control() {
+        let ctrl = Record {
+            header: header(HWPTAG_CTRL_HEADER),
+            data: vec![],
+        };
+        let text = Record {
+            header: header(HWPTAG_PARA_TEXT),
+            data: vec![],
+        };
+        assert!(ctrl.is_control());
+        assert!(!text.is_control());
+    }
 }
@@ -0,0 +1,228 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::error::{HwpError, Result};
+
+/// 문서에 포함된 JScript 매크로 (Scripts 스토리지)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DocumentScripts {
+    /// `Scripts/JScriptVersion`의 스크립트 버전 ("high.low")
+    pub version: String,
+    /// 본문 스크립트 소스 (OnDocument_* 핸들러 등)
+    pub source: Option<String>,
+    /// 문서 열기 전에 실행되는 Pre 소스
+    pub pre_script: Option<String>,
+    /// 문서 닫은 후에 실행되는 Post 소스
+    pub post_script: Option<String>,
+}
+
+impl DocumentScripts {
+    /// 공백이 아닌 실제 스크립트 코드가 하나라도 있는지 확인
+    ///
+    /// 한/글은 매크로가 없어도 본문 소스에 빈 `OnDocument_*` 함수 골격을 기록하므로,
+    /// 본문 소스는 주석과 빈 함수를 걷어낸 뒤 남는 문장이 있을 때만 코드로 본다.
+    pub fn is_non_trivial(&self) -> bool {
+        self.source.as_deref().is_some_and(has_statements)
+            || [&self.pre_script, &self.post_script]
+                .iter()
+                .any(|s| s.as_deref().is_some_and(|s| !s.trim().is_empty()))
+    }
+}
+
+/// 주석과 본문이 빈 함수 선언(`function f() { }`)을 제외한 문장이 있는지
+fn has_statements(source: &str) -> bool {
+    let code = strip_comments(source);
+    let mut rest = code.trim_start();
+    while !rest.is_empty() {
+        match skip_empty_function(rest) {
+            Some(next) => rest = next.trim_start(),
+            None => return true,
+        }
+    }
+    false
+}
+
+/// `rest`가 빈 함수 선언으로 시작하면 그 뒤를 반환한다.
+fn skip_empty_function(rest: &str) -> Option<&str> {
+    let after = rest.strip_prefix("function")?;
+    if !after.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let (name, after) = after.split_once('(')?;
+    let name = name.trim();
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    {
+        return None;
+    }
+    let (params, after) = after.split_once(')')?;
+    if params.contains(['(', '{', '}']) {
+        return None;
+    }
+    let body = after.trim_start().strip_prefix('{')?;
+    body.trim_start().strip_prefix('}')
+}
+
+/// `//`, `/* */` 주석을 지운다. 문자열 리터럴 안의 주석 기호는 그대로 둔다.
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' | '\'' => {
+                out.push(c);
+                while let Some(s) = chars.next() {
+                    out.push(s);
+                    if s == '\\' {
+                        out.extend(chars.next());
+                    } else if s == c || s == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => while chars.next_if(|&s| s != '\n').is_some() {},
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for s in chars.by_ref() {
+                    if prev == '*' && s == '/' {
+                        break;
+                    }
+                    prev = s;
+                }
+                out.push(' ');
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// `Scripts/JScriptVersion` 스트림 파싱: DWORD high + DWORD low
+pub fn parse_script_version(data: &[u8]) -> Result<String> {
+    if data.len() < 8 {
+        return Err(HwpError::Parse("JScriptVersion too short".into()));
+    }
+    let mut cursor = data;
+    let high = cursor.read_u32::<LittleEndian>()?;
+    let low = cursor.read_u32::<LittleEndian>()?;
+    Ok(format!("{}.{}", high, low))
+}
+
+/// `Scripts/DefaultJScript` 스트림 파싱
+///
+/// 구조 (각 문자열은 DWORD 길이 + WCHAR 배열):
+/// 1. 스크립트 헤더
+/// 2. 스크립트 소스
+/// 3. Pre 소스
+/// 4. Post 소스
+/// 5. 종료 플래그 (-1)
+///
+/// 반환: (소스, Pre 소스, Post 소스). 빈 문자열은 `None`.
+pub fn parse_default_script(
+    data: &[u8],
+) -> Result<(Option<String>, Option<String>, Option<String>)> {
+    let mut cursor = data;
+    let _header = read_script_string(&mut cursor)?;
+    let source = read_script_string(&mut cursor)?;
+    let pre = read_script_string(&mut cursor)?;
+    let post = read_script_string(&mut cursor)?;
+    Ok((non_empty(source), non_empty(pre), non_empty(post)))
+}
+
+/// DWORD 길이 + UTF-16LE 문자열을 읽는다.
+fn read_script_string(cursor: &mut &[u8]) -> Result<String> {
+    let len = cursor.read_u32::<LittleEndian>()? as usize;
+    let byte_len = len
+        .checked_mul(2)
+        .filter(|&n| n <= cursor.len())
+        .ok_or_else(|| HwpError::Parse(format!("Script string length {} out of range", len)))?;
+
+    let units: Vec<u16> = cursor[..byte_len]
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+    *cursor = &cursor[byte_len..];
+
+    Ok(String::from_utf16_lossy(&units))
+}
+
+fn non_empty(s: String) -> Option<String> {
+    if s.is_empty() {
+        None
+    } else {
+        Some(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::script_string;
+
+    #[test]
+    fn test_parse_script_version() {
+        let mut data = 1u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&2u32.to_le_bytes());
+        assert_eq!(parse_script_version(&data).unwrap(), "1.2");
+    }
+
+    #[test]
+    fn test_parse_script_version_too_short() {
+        assert!(parse_script_version(&[0u8; 4]).is_err());
+    }
+
+    #[test]
+    fn test_parse_default_script() {
+        let mut data = script_string("var Document;");
+        data.extend(script_string("function OnDocument_New() {}"));
+        data.extend(script_string(""));
+        data.extend(script_string("Run();"));
+        data.extend_from_slice(&(-1i32).to_le_bytes());
+
+        let (source, pre, post) = parse_default_script(&data).unwrap();
+        assert_eq!(source.as_deref(), Some("function OnDocument_New() {}"));
+        assert_eq!(pre, None);
+        assert_eq!(post.as_deref(), Some("Run();"));
+    }
+
+    #[test]
+    fn test_parse_default_script_truncated() {
+        // 길이 필드는 10자를 선언하지만 데이터 부족
+        let data = 10u32.to_le_bytes().to_vec();
+        assert!(parse_default_script(&data).is_err());
+    }
+
+    #[test]
+    fn test_is_non_trivial() {
+        let mut scripts = DocumentScripts {
+            source: Some("function OnDocument_New() {}".into()),
+            pre_script: Some("  \r\n".into()),
+            ..Default::default()
+        };
+        assert!(!scripts.is_non_trivial());
+        scripts.post_script = Some("Run();".into());
+        assert!(scripts.is_non_trivial());
+    }
+
+    #[test]
+    fn test_is_non_trivial_source_only() {
+        // 한/글이 기록하는 기본 골격
+        let skeleton = "function OnDocument_New()\r\n{\r\n\t//todo : \r\n}\r\n\r\n\
+                        function OnDocument_Open()\r\n{\r\n\t/* todo */\r\n}\r\n";
+        let mut scripts = DocumentScripts {
+            source: Some(skeleton.into()),
+            ..Default::default()
+        };
+        assert!(!scripts.is_non_trivial());
+
+        // 핸들러 본문에만 있는 매크로
+        scripts.source = Some(skeleton.replace("//todo : ", "Shell(\"cmd // x\");"));
+        assert!(scripts.is_non_trivial());
+
+        // 함수 밖의 최상위 문장
+        scripts.source = Some(format!("{skeleton}var x = 1;"));
+        assert!(scripts.is_non_trivial());
+    }
+}
@@ -1,20 +1,96 @@
 use std::io::Read;
 
-use flate2::read::DeflateDecoder;
+use flate2::{Decompress, FlushDecompress, Status};
 
 use crate::error::{HwpError, Result};
 
 /// 압축된 스트림 데이터를 raw deflate로 압축해제한다.
 /// HWP는 zlib 헤더 없는 raw deflate를 사용한다.
 pub fn decompress(data: &[u8]) -> Result<Vec<u8>> {
-    let mut decoder = DeflateDecoder::new(data);
     let mut decompressed = Vec::new();
-    decoder
-        .read_to_end(&mut decompressed)
-        .map_err(|e| HwpError::DecompressFailed(e.to_string()))?;
+    decompress_into(data, &mut decompressed)?;
     Ok(decompressed)
 }
 
+/// [`decompress`]와 같되, 호출자가 제공한 버퍼를 비우고 재사용한다.
+///
+/// 워커별 스크래치 버퍼를 유지하면 대량 처리 시 할당을 줄일 수 있다.
+/// 실패하면 버퍼 내용은 정의되지 않는다.
+pub fn decompress_into(data: &[u8], out: &mut Vec<u8>) -> Result<()> {
+    if inflate(data, out)? {
+        Ok(())
+    } else {
+        Err(HwpError::TruncatedStream(out.len()))
+    }
+}
+
+/// [`decompress`]의 관대한 변형: deflate 스트림이 중간에 끊겨 있어도
+/// 그때까지 복원한 데이터를 돌려준다.
+///
+/// 반환: (압축해제된 데이터, 스트림이 잘렸는지). 잘렸으면 호출자가 경고를 남기고
+/// 부분 결과를 쓸지 결정한다. 손상된 deflate 데이터는 여전히 오류다.
+pub fn decompress_partial(data: &[u8]) -> Result<(Vec<u8>, bool)> {
+    let mut out = Vec::new();
+    let truncated = decompress_partial_into(data, &mut out)?;
+    Ok((out, truncated))
+}
+
+/// [`decompress_partial`]과 같되, 호출자가 제공한 버퍼를 비우고 재사용한다.
+/// 스트림이 잘렸으면 `true`.
+pub fn decompress_partial_into(data: &[u8], out: &mut Vec<u8>) -> Result<bool> {
+    Ok(!inflate(data, out)?)
+}
+
+/// raw deflate를 `out`에 압축해제한다. 스트림 끝 블록까지 도달했으면 `true`.
+///
+/// `DeflateDecoder`는 입력이 끝 블록 전에 끝나도 오류 없이 부분 결과를 내므로
+/// `Decompress`를 직접 구동해 `Status::StreamEnd`를 확인한다.
+fn inflate(data: &[u8], out: &mut Vec<u8>) -> Result<bool> {
+    out.clear();
+    out.reserve(data.len().saturating_mul(2).max(64));
+    let mut inflater = Decompress::new(false);
+    loop {
+        if out.len() == out.capacity() {
+            out.reserve(out.capacity());
+        }
+        let consumed = inflater.total_in() as usize;
+        let produced = out.len();
+        let status = inflater
+            .decompress_vec(&data[consumed..], out, FlushDecompress::None)
+            .map_err(|e| HwpError::DecompressFailed(e.to_string()))?;
+        if status == Status::StreamEnd {
+            return Ok(true);
+        }
+        let stalled = inflater.total_in() as usize == consumed && out.len() == produced;
+        if stalled && out.len() < out.capacity() {
+            // 입력을 모두 소비했는데 더 나올 것이 없음: 끝 블록 전에 잘림
+            return Ok(false);
+        }
+    }
+}
+
+/// 압축 해제 결과를 버리면서 raw deflate 데이터의 압축 해제 크기만 센다.
+///
+/// 결과 전체를 메모리에 두지 않으므로 큰 첨부 파일의 크기를 잴 때 쓴다.
+/// 끝 블록 전에 잘렸으면 [`decompress`]와 같은 오류를 반환한다.
+pub fn decompressed_len(data: &[u8]) -> Result<usize> {
+    let mut inflater = Decompress::new(false);
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let consumed = inflater.total_in() as usize;
+        let produced = inflater.total_out();
+        let status = inflater
+            .decompress(&data[consumed..], &mut buf, FlushDecompress::None)
+            .map_err(|e| HwpError::DecompressFailed(e.to_string()))?;
+        if status == Status::StreamEnd {
+            return Ok(inflater.total_out() as usize);
+        }
+        if inflater.total_in() as usize == consumed && inflater.total_out() == produced {
+            return Err(HwpError::TruncatedStream(produced as usize));
+        }
+    }
+}
+
 /// OLE 스트림에서 전체 데이터를 읽는다.
 pub fn read_stream_data<R: Read>(stream: &mut R) -> Result<Vec<u8>> {
     let mut data = Vec::new();
@@ -22,6 +98,28 @@ pub fn read_stream_data<R: Read>(stream: &mut R) -> Result<Vec<u8>> {
     Ok(data)
 }
 
+/// [`read_stream_data`]와 같되, `max_len`바이트를 넘게 읽히면 멈추고
+/// 스트림 이름을 담은 파싱 오류를 반환한다.
+///
+/// 디렉터리가 선언한 크기나 파일 크기를 `max_len`으로 넘기면
+/// 조작된 파일이 끝없이 데이터를 내놓아도 그 이상 읽지 않는다.
+pub fn read_stream_data_limited<R: Read>(
+    stream: &mut R,
+    name: &str,
+    max_len: u64,
+) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+    stream
+        .take(max_len.saturating_add(1))
+        .read_to_end(&mut data)?;
+    if data.len() as u64 > max_len {
+        return Err(HwpError::Parse(format!(
+            "stream {name} is larger than its expected {max_len} bytes"
+        )));
+    }
+    Ok(data)
+}
+
 /// 압축 여부에 따라 스트림 데이터를 읽고 필요시 압축해제한다.
 pub fn read_and_decompress<R: Read>(stream: &mut R, compressed: bool) -> Result<Vec<u8>> {
     let raw = read_stream_data(stream)?;
@@ -53,6 +151,82 @@ mod tests {
         assert_eq!(&decompressed, original);
     }
 
+    #[test]
+    fn test_decompressed_len() {
+        let original = vec![0x5Au8; 300_000];
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&original).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert_eq!(decompressed_len(&compressed).unwrap(), original.len());
+        assert!(matches!(
+            decompressed_len(&compressed[..compressed.len() / 2]),
+            Err(HwpError::TruncatedStream(_))
+        ));
+    }
+
+    #[test]
+    fn test_decompress_into_reuses_buffer() {
+        let compress = |data: &[u8]| {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(data).unwrap();
+            encoder.finish().unwrap()
+        };
+        let long = vec![b'x'; 10_000];
+        let short = b"short".to_vec();
+
+        let mut buf = Vec::new();
+        decompress_into(&compress(&long), &mut buf).unwrap();
+        assert_eq!(buf, long);
+        let capacity = buf.capacity();
+
+        // 더 짧은 결과: 이전 내용이 남지 않고, 할당은 재사용
+        decompress_into(&compress(&short), &mut buf).unwrap();
+        assert_eq!(buf, short);
+        assert_eq!(buf.capacity(), capacity);
+
+        decompress_into(&compress(&long), &mut buf).unwrap();
+        assert_eq!(buf, long);
+    }
+
+    #[test]
+    fn test_truncated_deflate_stream() {
+        let original: Vec<u8> = (0..20_000u32).map(|i| (i * 7 % 251) as u8).collect();
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&original).unwrap();
+        let compressed = encoder.finish().unwrap();
+        let truncated = &compressed[..compressed.len() / 2];
+
+        match decompress(truncated) {
+            Err(HwpError::TruncatedStream(recovered)) => assert!(recovered < original.len()),
+            other => panic!("expected truncation error, got {other:?}"),
+        }
+
+        let (partial, was_truncated) = decompress_partial(truncated).unwrap();
+        assert!(was_truncated);
+        assert!(original.starts_with(&partial));
+
+        let (full, was_truncated) = decompress_partial(&compressed).unwrap();
+        assert!(!was_truncated);
+        assert_eq!(full, original);
+    }
+
+    #[test]
+    fn test_read_stream_data_limited() {
+        // 끝없이 데이터를 내놓는 스트림
+        let mut endless = std::io::repeat(0xAB);
+        match read_stream_data_limited(&mut endless, "BodyText/Section0", 1024) {
+            Err(HwpError::Parse(msg)) => assert!(msg.contains("BodyText/Section0"), "got: {msg}"),
+            other => panic!("expected oversized stream error, got {other:?}"),
+        }
+
+        let data = [1u8, 2, 3];
+        assert_eq!(
+            read_stream_data_limited(&mut &data[..], "s", 3).unwrap(),
+            data
+        );
+    }
+
     #[test]
     fn test_read_and_decompress_uncompressed() {
         let data = b"uncompressed data";
@@ -0,0 +1,288 @@
+use encoding_rs::Encoding;
+
+use crate::error::{HwpError, Result};
+
+/// `\x05HwpSummaryInformation` 스트림 이름
+pub const SUMMARY_STREAM: &str = "/\u{5}HwpSummaryInformation";
+
+/// 속성 ID (PIDSI_*)
+const PID_CODEPAGE: u32 = 1;
+const PID_TITLE: u32 = 2;
+const PID_SUBJECT: u32 = 3;
+const PID_AUTHOR: u32 = 4;
+const PID_KEYWORDS: u32 = 5;
+const PID_COMMENTS: u32 = 6;
+const PID_LAST_AUTHOR: u32 = 8;
+const PID_REV_NUMBER: u32 = 9;
+const PID_CREATE_TIME: u32 = 12;
+const PID_LAST_SAVE_TIME: u32 = 13;
+const PID_APP_NAME: u32 = 18;
+
+/// 속성 값 타입 (VT_*)
+const VT_I2: u32 = 2;
+const VT_LPSTR: u32 = 30;
+const VT_LPWSTR: u32 = 31;
+const VT_FILETIME: u32 = 64;
+
+/// 코드 페이지 UTF-16LE (문자열이 VT_LPSTR이어도 UTF-16으로 저장)
+const CP_UTF16LE: u16 = 1200;
+
+/// 문서 요약 정보 (HwpSummaryInformation 속성 집합)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DocumentSummary {
+    /// 문자열 속성의 코드 페이지 (PID 1). 없으면 `None`.
+    pub code_page: Option<u16>,
+    pub title: Option<String>,
+    pub subject: Option<String>,
+    pub author: Option<String>,
+    pub keywords: Option<String>,
+    pub comments: Option<String>,
+    pub last_author: Option<String>,
+    /// 작성 시각 (FILETIME: 1601-01-01 UTC 기준 100ns 단위)
+    pub created: Option<u64>,
+    /// 마지막 저장 시각 (FILETIME)
+    pub modified: Option<u64>,
+    /// 문서를 만든 프로그램 이름 (PID 18, 예: "Hancom Office Hangul")
+    pub authoring_app: Option<String>,
+    /// 저장 횟수 (PID 9). 문자열로 저장되며 숫자가 아니면 `None`.
+    pub revision_count: Option<u32>,
+}
+
+/// OLE 속성 집합 스트림을 파싱한다.
+///
+/// 구조: 바이트 순서(2) + 버전(2) + 시스템 ID(4) + CLSID(16) + 집합 수(4) +
+/// [FMTID(16) + 오프셋(4)]. 첫 번째 집합만 읽는다.
+/// 집합: 크기(4) + 속성 수(4) + [PID(4) + 오프셋(4)] (오프셋은 집합 시작 기준)
+///
+/// 문자열(VT_LPSTR)은 코드 페이지 속성(PID 1)에 따라 디코딩하므로
+/// 코드 페이지를 먼저 읽은 뒤 나머지 속성을 해석한다.
+pub fn parse_summary(data: &[u8]) -> Result<DocumentSummary> {
+    let too_short = || HwpError::Parse("SummaryInformation too short".into());
+
+    if data.len() < 48 || data[0..2] != [0xFE, 0xFF] {
+        return Err(HwpError::Parse("Invalid property set header".into()));
+    }
+    let set_offset = read_u32(data, 44).ok_or_else(too_short)? as usize;
+    let set = data.get(set_offset..).ok_or_else(too_short)?;
+    let count = read_u32(set, 4).ok_or_else(too_short)? as usize;
+
+    let mut props: Vec<(u32, &[u8])> = Vec::new();
+    for i in 0..count {
+        let base = 8 + i * 8;
+        let (Some(pid), Some(offset)) = (read_u32(set, base), read_u32(set, base + 4)) else {
+            break;
+        };
+        if let Some(value) = set.get(offset as usize..) {
+            props.push((pid, value));
+        }
+    }
+
+    let mut summary = DocumentSummary {
+        code_page: props
+            .iter()
+            .find(|(pid, _)| *pid == PID_CODEPAGE)
+            .and_then(|(_, v)| read_i2(v)),
+        ..Default::default()
+    };
+    let code_page = summary.code_page;
+
+    for (pid, value) in props {
+        match pid {
+            PID_TITLE => summary.title = read_string(value, code_page),
+            PID_SUBJECT => summary.subject = read_string(value, code_page),
+            PID_AUTHOR => summary.author = read_string(value, code_page),
+            PID_KEYWORDS => summary.keywords = read_string(value, code_page),
+            PID_COMMENTS => summary.comments = read_string(value, code_page),
+            PID_LAST_AUTHOR => summary.last_author = read_string(value, code_page),
+            PID_CREATE_TIME => summary.created = read_filetime(value),
+            PID_LAST_SAVE_TIME => summary.modified = read_filetime(value),
+            PID_APP_NAME => summary.authoring_app = read_string(value, code_page),
+            PID_REV_NUMBER => {
+                summary.revision_count =
+                    read_string(value, code_page).and_then(|s| s.trim().parse().ok());
+            }
+            _ => {}
+        }
+    }
+    Ok(summary)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let b = data.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// VT_I2 값 (코드 페이지는 부호 없는 값으로 해석)
+fn read_i2(value: &[u8]) -> Option<u16> {
+    if read_u32(value, 0)? != VT_I2 {
+        return None;
+    }
+    let b = value.get(4..6)?;
+    Some(u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_filetime(value: &[u8]) -> Option<u64> {
+    if read_u32(value, 0)? != VT_FILETIME {
+        return None;
+    }
+    let low = read_u32(value, 4)? as u64;
+    let high = read_u32(value, 8)? as u64;
+    Some((high << 32) | low)
+}
+
+/// 문자열 속성 디코딩 (끝의 NUL 제거, 빈 문자열은 `None`)
+///
+/// VT_LPWSTR: 문자 수(4) + UTF-16LE
+/// VT_LPSTR: 바이트 수(4) + 코드 페이지 인코딩 바이트열
+fn read_string(value: &[u8], code_page: Option<u16>) -> Option<String> {
+    let kind = read_u32(value, 0)?;
+    let len = read_u32(value, 4)? as usize;
+    let s = match kind {
+        VT_LPWSTR => decode_utf16le(value.get(8..8 + len.checked_mul(2)?)?),
+        VT_LPSTR => {
+            let bytes = value.get(8..8 + len)?;
+            match code_page {
+                Some(CP_UTF16LE) => decode_utf16le(bytes),
+                _ => {
+                    let encoding = code_page
+                        .and_then(encoding_for_code_page)
+                        .unwrap_or(encoding_rs::EUC_KR);
+                    encoding.decode_without_bom_handling(bytes).0.into_owned()
+                }
+            }
+        }
+        _ => return None,
+    };
+    let s = s.trim_end_matches('\0');
+    (!s.is_empty()).then(|| s.to_string())
+}
+
+fn decode_utf16le(bytes: &[u8]) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// Windows 코드 페이지 번호 → 인코딩
+fn encoding_for_code_page(code_page: u16) -> Option<&'static Encoding> {
+    Some(match code_page {
+        949 | 51949 => encoding_rs::EUC_KR,
+        65001 => encoding_rs::UTF_8,
+        932 => encoding_rs::SHIFT_JIS,
+        936 => encoding_rs::GBK,
+        950 => encoding_rs::BIG5,
+        1250 => encoding_rs::WINDOWS_1250,
+        1251 => encoding_rs::WINDOWS_1251,
+        1252 => encoding_rs::WINDOWS_1252,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 속성 목록으로 속성 집합 스트림을 조립한다.
+    fn property_set(props: &[(u32, Vec<u8>)]) -> Vec<u8> {
+        let mut data = vec![0xFE, 0xFF, 0, 0];
+        data.extend_from_slice(&[0u8; 20]); // 시스템 ID + CLSID
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&[0u8; 16]); // FMTID
+        data.extend_from_slice(&48u32.to_le_bytes());
+
+        let mut offset = 8 + props.len() * 8;
+        let mut index = Vec::new();
+        let mut values = Vec::new();
+        for (pid, value) in props {
+            index.extend_from_slice(&pid.to_le_bytes());
+            index.extend_from_slice(&(offset as u32).to_le_bytes());
+            offset += value.len();
+            values.extend_from_slice(value);
+        }
+        data.extend_from_slice(&(offset as u32).to_le_bytes());
+        data.extend_from_slice(&(props.len() as u32).to_le_bytes());
+        data.extend(index);
+        data.extend(values);
+        data
+    }
+
+    fn typed(vt: u32, body: &[u8]) -> Vec<u8> {
+        let mut v = vt.to_le_bytes().to_vec();
+        v.extend_from_slice(body);
+        v
+    }
+
+    fn lpstr(bytes: &[u8]) -> Vec<u8> {
+        let mut body = ((bytes.len() + 1) as u32).to_le_bytes().to_vec();
+        body.extend_from_slice(bytes);
+        body.push(0);
+        typed(VT_LPSTR, &body)
+    }
+
+    #[test]
+    fn test_cp949_title() {
+        let (title, _, _) = encoding_rs::EUC_KR.encode("한글 제목");
+        let data = property_set(&[
+            (PID_TITLE, lpstr(&title)),
+            (PID_CODEPAGE, typed(VT_I2, &[0xB5, 0x03, 0, 0])), // 949
+        ]);
+        let summary = parse_summary(&data).unwrap();
+        assert_eq!(summary.code_page, Some(949));
+        assert_eq!(summary.title.as_deref(), Some("한글 제목"));
+    }
+
+    #[test]
+    fn test_lpwstr_and_filetime() {
+        let mut author = 3u32.to_le_bytes().to_vec();
+        author.extend("홍길\0".encode_utf16().flat_map(|c| c.to_le_bytes()));
+        let mut time = 0x89AB_CDEFu32.to_le_bytes().to_vec();
+        time.extend_from_slice(&0x01D9_0000u32.to_le_bytes());
+
+        let data = property_set(&[
+            (PID_CODEPAGE, typed(VT_I2, &[0xB0, 0x04, 0, 0])), // 1200
+            (PID_AUTHOR, typed(VT_LPWSTR, &author)),
+            (PID_LAST_SAVE_TIME, typed(VT_FILETIME, &time)),
+        ]);
+        let summary = parse_summary(&data).unwrap();
+        assert_eq!(summary.author.as_deref(), Some("홍길"));
+        assert_eq!(summary.modified, Some(0x01D9_0000_89AB_CDEF));
+        assert_eq!(summary.title, None);
+    }
+
+    #[test]
+    fn test_utf16_code_page_lpstr() {
+        let bytes: Vec<u8> = "제목"
+            .encode_utf16()
+            .flat_map(|c| c.to_le_bytes())
+            .collect();
+        let mut body = (bytes.len() as u32).to_le_bytes().to_vec();
+        body.extend(bytes);
+        let data = property_set(&[
+            (PID_CODEPAGE, typed(VT_I2, &[0xB0, 0x04, 0, 0])),
+            (PID_TITLE, typed(VT_LPSTR, &body)),
+        ]);
+        assert_eq!(parse_summary(&data).unwrap().title.as_deref(), Some("제목"));
+    }
+
+    #[test]
+    fn test_authoring_app_and_revision() {
+        let data = property_set(&[
+            (PID_APP_NAME, lpstr(b"Hwp 2018 10.0.0.0")),
+            (PID_REV_NUMBER, lpstr(b"12")),
+        ]);
+        let summary = parse_summary(&data).unwrap();
+        assert_eq!(summary.authoring_app.as_deref(), Some("Hwp 2018 10.0.0.0"));
+        assert_eq!(summary.revision_count, Some(12));
+
+        let data = property_set(&[(PID_REV_NUMBER, lpstr(b"v2"))]);
+        assert_eq!(parse_summary(&data).unwrap().revision_count, None);
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        assert!(parse_summary(&[0u8; 10]).is_err());
+        assert!(parse_summary(&[0u8; 64]).is_err());
+    }
+}
@@ -1,73 +1,510 @@
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, Read};
 use std::path::Path;
 
-use quick_xml::events::Event;
+use quick_xml::events::{BytesStart, Event};
 use quick_xml::reader::Reader;
-use rayon::prelude::*;
 
-use crate::error::{HwpError, Result};
+use crate::error::{open_file, HwpError, Result};
+use crate::extract::{format_coordinate_cells, format_tab_separated_rows, OutlineEntry};
+use crate::hwp::docinfo::Style;
+use crate::hwp::field;
+use crate::markdown;
+use crate::options::{ExtractOptions, TableMode};
+use crate::par;
 
 /// HWPX (ZIP-based OWPML) 파일에서 텍스트를 추출한다.
 ///
 /// 섹션별 병렬 처리: ZIP 엔트리 I/O 후 XML 파싱을 rayon으로 병렬 수행한다.
 pub fn extract_text_from_hwpx(path: &Path) -> Result<String> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    let mut archive =
-        zip::ZipArchive::new(reader).map_err(|e| HwpError::Hwpx(format!("ZIP open: {}", e)))?;
+    extract_text_from_hwpx_with_options(path, &ExtractOptions::default())
+}
+
+/// [`extract_text_from_hwpx`]의 옵션 지정 버전
+pub fn extract_text_from_hwpx_with_options(path: &Path, opts: &ExtractOptions) -> Result<String> {
+    extract_parts(path, opts, is_section_entry)
+}
+
+/// [`extract_text_from_hwpx`]와 같되, `Contents/section*.xml` 대신 `predicate`가 참인
+/// 엔트리를 섹션 파트로 읽는다.
+///
+/// 표준과 다르게 패키징된 OWPML 문서나, 머리말 등 특정 파트만 골라 추출할 때 쓴다.
+/// 파트는 이름 끝의 번호(`part2.xml` → 2), 같으면 이름 순으로 이어 붙인다.
+/// 기본 추출은 [`is_section_entry`]를 조건으로 쓴 것과 같다.
+pub fn extract_text_from_hwpx_filtered(
+    path: &Path,
+    predicate: impl Fn(&str) -> bool,
+) -> Result<String> {
+    extract_parts(path, &ExtractOptions::default(), predicate)
+}
+
+/// 표준 섹션 파트(`Contents/section<N>.xml`) 엔트리 이름인지
+pub fn is_section_entry(name: &str) -> bool {
+    name.strip_prefix("Contents/")
+        .and_then(section_number)
+        .is_some()
+}
+
+/// `predicate`로 고른 파트들을 섹션으로 보고 텍스트를 추출한다.
+fn extract_parts(
+    path: &Path,
+    opts: &ExtractOptions,
+    predicate: impl Fn(&str) -> bool,
+) -> Result<String> {
+    let mut archive = open_archive(path)?;
+    let section_names = section_entry_names(&mut archive, predicate)?;
 
-    // section*.xml 파일들 찾기 (정렬)
-    let mut section_names: Vec<String> = Vec::new();
+    // Phase 1: 모든 섹션 XML과 참조 파트를 순차 읽기 (ZIP I/O)
+    let mut section_xmls: Vec<(String, ReferencedParts)> = Vec::new();
+    for section_name in &section_names {
+        let xml_data = read_entry(&mut archive, section_name)?;
+        let parts = read_referenced_parts(section_name, |name| {
+            let mut entry = archive.by_name(name).ok()?;
+            let mut xml = String::new();
+            entry.read_to_string(&mut xml).ok()?;
+            Some(xml)
+        });
+        section_xmls.push((xml_data, parts));
+    }
+
+    extract_section_xmls(section_xmls, opts)
+}
+
+type HwpxArchive = zip::ZipArchive<BufReader<File>>;
+
+fn open_archive(path: &Path) -> Result<HwpxArchive> {
+    let file = open_file(path)?;
+    zip::ZipArchive::new(BufReader::new(file))
+        .map_err(|e| HwpError::Hwpx(format!("ZIP open: {}", e)))
+}
+
+/// `predicate`가 참인 섹션 파트 엔트리 이름을 번호순으로 모은다.
+///
+/// DRM 표식 엔트리가 있으면 [`HwpError::DrmProtected`]를 반환한다.
+fn section_entry_names(
+    archive: &mut HwpxArchive,
+    predicate: impl Fn(&str) -> bool,
+) -> Result<Vec<String>> {
+    let mut section_names: Vec<(u32, String)> = Vec::new();
     for i in 0..archive.len() {
         let entry = archive
             .by_index(i)
             .map_err(|e| HwpError::Hwpx(format!("ZIP entry: {}", e)))?;
         let name = entry.name().to_string();
-        if name.starts_with("Contents/section") && name.ends_with(".xml") {
-            section_names.push(name);
+        if is_drm_marker(&name) {
+            return Err(HwpError::DrmProtected(name));
+        }
+        if predicate(&name) {
+            section_names.push((part_number(&name), name));
         }
     }
     section_names.sort();
+    Ok(section_names.into_iter().map(|(_, name)| name).collect())
+}
 
-    // Phase 1: 모든 섹션 XML을 순차 읽기 (ZIP I/O)
-    let mut section_xmls: Vec<(usize, String)> = Vec::new();
-    for (idx, section_name) in section_names.iter().enumerate() {
-        let mut entry = archive
-            .by_name(section_name)
-            .map_err(|e| HwpError::Hwpx(format!("ZIP entry '{}': {}", section_name, e)))?;
+/// 파트 이름 끝의 번호 (`Contents/section10.xml` → 10). 번호가 없으면 맨 뒤로 보낸다.
+fn part_number(name: &str) -> u32 {
+    let file_name = name.rsplit('/').next().unwrap_or(name);
+    let stem = file_name.split('.').next().unwrap_or(file_name);
+    let digits = stem.len() - stem.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    stem[stem.len() - digits..].parse().unwrap_or(u32::MAX)
+}
 
-        let mut xml_data = String::new();
-        entry
-            .read_to_string(&mut xml_data)
-            .map_err(|e| HwpError::Hwpx(format!("read section XML: {}", e)))?;
+/// ZIP 엔트리를 문자열로 읽는다.
+fn read_entry(archive: &mut HwpxArchive, name: &str) -> Result<String> {
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|e| HwpError::Hwpx(format!("ZIP entry '{}': {}", name, e)))?;
+    let mut xml = String::new();
+    entry
+        .read_to_string(&mut xml)
+        .map_err(|e| HwpError::Hwpx(format!("read {}: {}", name, e)))?;
+    Ok(xml)
+}
 
-        section_xmls.push((idx, xml_data));
+/// HWPX 문서의 개요(목차)를 추출한다.
+///
+/// `Contents/header.xml`의 문단 속성(`paraPr`)에 개요 문단 머리가 있거나
+/// 스타일 이름이 `개요 N`/`Outline N`이면 그 문단 속성·스타일을 참조하는
+/// 섹션 최상위 문단을 제목으로 본다. HWP의 [`crate::extract_outline`]과 같은 규칙이다.
+pub fn extract_outline_from_hwpx(path: &Path) -> Result<Vec<OutlineEntry>> {
+    let mut archive = open_archive(path)?;
+    let section_names = section_entry_names(&mut archive, is_section_entry)?;
+    let levels = match read_entry(&mut archive, "Contents/header.xml") {
+        Ok(xml) => parse_header_outline(&xml)?,
+        Err(_) => HeaderOutline::default(),
+    };
+
+    let mut outline = Vec::new();
+    for name in &section_names {
+        let xml = read_entry(&mut archive, name)?;
+        outline.extend(section_outline_xml(&xml, &levels)?);
     }
+    Ok(outline)
+}
 
-    // Phase 2: 섹션별 XML 파싱을 병렬 수행
-    let mut section_texts: Vec<(usize, String)> = section_xmls
-        .into_par_iter()
-        .map(|(idx, xml_data)| {
-            let mut text = String::new();
-            extract_section_xml(&xml_data, &mut text)?;
-            Ok((idx, text))
-        })
-        .collect::<Result<Vec<_>>>()?;
-
-    // Phase 3: 섹션 순서대로 병합
-    section_texts.sort_unstable_by_key(|(i, _)| *i);
-    let text = section_texts
+/// header.xml에서 읽은 개요 수준 (1부터)
+#[derive(Debug, Default)]
+struct HeaderOutline {
+    /// 문단 속성 ID → 개요 수준
+    para_pr: HashMap<String, u8>,
+    /// 스타일 ID → 개요 수준 (제목 스타일 이름)
+    style: HashMap<String, u8>,
+}
+
+impl HeaderOutline {
+    /// 문단 속성이 개요 문단이면 그 수준, 아니면 제목 스타일의 수준
+    fn level(&self, para_pr_id: Option<&str>, style_id: Option<&str>) -> Option<u8> {
+        para_pr_id
+            .and_then(|id| self.para_pr.get(id))
+            .or_else(|| style_id.and_then(|id| self.style.get(id)))
+            .copied()
+    }
+}
+
+/// header.xml의 문단 속성과 스타일에서 개요 수준을 읽는다.
+///
+/// `<hh:paraPr id="..">` 안의 `<hh:heading type="OUTLINE" level="0"/>`(수준은 0부터),
+/// `<hh:style id=".." name="개요 1" engName="Outline 1"/>`
+fn parse_header_outline(xml: &str) -> Result<HeaderOutline> {
+    let mut reader = Reader::from_str(xml);
+    let mut outline = HeaderOutline::default();
+    let mut para_pr_id: Option<String> = None;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => match e.local_name().as_ref() {
+                b"paraPr" => para_pr_id = attr_str(e, b"id"),
+                b"heading" if attr_str(e, b"type").as_deref() == Some("OUTLINE") => {
+                    let level = attr_u16(e, b"level").unwrap_or(0).min(9) as u8;
+                    if let Some(id) = &para_pr_id {
+                        outline.para_pr.insert(id.clone(), level + 1);
+                    }
+                }
+                b"style" => {
+                    let style = Style {
+                        name: attr_str(e, b"name").unwrap_or_default(),
+                        english_name: attr_str(e, b"engName").unwrap_or_default(),
+                        ..Default::default()
+                    };
+                    if let (Some(id), Some(level)) = (attr_str(e, b"id"), style.heading_level()) {
+                        outline.style.insert(id, level);
+                    }
+                }
+                _ => {}
+            },
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"paraPr" => para_pr_id = None,
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(HwpError::Hwpx(format!(
+                    "XML parse error at {}: {}",
+                    reader.error_position(),
+                    e
+                )));
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(outline)
+}
+
+/// 섹션 XML의 최상위 문단 중 개요 문단을 순서대로 모은다.
+///
+/// 표 셀·글상자 등 안쪽 문단(`<hp:p>` 중첩)은 제외한다.
+fn section_outline_xml(xml: &str, levels: &HeaderOutline) -> Result<Vec<OutlineEntry>> {
+    let mut reader = Reader::from_str(xml);
+    let mut entries = Vec::new();
+    let mut para_depth = 0usize;
+    let mut level: Option<u8> = None;
+    let mut in_t_tag = false;
+    let mut para_text = String::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => match e.local_name().as_ref() {
+                b"p" => {
+                    para_depth += 1;
+                    if para_depth == 1 {
+                        level = levels.level(
+                            attr_str(e, b"paraPrIDRef").as_deref(),
+                            attr_str(e, b"styleIDRef").as_deref(),
+                        );
+                        para_text.clear();
+                    }
+                }
+                b"t" => in_t_tag = true,
+                _ => {}
+            },
+            Ok(Event::End(ref e)) => match e.local_name().as_ref() {
+                b"p" => {
+                    para_depth = para_depth.saturating_sub(1);
+                    if para_depth == 0 {
+                        let text = para_text.trim();
+                        if let (Some(level), false) = (level.take(), text.is_empty()) {
+                            entries.push(OutlineEntry {
+                                level,
+                                text: text.to_string(),
+                            });
+                        }
+                    }
+                }
+                b"t" => in_t_tag = false,
+                _ => {}
+            },
+            Ok(Event::Text(ref e)) if in_t_tag && para_depth == 1 => {
+                let t = e
+                    .unescape()
+                    .map_err(|err| HwpError::Hwpx(format!("XML unescape: {}", err)))?;
+                para_text.push_str(&t);
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(HwpError::Hwpx(format!(
+                    "XML parse error at {}: {}",
+                    reader.error_position(),
+                    e
+                )));
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(entries)
+}
+
+/// HWPX 패키지의 미리보기 텍스트(`Preview/PrvText.txt`)를 읽는다.
+///
+/// 엔트리가 없거나 비어 있으면 `None`.
+pub fn read_preview_text(path: &Path) -> Result<Option<String>> {
+    let file = open_file(path)?;
+    let mut archive = zip::ZipArchive::new(BufReader::new(file))
+        .map_err(|e| HwpError::Hwpx(format!("ZIP open: {}", e)))?;
+    let Ok(mut entry) = archive.by_name("Preview/PrvText.txt") else {
+        return Ok(None);
+    };
+    let mut data = Vec::new();
+    entry.read_to_end(&mut data)?;
+    let text = String::from_utf8_lossy(&data);
+    let text = text.strip_prefix('\u{FEFF}').unwrap_or(&text);
+    Ok((!text.trim().is_empty()).then(|| text.to_string()))
+}
+
+/// HWPX 패키지의 미리보기 그림(`Preview/PrvImage.png`)을 읽는다. 엔트리가 없으면 `None`.
+pub fn read_preview_image(path: &Path) -> Result<Option<Vec<u8>>> {
+    let file = open_file(path)?;
+    let mut archive = zip::ZipArchive::new(BufReader::new(file))
+        .map_err(|e| HwpError::Hwpx(format!("ZIP open: {}", e)))?;
+    let Ok(mut entry) = archive.by_name("Preview/PrvImage.png") else {
+        return Ok(None);
+    };
+    let mut data = Vec::new();
+    entry.read_to_end(&mut data)?;
+    Ok(Some(data))
+}
+
+/// HWPX 패키지의 `BinData/` 엔트리마다 (이름, 압축 해제 크기)를 ZIP 디렉토리에서 읽는다.
+///
+/// 엔트리 내용은 읽지 않는다.
+pub fn bin_data_entries(path: &Path) -> Result<Vec<(String, u64)>> {
+    let file = open_file(path)?;
+    let mut archive = zip::ZipArchive::new(BufReader::new(file))
+        .map_err(|e| HwpError::Hwpx(format!("ZIP open: {}", e)))?;
+    let mut entries = Vec::new();
+    for i in 0..archive.len() {
+        let entry = archive
+            .by_index_raw(i)
+            .map_err(|e| HwpError::Hwpx(format!("ZIP entry {i}: {e}")))?;
+        if entry.is_file() && entry.name().starts_with("BinData/") {
+            entries.push((entry.name().to_string(), entry.size()));
+        }
+    }
+    Ok(entries)
+}
+
+/// 압축을 푼 HWPX 디렉토리에서 텍스트를 추출한다.
+///
+/// ZIP 대신 `dir/Contents/section*.xml`을 파일 시스템에서 직접 읽는다.
+pub fn extract_text_from_hwpx_dir(dir: &Path) -> Result<String> {
+    extract_text_from_hwpx_dir_with_options(dir, &ExtractOptions::default())
+}
+
+/// [`extract_text_from_hwpx_dir`]의 옵션 지정 버전
+pub fn extract_text_from_hwpx_dir_with_options(
+    dir: &Path,
+    opts: &ExtractOptions,
+) -> Result<String> {
+    let contents = dir.join("Contents");
+    let entries = std::fs::read_dir(&contents)
+        .map_err(|e| HwpError::Hwpx(format!("read {}: {}", contents.display(), e)))?;
+
+    let mut section_paths: Vec<(u32, std::path::PathBuf)> = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name();
+        if let Some(num) = name.to_str().and_then(section_number) {
+            section_paths.push((num, entry.path()));
+        }
+    }
+    section_paths.sort();
+
+    let mut section_xmls = Vec::new();
+    for (_, path) in &section_paths {
+        let xml = std::fs::read_to_string(path)?;
+        let name = format!(
+            "Contents/{}",
+            path.file_name().unwrap_or_default().to_string_lossy()
+        );
+        let parts =
+            read_referenced_parts(&name, |part| std::fs::read_to_string(dir.join(part)).ok());
+        section_xmls.push((xml, parts));
+    }
+
+    extract_section_xmls(section_xmls, opts)
+}
+
+/// 한컴 DRM으로 보호된 패키지에만 있는 엔트리인지 확인 (대소문자 무시)
+///
+/// DRM 패키지는 섹션 XML을 암호화된 형태로 두고 `Preferences/DRM*` 또는
+/// `META-INF/drm.xml` 엔트리에 보호 정보를 기록한다. 그대로 파싱하면
+/// 빈 텍스트가 나오므로 미리 구분한다.
+fn is_drm_marker(entry_name: &str) -> bool {
+    let name = entry_name.to_ascii_lowercase();
+    name.starts_with("preferences/drm") || name == "meta-inf/drm.xml"
+}
+
+/// `section{N}.xml` 파일 이름에서 섹션 번호를 읽는다.
+///
+/// 사전순 정렬은 section10이 section2보다 앞서므로 번호로 정렬한다.
+fn section_number(file_name: &str) -> Option<u32> {
+    file_name
+        .strip_prefix("section")?
+        .strip_suffix(".xml")?
+        .parse()
+        .ok()
+}
+
+/// 섹션이 관계(rels)로 참조하는 별도 파트: 관계 ID → 파트 XML
+type ReferencedParts = HashMap<String, String>;
+
+/// 섹션 파트의 관계 파일 경로 (`Contents/section0.xml` → `Contents/_rels/section0.xml.rels`)
+fn section_rels_path(section_path: &str) -> String {
+    match section_path.rsplit_once('/') {
+        Some((dir, file)) => format!("{dir}/_rels/{file}.rels"),
+        None => format!("_rels/{section_path}.rels"),
+    }
+}
+
+/// 관계 파일에서 XML 파트를 가리키는 (Id, Target) 목록을 읽는다.
+///
+/// 외부 대상(`TargetMode="External"`)과 이미지 등 XML이 아닌 파트는 제외한다.
+fn parse_relationships(xml: &str) -> Vec<(String, String)> {
+    let mut reader = Reader::from_str(xml);
+    let mut relationships = Vec::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) | Ok(Event::Empty(e))
+                if e.local_name().as_ref() == b"Relationship" =>
+            {
+                let attr = |name: &[u8]| {
+                    e.try_get_attribute(name)
+                        .ok()
+                        .flatten()
+                        .and_then(|a| a.unescape_value().ok())
+                        .map(|v| v.into_owned())
+                };
+                if attr(b"TargetMode").as_deref() == Some("External") {
+                    continue;
+                }
+                if let (Some(id), Some(target)) = (attr(b"Id"), attr(b"Target")) {
+                    if target.to_ascii_lowercase().ends_with(".xml") {
+                        relationships.push((id, target));
+                    }
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+    relationships
+}
+
+/// 관계 대상 경로를 패키지 내 경로로 바꾼다. 상대 경로는 섹션 파트의 디렉토리 기준이다.
+fn resolve_part_path(section_path: &str, target: &str) -> String {
+    let mut segments: Vec<&str> = match target.strip_prefix('/') {
+        Some(_) => Vec::new(),
+        None => section_path.split('/').collect(),
+    };
+    if !target.starts_with('/') {
+        segments.pop(); // 섹션 파일 이름
+    }
+    for segment in target.trim_start_matches('/').split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            s => segments.push(s),
+        }
+    }
+    segments.join("/")
+}
+
+/// 섹션의 관계 파일이 가리키는 파트(머리말/꼬리말/각주 등)를 읽는다.
+///
+/// 관계 파일이나 대상 파트가 없으면 건너뛴다.
+fn read_referenced_parts(
+    section_path: &str,
+    mut read: impl FnMut(&str) -> Option<String>,
+) -> ReferencedParts {
+    let Some(rels) = read(&section_rels_path(section_path)) else {
+        return ReferencedParts::new();
+    };
+    parse_relationships(&rels)
         .into_iter()
-        .map(|(_, t)| t)
-        .collect::<String>();
+        .filter_map(|(id, target)| Some((id, read(&resolve_part_path(section_path, &target))?)))
+        .collect()
+}
 
-    Ok(text)
+/// 요소의 관계 참조 속성(`r:id` 등 접두사가 붙은 `id`)이 가리키는 파트를 찾는다.
+fn referenced_part<'a>(e: &BytesStart, parts: &'a ReferencedParts) -> Option<&'a str> {
+    e.attributes().flatten().find_map(|a| {
+        if a.key.local_name().as_ref() != b"id" || a.key.prefix().is_none() {
+            return None;
+        }
+        let value = a.unescape_value().ok()?;
+        parts.get(value.as_ref()).map(String::as_str)
+    })
+}
+
+/// 섹션 XML 목록을 병렬 파싱하여 순서대로 병합한다.
+fn extract_section_xmls(
+    section_xmls: Vec<(String, ReferencedParts)>,
+    opts: &ExtractOptions,
+) -> Result<String> {
+    // 섹션별 XML 파싱을 병렬 수행 (결과는 섹션 순서대로)
+    let section_texts = par::map_sections(section_xmls, |(xml_data, parts)| {
+        let mut text = String::new();
+        extract_section_xml(&xml_data, &parts, opts, &mut text)?;
+        Ok(text)
+    });
+    section_texts.into_iter().collect()
 }
 
 /// 섹션 XML에서 텍스트를 추출한다.
 /// <hp:p> → 줄바꿈, <hp:t> → 텍스트 수집
-fn extract_section_xml(xml: &str, text: &mut String) -> Result<()> {
+///
+/// 관계 ID로 별도 파트를 참조하는 요소는 그 위치에 파트의 텍스트를 넣는다.
+/// 참조된 파트 안의 참조는 따라가지 않는다.
+fn extract_section_xml(
+    xml: &str,
+    parts: &ReferencedParts,
+    opts: &ExtractOptions,
+    text: &mut String,
+) -> Result<()> {
     let mut reader = Reader::from_str(xml);
     let mut in_t_tag = false;
     let mut para_has_text = false;
@@ -77,24 +514,104 @@ fn extract_section_xml(xml: &str, text: &mut String) -> Result<()> {
     let mut in_table = false;
     let mut in_tc = false;
     let mut table_rows: Vec<Vec<String>> = Vec::new();
+    let mut table_cells: Vec<(u16, u16, String)> = Vec::new();
     let mut current_row: Vec<String> = Vec::new();
     let mut current_cell_text = String::new();
+    let mut current_cell_addr: Option<(u16, u16)> = None;
     let mut tc_para_has_text = false;
 
+    // 하이퍼링크 추적 (include_hyperlinks)
+    let mut link_command: Option<String> = None;
+    let mut in_command_param = false;
+    // (연결 대상, 표시 문자열 시작 위치)
+    let mut link: Option<(String, usize)> = None;
+
+    // 그림 설명 추적 (include_image_alt)
+    let mut image_alt: Option<String> = None;
+    let mut in_shape_comment = false;
+
     loop {
         match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
+            Ok(ref event @ (Event::Start(ref e) | Event::Empty(ref e))) => {
                 let local_name = e.local_name();
                 let name = local_name.as_ref();
+                if opts.include_image_alt {
+                    // <hp:pic alt=".."> 또는 <hp:pic><hp:shapeComment>..</hp:shapeComment>
+                    if name == b"pic" {
+                        let alt = attr_str(e, b"alt")
+                            .or_else(|| attr_str(e, b"desc"))
+                            .unwrap_or_default();
+                        if matches!(event, Event::Empty(_)) {
+                            if in_tc {
+                                tc_para_has_text |= push_image_alt(&alt, &mut current_cell_text);
+                            } else if !in_table {
+                                para_has_text |= push_image_alt(&alt, text);
+                            }
+                        } else {
+                            image_alt = Some(alt);
+                        }
+                    } else if name == b"shapeComment" {
+                        in_shape_comment = image_alt.is_some();
+                    }
+                }
+                if opts.include_hyperlinks {
+                    // <hp:fieldBegin type="HYPERLINK"><hp:parameters>
+                    //   <hp:stringParam name="Command">http\://...;1;0;0;</hp:stringParam>
+                    if name == b"fieldBegin" && attr_str(e, b"type").as_deref() == Some("HYPERLINK")
+                    {
+                        link_command = Some(String::new());
+                    } else if name == b"stringParam" {
+                        in_command_param = link_command.is_some()
+                            && attr_str(e, b"name").as_deref() == Some("Command");
+                    } else if name == b"fieldEnd" {
+                        if let Some((target, start)) = link.take() {
+                            let out = if in_tc {
+                                &mut current_cell_text
+                            } else {
+                                &mut *text
+                            };
+                            if out
+                                .get(start..)
+                                .is_some_and(|shown| !shown.trim().is_empty())
+                            {
+                                out.insert(start, '[');
+                                out.push_str("](");
+                                out.push_str(&target);
+                                out.push(')');
+                            }
+                        }
+                    }
+                }
+                if let Some(part) = referenced_part(e, parts) {
+                    let mut part_text = String::new();
+                    extract_section_xml(part, &ReferencedParts::new(), opts, &mut part_text)?;
+                    let has_text = !part_text.trim().is_empty();
+                    if in_tc {
+                        tc_para_has_text |= has_text;
+                        current_cell_text.push_str(&part_text);
+                    } else if !in_table {
+                        para_has_text |= has_text;
+                        text.push_str(&part_text);
+                    }
+                }
                 if name == b"t" {
                     in_t_tag = true;
                 } else if name == b"tbl" {
                     in_table = true;
                     table_rows.clear();
+                    table_cells.clear();
                 } else if name == b"tc" {
                     in_tc = true;
                     current_cell_text.clear();
+                    current_cell_addr = None;
                     tc_para_has_text = false;
+                } else if name == b"cellAddr" && in_tc {
+                    // <hp:cellAddr colAddr=".." rowAddr=".."/>
+                    if let (Some(col), Some(row)) =
+                        (attr_u16(e, b"colAddr"), attr_u16(e, b"rowAddr"))
+                    {
+                        current_cell_addr = Some((col, row));
+                    }
                 } else if name == b"tr" && in_table {
                     current_row.clear();
                 } else if name == b"p" {
@@ -108,6 +625,30 @@ fn extract_section_xml(xml: &str, text: &mut String) -> Result<()> {
             Ok(Event::End(ref e)) => {
                 let local_name = e.local_name();
                 let name = local_name.as_ref();
+                if name == b"shapeComment" {
+                    in_shape_comment = false;
+                } else if name == b"pic" {
+                    if let Some(alt) = image_alt.take() {
+                        if in_tc {
+                            tc_para_has_text |= push_image_alt(&alt, &mut current_cell_text);
+                        } else if !in_table {
+                            para_has_text |= push_image_alt(&alt, text);
+                        }
+                    }
+                }
+                if name == b"stringParam" {
+                    in_command_param = false;
+                } else if name == b"fieldBegin" {
+                    in_command_param = false;
+                    if let Some(command) = link_command.take() {
+                        let start = if in_tc {
+                            current_cell_text.len()
+                        } else {
+                            text.len()
+                        };
+                        link = field::hyperlink_target(&command).map(|target| (target, start));
+                    }
+                }
                 if name == b"t" {
                     in_t_tag = false;
                 } else if name == b"p" {
@@ -125,6 +666,9 @@ fn extract_section_xml(xml: &str, text: &mut String) -> Result<()> {
                 } else if name == b"tc" {
                     // 셀 텍스트 끝의 줄바꿈 제거
                     let trimmed = current_cell_text.trim_end_matches('\n').to_string();
+                    let (col, row) = current_cell_addr
+                        .unwrap_or((current_row.len() as u16, table_rows.len() as u16));
+                    table_cells.push((col, row, trimmed.clone()));
                     current_row.push(trimmed);
                     in_tc = false;
                 } else if name == b"tr" && in_table {
@@ -132,29 +676,40 @@ fn extract_section_xml(xml: &str, text: &mut String) -> Result<()> {
                         table_rows.push(std::mem::take(&mut current_row));
                     }
                 } else if name == b"tbl" {
-                    emit_hwpx_markdown_table(&table_rows, text);
+                    emit_hwpx_table(&table_rows, &table_cells, opts, text);
                     table_rows.clear();
+                    table_cells.clear();
                     in_table = false;
                 }
             }
-            Ok(Event::Text(ref e)) => {
-                if in_t_tag {
-                    let t = e
-                        .unescape()
-                        .map_err(|err| HwpError::Hwpx(format!("XML unescape: {}", err)))?;
-                    if in_tc {
-                        if !t.is_empty() {
-                            tc_para_has_text = true;
-                        }
-                        current_cell_text.push_str(&t);
-                    } else if !in_table {
-                        if !t.is_empty() {
-                            para_has_text = true;
-                        }
-                        text.push_str(&t);
+            Ok(Event::Text(ref e)) if in_t_tag => {
+                let t = e
+                    .unescape()
+                    .map_err(|err| HwpError::Hwpx(format!("XML unescape: {}", err)))?;
+                if in_tc {
+                    if !t.is_empty() {
+                        tc_para_has_text = true;
+                    }
+                    current_cell_text.push_str(&t);
+                } else if !in_table {
+                    if !t.is_empty() {
+                        para_has_text = true;
+                    }
+                    text.push_str(&t);
+                }
+            }
+            Ok(Event::Text(ref e)) if in_shape_comment => {
+                if let (Some(alt), Ok(t)) = (image_alt.as_mut(), e.unescape()) {
+                    if alt.is_empty() {
+                        alt.push_str(&t);
                     }
                 }
             }
+            Ok(Event::Text(ref e)) if in_command_param => {
+                if let (Some(command), Ok(t)) = (link_command.as_mut(), e.unescape()) {
+                    command.push_str(&t);
+                }
+            }
             Ok(Event::Eof) => break,
             Err(e) => {
                 return Err(HwpError::Hwpx(format!(
@@ -171,58 +726,74 @@ fn extract_section_xml(xml: &str, text: &mut String) -> Result<()> {
     Ok(())
 }
 
-/// HWPX 표 데이터를 마크다운 테이블로 출력
-fn emit_hwpx_markdown_table(rows: &[Vec<String>], text: &mut String) {
-    if rows.is_empty() {
-        return;
-    }
-
-    let col_count = rows.iter().map(|r| r.len()).max().unwrap_or(0);
-    if col_count == 0 {
-        return;
+/// 그림 설명을 `[image: 설명]`으로 덧붙인다. 설명이 비어 있으면 아무것도 하지 않고 `false`.
+fn push_image_alt(alt: &str, out: &mut String) -> bool {
+    let alt = alt.trim();
+    if alt.is_empty() {
+        return false;
     }
+    out.push_str("[image: ");
+    out.push_str(alt);
+    out.push(']');
+    true
+}
 
-    for (i, row) in rows.iter().enumerate() {
-        text.push('|');
-        for j in 0..col_count {
-            let cell = row.get(j).map(|s| s.as_str()).unwrap_or("");
-            let escaped = escape_markdown_cell(cell);
-            text.push(' ');
-            text.push_str(&escaped);
-            text.push_str(" |");
+/// 표 출력 방식에 따라 HWPX/HWPML 표를 출력한다.
+fn emit_hwpx_table(
+    rows: &[Vec<String>],
+    cells: &[(u16, u16, String)],
+    opts: &ExtractOptions,
+    text: &mut String,
+) {
+    match opts.table_mode {
+        TableMode::Markdown => text.push_str(&markdown::format_table(rows, opts.cell_newlines)),
+        TableMode::Coordinates => {
+            text.push_str(&format_coordinate_cells(cells, opts.cell_newlines))
         }
-        text.push('\n');
-
-        // 첫 행 뒤에 구분선
-        if i == 0 {
-            text.push('|');
-            for _ in 0..col_count {
-                text.push_str(" --- |");
-            }
-            text.push('\n');
+        TableMode::TabSeparated => {
+            text.push_str(&format_tab_separated_rows(rows, opts.cell_newlines))
         }
     }
 }
 
+/// 요소 속성값을 u16으로 읽는다.
+fn attr_u16(e: &BytesStart, name: &[u8]) -> Option<u16> {
+    attr_str(e, name)?.trim().parse().ok()
+}
+
+/// 요소 속성값을 문자열로 읽는다.
+fn attr_str(e: &BytesStart, name: &[u8]) -> Option<String> {
+    let attr = e.try_get_attribute(name).ok()??;
+    Some(attr.unescape_value().ok()?.into_owned())
+}
+
 /// HWPML (순수 XML, ZIP 없음) 파일에서 텍스트를 추출한다.
 /// 구조: `HWPML → BODY → SECTION → P → TEXT → CHAR`
 pub fn extract_text_from_hwpml(path: &Path) -> Result<String> {
-    let mut file = File::open(path)?;
+    extract_text_from_hwpml_with_options(path, &ExtractOptions::default())
+}
+
+/// [`extract_text_from_hwpml`]의 옵션 지정 버전
+pub fn extract_text_from_hwpml_with_options(path: &Path, opts: &ExtractOptions) -> Result<String> {
+    let mut file = open_file(path)?;
     let mut xml_data = String::new();
     file.read_to_string(&mut xml_data)
         .map_err(|e| HwpError::Hwpx(format!("read HWPML: {}", e)))?;
 
+    // BOM·선행 공백 제거 (XML 선언 앞에 오면 파싱 오류)
+    let xml_data = xml_data.trim_start_matches('\u{FEFF}').trim_start();
+
     // quick-xml은 DTD 엔티티를 지원하지 않으므로 &nbsp; → &#160; 치환
     let xml_data = xml_data.replace("&nbsp;", "&#160;");
 
     let mut text = String::new();
-    extract_hwpml_xml(&xml_data, &mut text)?;
+    extract_hwpml_xml(&xml_data, opts, &mut text)?;
     Ok(text)
 }
 
 /// HWPML XML에서 텍스트를 추출한다.
 /// <P> → 줄바꿈, <CHAR> → 텍스트 수집
-fn extract_hwpml_xml(xml: &str, text: &mut String) -> Result<()> {
+fn extract_hwpml_xml(xml: &str, opts: &ExtractOptions, text: &mut String) -> Result<()> {
     let mut reader = Reader::from_str(xml);
     let mut in_char_tag = false;
     let mut para_has_text = false;
@@ -232,8 +803,10 @@ fn extract_hwpml_xml(xml: &str, text: &mut String) -> Result<()> {
     let mut in_table = false;
     let mut in_cell = false;
     let mut table_rows: Vec<Vec<String>> = Vec::new();
+    let mut table_cells: Vec<(u16, u16, String)> = Vec::new();
     let mut current_row: Vec<String> = Vec::new();
     let mut current_cell_text = String::new();
+    let mut current_cell_addr: Option<(u16, u16)> = None;
     let mut cell_para_has_text = false;
 
     loop {
@@ -246,9 +819,12 @@ fn extract_hwpml_xml(xml: &str, text: &mut String) -> Result<()> {
                 } else if name == b"TABLE" {
                     in_table = true;
                     table_rows.clear();
+                    table_cells.clear();
                 } else if name == b"CELL" {
                     in_cell = true;
                     current_cell_text.clear();
+                    // <CELL ColAddr=".." RowAddr="..">
+                    current_cell_addr = attr_u16(e, b"ColAddr").zip(attr_u16(e, b"RowAddr"));
                     cell_para_has_text = false;
                 } else if name == b"ROW" && in_table {
                     current_row.clear();
@@ -279,6 +855,9 @@ fn extract_hwpml_xml(xml: &str, text: &mut String) -> Result<()> {
                     }
                 } else if name == b"CELL" {
                     let trimmed = current_cell_text.trim_end_matches('\n').to_string();
+                    let (col, row) = current_cell_addr
+                        .unwrap_or((current_row.len() as u16, table_rows.len() as u16));
+                    table_cells.push((col, row, trimmed.clone()));
                     current_row.push(trimmed);
                     in_cell = false;
                 } else if name == b"ROW" && in_table {
@@ -286,27 +865,26 @@ fn extract_hwpml_xml(xml: &str, text: &mut String) -> Result<()> {
                         table_rows.push(std::mem::take(&mut current_row));
                     }
                 } else if name == b"TABLE" {
-                    emit_hwpx_markdown_table(&table_rows, text);
+                    emit_hwpx_table(&table_rows, &table_cells, opts, text);
                     table_rows.clear();
+                    table_cells.clear();
                     in_table = false;
                 }
             }
-            Ok(Event::Text(ref e)) => {
-                if in_char_tag {
-                    let t = e
-                        .unescape()
-                        .map_err(|err| HwpError::Hwpx(format!("HWPML unescape: {}", err)))?;
-                    if in_cell {
-                        if !t.is_empty() {
-                            cell_para_has_text = true;
-                        }
-                        current_cell_text.push_str(&t);
-                    } else if !in_table {
-                        if !t.is_empty() {
-                            para_has_text = true;
-                        }
-                        text.push_str(&t);
+            Ok(Event::Text(ref e)) if in_char_tag => {
+                let t = e
+                    .unescape()
+                    .map_err(|err| HwpError::Hwpx(format!("HWPML unescape: {}", err)))?;
+                if in_cell {
+                    if !t.is_empty() {
+                        cell_para_has_text = true;
+                    }
+                    current_cell_text.push_str(&t);
+                } else if !in_table {
+                    if !t.is_empty() {
+                        para_has_text = true;
                     }
+                    text.push_str(&t);
                 }
             }
             Ok(Event::Eof) => break,
@@ -325,14 +903,10 @@ fn extract_hwpml_xml(xml: &str, text: &mut String) -> Result<()> {
     Ok(())
 }
 
-/// 마크다운 셀 텍스트 이스케이프: 줄바꿈 → 공백, | → \|
-fn escape_markdown_cell(s: &str) -> String {
-    s.replace('|', "\\|").replace('\n', " ")
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::options::CellNewlines;
 
     #[test]
     fn test_extract_section_xml_simple() {
@@ -351,7 +925,13 @@ mod tests {
 </hp:sec>"#;
 
         let mut text = String::new();
-        extract_section_xml(xml, &mut text).unwrap();
+        extract_section_xml(
+            xml,
+            &ReferencedParts::new(),
+            &ExtractOptions::default(),
+            &mut text,
+        )
+        .unwrap();
         assert!(text.contains("안녕하세요"));
         assert!(text.contains("테스트"));
     }
@@ -371,7 +951,7 @@ mod tests {
 </HWPML>"#;
 
         let mut text = String::new();
-        extract_hwpml_xml(xml, &mut text).unwrap();
+        extract_hwpml_xml(xml, &ExtractOptions::default(), &mut text).unwrap();
         assert!(text.contains("안녕하세요"), "got: {:?}", text);
         assert!(text.contains("HWPML 테스트"), "got: {:?}", text);
     }
@@ -391,7 +971,13 @@ mod tests {
 </hp:sec>"#;
 
         let mut text = String::new();
-        extract_section_xml(xml, &mut text).unwrap();
+        extract_section_xml(
+            xml,
+            &ReferencedParts::new(),
+            &ExtractOptions::default(),
+            &mut text,
+        )
+        .unwrap();
         assert!(text.contains("Hello World"));
     }
 
@@ -401,7 +987,13 @@ mod tests {
 <hp:sec xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph">
 </hp:sec>"#;
         let mut text = String::new();
-        extract_section_xml(xml, &mut text).unwrap();
+        extract_section_xml(
+            xml,
+            &ReferencedParts::new(),
+            &ExtractOptions::default(),
+            &mut text,
+        )
+        .unwrap();
         assert!(text.trim().is_empty());
     }
 
@@ -409,7 +1001,12 @@ mod tests {
     fn test_extract_section_xml_invalid_xml() {
         let xml = "this is not valid xml <<<<";
         let mut text = String::new();
-        let result = extract_section_xml(xml, &mut text);
+        let result = extract_section_xml(
+            xml,
+            &ReferencedParts::new(),
+            &ExtractOptions::default(),
+            &mut text,
+        );
         assert!(result.is_err());
     }
 
@@ -431,35 +1028,6 @@ mod tests {
         std::fs::remove_file(&path).ok();
     }
 
-    #[test]
-    fn test_escape_markdown_cell_hwpx() {
-        assert_eq!(escape_markdown_cell(""), "");
-        assert_eq!(escape_markdown_cell("hello"), "hello");
-        assert_eq!(escape_markdown_cell("a|b"), "a\\|b");
-        assert_eq!(escape_markdown_cell("x\ny"), "x y");
-    }
-
-    #[test]
-    fn test_emit_hwpx_markdown_table_empty() {
-        let rows: Vec<Vec<String>> = vec![];
-        let mut text = String::new();
-        emit_hwpx_markdown_table(&rows, &mut text);
-        assert!(text.is_empty());
-    }
-
-    #[test]
-    fn test_emit_hwpx_markdown_table_basic() {
-        let rows = vec![
-            vec!["A".to_string(), "B".to_string()],
-            vec!["C".to_string(), "D".to_string()],
-        ];
-        let mut text = String::new();
-        emit_hwpx_markdown_table(&rows, &mut text);
-        assert!(text.contains("| A | B |"));
-        assert!(text.contains("| --- | --- |"));
-        assert!(text.contains("| C | D |"));
-    }
-
     #[test]
     fn test_extract_section_xml_table() {
         let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
@@ -476,8 +1044,301 @@ mod tests {
   </hp:p>
 </hp:sec>"#;
         let mut text = String::new();
-        extract_section_xml(xml, &mut text).unwrap();
+        extract_section_xml(
+            xml,
+            &ReferencedParts::new(),
+            &ExtractOptions::default(),
+            &mut text,
+        )
+        .unwrap();
         assert!(text.contains("셀1"), "got: {text:?}");
         assert!(text.contains("셀2"), "got: {text:?}");
     }
+
+    #[test]
+    fn test_extract_section_xml_table_coordinates() {
+        let xml = r#"<hp:sec xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph">
+  <hp:p><hp:run><hp:tbl>
+    <hp:tr>
+      <hp:tc><hp:cellAddr colAddr="0" rowAddr="0"/><hp:p><hp:run><hp:t>병합</hp:t></hp:run></hp:p></hp:tc>
+      <hp:tc><hp:cellAddr colAddr="2" rowAddr="0"/><hp:p><hp:run><hp:t>오른쪽</hp:t></hp:run></hp:p></hp:tc>
+    </hp:tr>
+    <hp:tr>
+      <hp:tc><hp:cellAddr colAddr="1" rowAddr="1"/><hp:p><hp:run><hp:t>아래</hp:t></hp:run></hp:p></hp:tc>
+    </hp:tr>
+  </hp:tbl></hp:run></hp:p>
+</hp:sec>"#;
+        let opts = ExtractOptions {
+            table_mode: TableMode::Coordinates,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_xml(xml, &ReferencedParts::new(), &opts, &mut text).unwrap();
+        assert!(
+            text.contains("R1C1: 병합\nR1C3: 오른쪽\nR2C2: 아래\n"),
+            "got: {text:?}"
+        );
+        assert!(!text.contains("| --- |"));
+    }
+
+    #[test]
+    fn test_extract_section_xml_table_tab_separated() {
+        let xml = r#"<hp:sec xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:tbl><hp:tr><hp:tc><hp:p><hp:run><hp:t>첫 줄</hp:t></hp:run></hp:p><hp:p><hp:run><hp:t>둘째 줄</hp:t></hp:run></hp:p></hp:tc><hp:tc><hp:p><hp:run><hp:t>B</hp:t></hp:run></hp:p></hp:tc></hp:tr></hp:tbl></hp:run></hp:p></hp:sec>"#;
+        let render = |cell_newlines| {
+            let opts = ExtractOptions {
+                table_mode: TableMode::TabSeparated,
+                cell_newlines,
+                ..Default::default()
+            };
+            let mut text = String::new();
+            extract_section_xml(xml, &ReferencedParts::new(), &opts, &mut text).unwrap();
+            text
+        };
+        assert!(render(CellNewlines::Space).starts_with("첫 줄 둘째 줄\tB\n"));
+        assert!(render(CellNewlines::LiteralBackslashN).starts_with("첫 줄\\n둘째 줄\tB\n"));
+        assert!(render(CellNewlines::Preserve).starts_with("첫 줄\n둘째 줄\tB\n"));
+    }
+
+    #[test]
+    fn test_extract_hwpml_xml_table_coordinates() {
+        let xml = r#"<HWPML><BODY><SECTION><P><TEXT>
+<TABLE><ROW>
+  <CELL ColAddr="0" RowAddr="0"><PARALIST><P><TEXT><CHAR>가</CHAR></TEXT></P></PARALIST></CELL>
+  <CELL ColAddr="1" RowAddr="0"><PARALIST><P><TEXT><CHAR>나</CHAR></TEXT></P></PARALIST></CELL>
+</ROW></TABLE>
+</TEXT></P></SECTION></BODY></HWPML>"#;
+        let opts = ExtractOptions {
+            table_mode: TableMode::Coordinates,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_hwpml_xml(xml, &opts, &mut text).unwrap();
+        assert!(text.contains("R1C1: 가\nR1C2: 나\n"), "got: {text:?}");
+    }
+
+    #[test]
+    fn test_section_number() {
+        assert_eq!(section_number("section0.xml"), Some(0));
+        assert_eq!(section_number("section12.xml"), Some(12));
+        assert_eq!(section_number("section.xml"), None);
+        assert_eq!(section_number("header.xml"), None);
+        assert_eq!(section_number("section1.xml.bak"), None);
+    }
+
+    #[test]
+    fn test_extract_text_from_hwpx_dir() {
+        let dir = std::env::temp_dir().join(format!("hwarang_{}_hwpx_dir", std::process::id()));
+        let contents = dir.join("Contents");
+        std::fs::create_dir_all(&contents).unwrap();
+        for (num, body) in [(0, "첫째"), (2, "셋째"), (10, "열한째"), (1, "둘째")] {
+            let xml = format!(
+                r#"<hp:sec xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>{body}</hp:t></hp:run></hp:p></hp:sec>"#
+            );
+            std::fs::write(contents.join(format!("section{num}.xml")), xml).unwrap();
+        }
+        std::fs::write(contents.join("header.xml"), "<hh:head/>").unwrap();
+
+        let text = extract_text_from_hwpx_dir(&dir);
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(text.unwrap(), "첫째\n둘째\n셋째\n열한째\n");
+    }
+
+    #[test]
+    fn test_extract_section_xml_hyperlink() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>참고: </hp:t><hp:ctrl><hp:fieldBegin id="1" type="HYPERLINK" name=""><hp:parameters cnt="2"><hp:integerParam name="Prop">0</hp:integerParam><hp:stringParam name="Command">https\://www.hancom.com;1;0;0;</hp:stringParam></hp:parameters></hp:fieldBegin></hp:ctrl><hp:t>한컴</hp:t><hp:ctrl><hp:fieldEnd beginIDRef="1"/></hp:ctrl><hp:t> 끝</hp:t></hp:run></hp:p></hs:sec>"#;
+        let opts = ExtractOptions {
+            include_hyperlinks: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_xml(xml, &ReferencedParts::new(), &opts, &mut text).unwrap();
+        assert_eq!(text, "참고: [한컴](https://www.hancom.com) 끝\n");
+
+        let mut text = String::new();
+        extract_section_xml(
+            xml,
+            &ReferencedParts::new(),
+            &ExtractOptions::default(),
+            &mut text,
+        )
+        .unwrap();
+        assert_eq!(text, "참고: 한컴 끝\n");
+    }
+
+    #[test]
+    fn test_extract_section_xml_image_alt() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>그림 </hp:t><hp:pic id="1"><hp:shapeComment>조직도</hp:shapeComment><hp:img binaryItemIDRef="image1"/></hp:pic></hp:run></hp:p><hp:p><hp:run><hp:pic alt="로고"/></hp:run></hp:p><hp:p><hp:run><hp:pic id="3"><hp:img binaryItemIDRef="image3"/></hp:pic></hp:run></hp:p></hs:sec>"#;
+        let opts = ExtractOptions {
+            include_image_alt: true,
+            ..Default::default()
+        };
+        let mut text = String::new();
+        extract_section_xml(xml, &ReferencedParts::new(), &opts, &mut text).unwrap();
+        assert_eq!(text, "그림 [image: 조직도]\n[image: 로고]\n\n\n");
+
+        let mut text = String::new();
+        extract_section_xml(
+            xml,
+            &ReferencedParts::new(),
+            &ExtractOptions::default(),
+            &mut text,
+        )
+        .unwrap();
+        assert_eq!(text, "그림 \n\n\n\n\n");
+    }
+
+    #[test]
+    fn test_read_preview_text() {
+        let bytes = crate::test_util::build_zip(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Preview/PrvText.txt", "\u{FEFF}미리보기"),
+        ]);
+        let path = crate::test_util::temp_file("preview.hwpx", &bytes);
+        let preview = read_preview_text(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(preview.unwrap().as_deref(), Some("미리보기"));
+    }
+
+    #[test]
+    fn test_extract_filtered_custom_parts() {
+        let part = |text: &str| {
+            format!(
+                r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>{text}</hp:t></hp:run></hp:p></hs:sec>"#
+            )
+        };
+        let (body2, body10, section) = (part("둘째"), part("열째"), part("표준"));
+        let bytes = crate::test_util::build_zip(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Body/part10.xml", &body10),
+            ("Body/part2.xml", &body2),
+            ("Contents/section0.xml", &section),
+        ]);
+        let path = crate::test_util::temp_file("filtered.hwpx", &bytes);
+        let custom = extract_text_from_hwpx_filtered(&path, |name| name.starts_with("Body/"));
+        let standard = extract_text_from_hwpx(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(custom.unwrap(), "둘째\n열째\n");
+        assert_eq!(standard.unwrap(), "표준\n");
+        assert_eq!(part_number("Contents/section10.xml"), 10);
+        assert_eq!(part_number("Contents/header.xml"), u32::MAX);
+        assert!(is_section_entry("Contents/section3.xml"));
+        assert!(!is_section_entry("Contents/header.xml"));
+    }
+
+    #[test]
+    fn test_drm_protected_hwpx() {
+        let bytes = crate::test_util::build_zip(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Preferences/DRM.xml", "<drm/>"),
+            ("Contents/section0.xml", "\u{1}\u{2}encrypted"),
+        ]);
+        let path = crate::test_util::temp_file("drm.hwpx", &bytes);
+        let result = extract_text_from_hwpx(&path);
+        std::fs::remove_file(&path).ok();
+        match result {
+            Err(HwpError::DrmProtected(entry)) => assert_eq!(entry, "Preferences/DRM.xml"),
+            other => panic!("expected DRM error, got {other:?}"),
+        }
+
+        assert!(is_drm_marker("META-INF/DRM.xml"));
+        assert!(!is_drm_marker("META-INF/manifest.xml"));
+        assert!(!is_drm_marker("Contents/section0.xml"));
+    }
+
+    #[test]
+    fn test_resolve_part_path() {
+        assert_eq!(
+            section_rels_path("Contents/section0.xml"),
+            "Contents/_rels/section0.xml.rels"
+        );
+        assert_eq!(
+            resolve_part_path("Contents/section0.xml", "notes/fn1.xml"),
+            "Contents/notes/fn1.xml"
+        );
+        assert_eq!(
+            resolve_part_path("Contents/section0.xml", "../Shared/h.xml"),
+            "Shared/h.xml"
+        );
+        assert_eq!(
+            resolve_part_path("Contents/section0.xml", "/Shared/h.xml"),
+            "Shared/h.xml"
+        );
+    }
+
+    #[test]
+    fn test_hwpx_section_with_referenced_footnote_part() {
+        let section = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><hp:p><hp:run><hp:t>본문</hp:t><hp:footNote r:id="rId1"/></hp:run></hp:p><hp:p><hp:run><hp:t>다음</hp:t></hp:run></hp:p></hs:sec>"#;
+        let rels = r#"<?xml version="1.0"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="footnote" Target="notes/footnote1.xml"/><Relationship Id="rId2" Type="image" Target="../BinData/image1.png"/><Relationship Id="rId3" Type="link" Target="http://example.com/a.xml" TargetMode="External"/></Relationships>"#;
+        let footnote = r#"<hp:subList xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>각주 내용</hp:t></hp:run></hp:p></hp:subList>"#;
+
+        let bytes = crate::test_util::build_zip(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/section0.xml", section),
+            ("Contents/_rels/section0.xml.rels", rels),
+            ("Contents/notes/footnote1.xml", footnote),
+        ]);
+        let path = crate::test_util::temp_file("rels.hwpx", &bytes);
+        let text = extract_text_from_hwpx(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(text.unwrap(), "본문각주 내용\n\n다음\n");
+    }
+
+    #[test]
+    fn test_parse_relationships_skips_external_and_non_xml() {
+        let rels = r#"<Relationships><Relationship Id="a" Target="h.xml"/><Relationship Id="b" Target="i.png"/><Relationship Id="c" Target="x.xml" TargetMode="External"/></Relationships>"#;
+        assert_eq!(
+            parse_relationships(rels),
+            [("a".to_string(), "h.xml".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_extract_outline_from_hwpx() {
+        let header = r#"<hh:head xmlns:hh="http://www.hancom.co.kr/hwpml/2011/head"><hh:refList>
+<hh:paraProperties>
+  <hh:paraPr id="0"><hh:heading type="NONE" idRef="0" level="0"/></hh:paraPr>
+  <hh:paraPr id="1"><hh:heading type="OUTLINE" idRef="0" level="0"/></hh:paraPr>
+</hh:paraProperties>
+<hh:styles>
+  <hh:style id="0" type="PARA" name="바탕글" engName="Normal" paraPrIDRef="0"/>
+  <hh:style id="3" type="PARA" name="개요 2" engName="Outline 2" paraPrIDRef="0"/>
+</hh:styles>
+</hh:refList></hh:head>"#;
+        let section = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph">
+<hp:p paraPrIDRef="1" styleIDRef="0"><hp:run><hp:t>1. 개요</hp:t></hp:run></hp:p>
+<hp:p paraPrIDRef="0" styleIDRef="0"><hp:run><hp:t>본문</hp:t>
+  <hp:tbl><hp:tr><hp:tc><hp:subList><hp:p paraPrIDRef="1" styleIDRef="0"><hp:run><hp:t>셀</hp:t></hp:run></hp:p></hp:subList></hp:tc></hp:tr></hp:tbl>
+</hp:run></hp:p>
+<hp:p paraPrIDRef="0" styleIDRef="3"><hp:run><hp:t>가. 배경</hp:t></hp:run></hp:p>
+<hp:p paraPrIDRef="1" styleIDRef="0"/>
+</hs:sec>"#;
+        let bytes = crate::test_util::build_zip(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/header.xml", header),
+            ("Contents/section0.xml", section),
+        ]);
+        let path = crate::test_util::temp_file("outline.hwpx", &bytes);
+        let outline = crate::extract_outline(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(
+            outline.unwrap(),
+            [
+                OutlineEntry {
+                    level: 1,
+                    text: "1. 개요".into()
+                },
+                OutlineEntry {
+                    level: 2,
+                    text: "가. 배경".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_text_from_hwpx_dir_missing_contents() {
+        let result = extract_text_from_hwpx_dir(Path::new("/nonexistent/hwpx_dir"));
+        assert!(matches!(result, Err(HwpError::Hwpx(_))));
+    }
 }
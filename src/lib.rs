@@ -2,27 +2,45 @@ pub mod error;
 pub mod extract;
 pub mod hwp;
 pub mod hwpx;
+pub mod markdown;
+pub mod options;
+mod par;
+pub mod validation;
+
+#[cfg(test)]
+mod test_util;
 
 use std::fs::File;
-use std::io::Read;
+use std::io::{Cursor, Read, Seek};
 use std::path::{Path, PathBuf};
 
-use rayon::prelude::*;
+pub use crate::extract::{
+    DocumentRegions, HeaderFooterPages, OutlineEntry, ParaKind, ParaSpan, Paragraph, SectionLayout,
+};
+pub use crate::hwp::docinfo::Alignment;
+pub use crate::options::{
+    CellNewlines, ExtractOptions, FloatingObjects, FootnotePlacement, TableMode,
+};
+pub use crate::validation::{ValidationIssue, ValidationReport};
 
-use crate::error::{HwpError, Result};
+use crate::error::{open_file, HwpError, Result, Warning};
 use crate::extract as text_extract;
+use crate::hwp::bindata::BinCompression;
 use crate::hwp::crypto;
 use crate::hwp::docinfo;
 use crate::hwp::header::FileHeader;
+use crate::hwp::ole;
+use crate::hwp::para_text;
 use crate::hwp::record;
+use crate::hwp::script::{self, DocumentScripts};
 use crate::hwp::stream;
+use crate::hwp::summary::{self, DocumentSummary};
+use crate::par::*;
 
 /// Extracts text content from an HWP or HWPX document file.
 ///
-/// Automatically detects the file format by reading the magic bytes:
-/// - `D0 CF 11 E0` — HWP (OLE compound document)
-/// - `50 4B 03 04` — HWPX (ZIP-based OWPML)
-/// - `3C 3F 78 6D` — HWPML (plain XML)
+/// Automatically detects the file format from its leading bytes; see
+/// [`detect_format`].
 ///
 /// # Errors
 ///
@@ -40,325 +58,2222 @@ use crate::hwp::stream;
 /// # Ok::<(), hwarang::error::HwpError>(())
 /// ```
 pub fn extract_text_from_file(path: &Path) -> Result<String> {
-    let mut file = File::open(path)?;
-    let mut magic = [0u8; 4];
-    let n = file.read(&mut magic)?;
-    drop(file);
-
-    if n < 4 {
-        return Err(HwpError::UnsupportedFormat);
-    }
-
-    match magic {
-        [0x50, 0x4B, 0x03, 0x04] => hwpx::extract_text_from_hwpx(path), // ZIP (HWPX)
-        [0xD0, 0xCF, 0x11, 0xE0] => extract_text_from_hwp(path),        // OLE (HWP)
-        [0x3C, 0x3F, 0x78, 0x6D] => hwpx::extract_text_from_hwpml(path), // <?xml (HWPML)
-        _ => Err(HwpError::UnsupportedFormat),
-    }
+    extract_text_from_file_with_options(path, &ExtractOptions::default())
 }
 
-/// HWP(OLE 컨테이너) 파일에서 텍스트를 추출한다.
+/// Extracts text from an HWP or HWPX document file using custom options.
 ///
-/// 섹션별 병렬 처리: CFB 스트림 I/O 후 압축해제·파싱·텍스트 추출을
-/// rayon으로 병렬 수행한다.
-fn extract_text_from_hwp(path: &Path) -> Result<String> {
-    let file = File::open(path)?;
-    let mut comp = cfb::CompoundFile::open(file)?;
-
-    // FileHeader 스트림 읽기
-    let header = {
-        let mut stream = comp
-            .open_stream("/FileHeader")
-            .map_err(|_| HwpError::StreamNotFound("FileHeader".into()))?;
-        FileHeader::from_reader(&mut stream)?
-    };
-
-    // DocInfo에서 section_count 파싱
-    let doc_info = {
-        let mut s = comp
-            .open_stream("/DocInfo")
-            .map_err(|_| HwpError::StreamNotFound("DocInfo".into()))?;
-        let data = stream::read_and_decompress(&mut s, header.compressed)?;
-        let records = record::read_records(&data)?;
-        docinfo::parse_doc_info(&records)?
-    };
-
-    let storage = if header.distribution {
-        "ViewText"
-    } else {
-        "BodyText"
-    };
-
-    // Phase 1: 모든 섹션의 raw 스트림 데이터를 순차 읽기 (CFB I/O)
-    let mut section_raw: Vec<(u16, Vec<u8>)> = Vec::new();
-    for i in 0..doc_info.section_count {
-        let stream_name = format!("/{}/Section{}", storage, i);
-        let mut s = match comp.open_stream(&stream_name) {
-            Ok(s) => s,
-            Err(_) => continue,
-        };
-        let raw = stream::read_stream_data(&mut s)?;
-        section_raw.push((i, raw));
-    }
-
-    // Phase 2: 섹션별 병렬 처리 (압축해제 + 레코드 파싱 + 텍스트 추출)
-    let compressed = header.compressed;
-    let distribution = header.distribution;
-
-    let mut section_texts: Vec<(u16, String)> = section_raw
-        .into_par_iter()
-        .map(|(i, raw)| {
-            let data = if distribution {
-                let decrypted = crypto::decrypt_distribution_stream(&raw)?;
-                if compressed {
-                    stream::decompress(&decrypted)?
-                } else {
-                    decrypted
-                }
-            } else if compressed {
-                stream::decompress(&raw)?
-            } else {
-                raw
-            };
-
-            let records = record::read_records(&data)?;
-            let mut text = String::new();
-            text_extract::extract_section_text(&records, &mut text);
-            Ok((i, text))
-        })
-        .collect::<Result<Vec<_>>>()?;
-
-    // Phase 3: 섹션 순서대로 병합
-    section_texts.sort_unstable_by_key(|(i, _)| *i);
-    let text = section_texts
-        .into_iter()
-        .map(|(_, t)| t)
-        .collect::<String>();
-
-    Ok(text)
+/// Behaves like [`extract_text_from_file`] but lets the caller control the
+/// output through [`ExtractOptions`].
+///
+/// # Errors
+///
+/// Same as [`extract_text_from_file`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use hwarang::{ExtractOptions, TableMode};
+///
+/// let opts = ExtractOptions {
+///     table_mode: TableMode::Coordinates,
+///     ..Default::default()
+/// };
+/// let text = hwarang::extract_text_from_file_with_options(Path::new("table.hwp"), &opts)?;
+/// println!("{text}");
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn extract_text_from_file_with_options(path: &Path, opts: &ExtractOptions) -> Result<String> {
+    extract_text_with_format(path, opts).map(|(text, _, _)| text)
 }
 
-/// Lists all streams inside an OLE compound file.
+/// Extracts text like [`extract_text_from_file_with_options`] and also
+/// returns the recoverable problems met along the way.
 ///
-/// Useful for inspecting the internal structure of an HWP file.
+/// Corrupt parts of a document that can be worked around, such as a
+/// truncated section stream or a table declaring more cells than
+/// [`ExtractOptions::max_table_cells`], do not fail extraction; each one is
+/// reported as a [`Warning`] instead, so batch jobs can flag documents whose
+/// text may be incomplete. Only HWP documents produce warnings.
 ///
 /// # Errors
 ///
-/// Returns an error if the file cannot be opened or is not a valid OLE
-/// compound document.
+/// Same as [`extract_text_from_file`].
 ///
 /// # Examples
 ///
 /// ```no_run
 /// use std::path::Path;
+/// use hwarang::ExtractOptions;
 ///
-/// let streams = hwarang::list_streams(Path::new("document.hwp"))?;
-/// for s in &streams {
-///     println!("{s}");
+/// let (text, warnings) =
+///     hwarang::extract_text_with_warnings(Path::new("document.hwp"), &ExtractOptions::default())?;
+/// for warning in &warnings {
+///     eprintln!("warning: {warning}");
 /// }
+/// println!("{text}");
 /// # Ok::<(), hwarang::error::HwpError>(())
 /// ```
-pub fn list_streams(path: &Path) -> Result<Vec<String>> {
-    let file = File::open(path)?;
-    let comp = cfb::CompoundFile::open(file)?;
-    Ok(comp
-        .walk()
-        .map(|e| e.path().to_string_lossy().into_owned())
-        .collect())
-}
-
-/// The outcome of extracting text from a single file in a batch operation.
-///
-/// Used by [`extract_text_batch`] to report per-file success or failure
-/// without aborting the entire batch.
-#[derive(Debug)]
-pub struct BatchResult {
-    /// The path of the processed file.
-    pub path: PathBuf,
-    /// Extracted text on success, or the error that occurred.
-    pub result: Result<String>,
+pub fn extract_text_with_warnings(
+    path: &Path,
+    opts: &ExtractOptions,
+) -> Result<(String, Vec<Warning>)> {
+    extract_text_with_format(path, opts).map(|(text, _, warnings)| (text, warnings))
 }
 
-/// Extracts text from multiple HWP/HWPX files in parallel.
+/// Extracts text from a document file and also returns the format it was
+/// detected as, so callers that log or branch on the format do not have to
+/// sniff the file again.
 ///
-/// Every file is processed concurrently using rayon's work-stealing
-/// scheduler. Within each file, sections are also processed in parallel
-/// (see [`extract_text_from_file`]), so all available CPU cores are
-/// fully utilised even when the input contains only a handful of
-/// large documents.
+/// # Errors
 ///
-/// The returned [`Vec<BatchResult>`] preserves the input order.
+/// Same as [`extract_text_from_file`].
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use std::path::PathBuf;
+/// use std::path::Path;
 ///
-/// let paths = vec![
-///     PathBuf::from("a.hwp"),
-///     PathBuf::from("b.hwpx"),
-/// ];
-/// let results = hwarang::extract_text_batch(&paths);
-/// for br in &results {
-///     match &br.result {
-///         Ok(text) => println!("{}: {} chars", br.path.display(), text.len()),
-///         Err(e) => eprintln!("{}: {}", br.path.display(), e),
-///     }
-/// }
+/// let (text, format) = hwarang::extract_text_and_format(Path::new("document.hwpx"))?;
+/// println!("{format:?}: {} chars", text.chars().count());
+/// # Ok::<(), hwarang::error::HwpError>(())
 /// ```
-pub fn extract_text_batch(paths: &[PathBuf]) -> Vec<BatchResult> {
-    paths
-        .par_iter()
-        .map(|path| BatchResult {
-            path: path.clone(),
-            result: extract_text_from_file(path),
-        })
+pub fn extract_text_and_format(path: &Path) -> Result<(String, DocumentFormat)> {
+    extract_text_with_format(path, &ExtractOptions::default())
+        .map(|(text, format, _)| (text, format))
+}
+
+/// 포맷을 판별해 추출하고, 추출 결과와 판별한 포맷, 추출 경고를 함께 돌려준다.
+fn extract_text_with_format(
+    path: &Path,
+    opts: &ExtractOptions,
+) -> Result<(String, DocumentFormat, Vec<Warning>)> {
+    let format = detect_file_format(path)?;
+    let (text, warnings) = match format {
+        DocumentFormat::Hwpx => (
+            hwpx::extract_text_from_hwpx_with_options(path, opts)?,
+            Vec::new(),
+        ),
+        DocumentFormat::Hwp => extract_text_from_hwp(path, opts)?,
+        DocumentFormat::Hwpml => (
+            hwpx::extract_text_from_hwpml_with_options(path, opts)?,
+            Vec::new(),
+        ),
+        DocumentFormat::Unknown => return Err(HwpError::UnsupportedFormat),
+    };
+    Ok((
+        apply_placeholder(normalize(text, opts), opts),
+        format,
+        warnings,
+    ))
+}
+
+/// [`ExtractOptions::normalize_spaces`]이면 유니코드 공백 변형(Zs 분류)을 ASCII 공백으로 바꾼다.
+fn normalize(text: String, opts: &ExtractOptions) -> String {
+    if !opts.normalize_spaces || text.is_ascii() {
+        return text;
+    }
+    text.chars()
+        .map(|c| if is_space_variant(c) { ' ' } else { c })
         .collect()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::hwp::{docinfo, record, stream};
+/// U+0020 외의 공백 문자 (유니코드 Zs 분류)
+fn is_space_variant(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00A0}' | '\u{1680}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}'
+    )
+}
 
-    fn sample_path(name: &str) -> std::path::PathBuf {
-        Path::new(env!("CARGO_MANIFEST_DIR"))
-            .join("..")
-            .join("hwplib")
-            .join("sample_hwp")
-            .join(name)
+/// 추출 결과가 비어 있으면 [`ExtractOptions::placeholder_for_empty`]로 바꾼다.
+fn apply_placeholder(text: String, opts: &ExtractOptions) -> String {
+    match &opts.placeholder_for_empty {
+        Some(placeholder) if text.trim().is_empty() => placeholder.clone(),
+        _ => text,
     }
+}
 
-    fn open_hwp(name: &str) -> Option<(cfb::CompoundFile<File>, FileHeader)> {
-        let path = sample_path(name);
-        if !path.exists() {
-            eprintln!("Skipping: {:?} not found", path);
-            return None;
-        }
-        let file = File::open(&path).unwrap();
-        let mut comp = cfb::CompoundFile::open(file).unwrap();
-        let header = {
-            let mut s = comp.open_stream("/FileHeader").unwrap();
-            FileHeader::from_reader(&mut s).unwrap()
-        };
-        Some((comp, header))
+/// An open HWP (OLE) document with its FileHeader and DocInfo already parsed.
+///
+/// The free functions such as [`extract_text_from_file`] open the document
+/// anew on every call and only accept paths. A handle reads the headers once
+/// and can be built from a path, an in-memory buffer, or any `Read + Seek`
+/// source.
+///
+/// # Examples
+///
+/// ```no_run
+/// use hwarang::HwpDocument;
+///
+/// let bytes = std::fs::read("document.hwp")?;
+/// let mut doc = HwpDocument::from_bytes(&bytes)?;
+/// println!("{} sections", doc.doc_info().section_count);
+/// println!("{}", doc.extract_text()?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct HwpDocument<F = File> {
+    comp: cfb::CompoundFile<F>,
+    header: FileHeader,
+    doc_info: docinfo::DocInfo,
+}
+
+impl HwpDocument<File> {
+    /// Opens the HWP document at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`HwpDocument::from_reader`], plus I/O errors opening the file.
+    pub fn open(path: &Path) -> Result<Self> {
+        Self::from_compound(ole::open_compound(path)?)
     }
+}
 
-    #[test]
-    fn test_list_streams_blank_hwp() {
-        let path = sample_path("basic/blank.hwp");
-        if !path.exists() {
-            return;
-        }
-        let streams = list_streams(&path).unwrap();
-        assert!(!streams.is_empty());
-        let names: Vec<&str> = streams.iter().map(|s| s.as_str()).collect();
-        assert!(names.iter().any(|n| n.contains("FileHeader")));
-        assert!(names.iter().any(|n| n.contains("DocInfo")));
+impl<'a> HwpDocument<Cursor<&'a [u8]>> {
+    /// Opens an HWP document held in memory, without copying it.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`HwpDocument::from_reader`].
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_reader(Cursor::new(bytes))
     }
+}
 
-    #[test]
-    fn test_extract_text_blank_hwp() {
-        let path = sample_path("basic/blank.hwp");
-        if !path.exists() {
-            return;
-        }
-        // blank.hwp는 빈 문서이므로 빈 텍스트 또는 줄바꿈만
-        let text = extract_text_from_file(&path).unwrap();
-        assert!(text.trim().is_empty() || text.chars().all(|c| c.is_whitespace()));
+impl<F: Read + Seek> HwpDocument<F> {
+    /// Opens an HWP document from any seekable reader.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the data is not a valid (or is a truncated) OLE compound
+    /// file, or if its FileHeader or DocInfo stream is missing or malformed.
+    pub fn from_reader(reader: F) -> Result<Self> {
+        Self::from_compound(ole::open_compound_reader(reader)?)
     }
 
-    #[test]
-    fn test_extract_text_table_hwp() {
-        let path = sample_path("basic/표.hwp");
-        if !path.exists() {
-            return;
-        }
-        let text = extract_text_from_file(&path).unwrap();
-        // 표 안의 셀 텍스트도 추출되어야 함
-        assert!(!text.trim().is_empty(), "Table HWP should have text");
-        eprintln!("=== 표.hwp ===\n{}", text);
+    fn from_compound(comp: cfb::CompoundFile<F>) -> Result<Self> {
+        let (comp, header, doc_info) = open_hwp_compound(comp)?;
+        Ok(HwpDocument {
+            comp,
+            header,
+            doc_info,
+        })
     }
 
-    #[test]
-    fn test_extract_text_header_footer_hwp() {
-        let path = sample_path("basic/머리글꼬리글.hwp");
-        if !path.exists() {
-            return;
-        }
-        let text = extract_text_from_file(&path).unwrap();
-        assert!(!text.trim().is_empty());
-        eprintln!("=== 머리글꼬리글.hwp ===\n{}", text);
+    /// The parsed FileHeader (version, compression, distribution flags).
+    pub fn header(&self) -> &FileHeader {
+        &self.header
     }
 
-    #[test]
-    fn test_extract_text_footnote_hwp() {
-        let path = sample_path("basic/각주미주.hwp");
-        if !path.exists() {
-            return;
-        }
-        let text = extract_text_from_file(&path).unwrap();
-        assert!(!text.trim().is_empty());
-        eprintln!("=== 각주미주.hwp ===\n{}", text);
+    /// The parsed DocInfo (section count, paragraph shapes, styles).
+    pub fn doc_info(&self) -> &docinfo::DocInfo {
+        &self.doc_info
     }
 
-    #[test]
-    fn test_extract_text_hidden_comment_hwp() {
-        let path = sample_path("basic/숨은설명.hwp");
-        if !path.exists() {
-            return;
-        }
-        let text = extract_text_from_file(&path).unwrap();
-        assert!(!text.trim().is_empty());
-        eprintln!("=== 숨은설명.hwp ===\n{}", text);
+    /// Extracts the document text, as [`extract_text_from_file`] would.
+    ///
+    /// # Errors
+    ///
+    /// Fails if a section stream cannot be read, decompressed, or parsed.
+    pub fn extract_text(&mut self) -> Result<String> {
+        self.extract_text_with_options(&ExtractOptions::default())
     }
 
-    #[test]
-    fn test_extract_text_textbox_hwp() {
-        let path = sample_path("basic/글상자.hwp");
-        if !path.exists() {
-            return;
-        }
-        let text = extract_text_from_file(&path).unwrap();
-        assert!(!text.trim().is_empty());
-        eprintln!("=== 글상자.hwp ===\n{}", text);
+    /// Extracts the document text using custom options, as
+    /// [`extract_text_from_file_with_options`] would.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`HwpDocument::extract_text`].
+    pub fn extract_text_with_options(&mut self, opts: &ExtractOptions) -> Result<String> {
+        let (text, _) = extract_hwp_text(&mut self.comp, &self.header, &self.doc_info, opts)?;
+        Ok(apply_placeholder(normalize(text, opts), opts))
     }
+}
 
-    #[test]
-    fn test_extract_text_equation_hwp() {
-        let path = sample_path("basic/수식.hwp");
-        if !path.exists() {
-            return;
+/// Renders a section's record sequence as text using the crate's own
+/// extraction logic.
+///
+/// This is the counterpart of [`hwp::record::read_records`]: records parsed
+/// (and possibly inspected or filtered) by the caller are turned back into
+/// text exactly as [`extract_text_from_file_with_options`] would render them.
+///
+/// # Examples
+///
+/// ```
+/// use hwarang::hwp::record::{self, Record, RecordHeader};
+/// use hwarang::ExtractOptions;
+///
+/// let data: Vec<u8> = "안녕".encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+/// let records = vec![
+///     Record {
+///         header: RecordHeader { tag_id: record::HWPTAG_PARA_HEADER, level: 0, size: 0 },
+///         data: vec![],
+///     },
+///     Record {
+///         header: RecordHeader { tag_id: record::HWPTAG_PARA_TEXT, level: 1, size: 4 },
+///         data,
+///     },
+/// ];
+///
+/// let text = hwarang::records_to_text(&records, &ExtractOptions::default());
+/// assert_eq!(text, "안녕\n");
+/// ```
+pub fn records_to_text(records: &[record::Record], opts: &ExtractOptions) -> String {
+    let mut text = String::new();
+    text_extract::extract_section_text_with_options(records, opts, &mut text);
+    text
+}
+
+/// The container format of a document, as determined by [`detect_format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    /// HWP 5.x binary document (OLE compound file).
+    Hwp,
+    /// HWPX document (ZIP-based OWPML).
+    Hwpx,
+    /// HWPML document (plain XML).
+    Hwpml,
+    /// Not a recognised format.
+    Unknown,
+}
+
+impl DocumentFormat {
+    /// The conventional file extension for this format, without the dot.
+    /// `None` for [`DocumentFormat::Unknown`].
+    pub fn extension(self) -> Option<&'static str> {
+        match self {
+            DocumentFormat::Hwp => Some("hwp"),
+            DocumentFormat::Hwpx => Some("hwpx"),
+            DocumentFormat::Hwpml => Some("hwpml"),
+            DocumentFormat::Unknown => None,
         }
-        let text = extract_text_from_file(&path).unwrap();
-        eprintln!("=== 수식.hwp ===\n{}", text);
     }
 
-    #[test]
-    fn test_extract_text_distribution_hwp() {
-        let path = sample_path("distribution.hwp");
-        if !path.exists() {
-            return;
+    /// Whether `ext` (without the dot, case-insensitive) is an extension in
+    /// common use for this format. HWPML files are seen as both `.hwpml` and
+    /// `.hml`.
+    pub fn matches_extension(self, ext: &str) -> bool {
+        let ext = ext.to_ascii_lowercase();
+        match self {
+            DocumentFormat::Hwpml => ext == "hwpml" || ext == "hml",
+            _ => self.extension() == Some(ext.as_str()),
         }
-        let text = extract_text_from_file(&path).unwrap();
-        eprintln!("=== distribution.hwp ===\n{}", text);
-        // 배포문서도 텍스트 추출이 가능해야 함
     }
+}
 
-    #[test]
-    fn test_extract_text_compressed() {
-        // superboard 실제 compressed 파일
-        let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .join("..")
-            .join("superboard/data/raw/files/20160322124");
-        if !path.exists() {
-            return;
-        }
-        let hwp_path = std::fs::read_dir(&path)
-            .unwrap()
-            .filter_map(|e| e.ok())
-            .find(|e| e.path().extension().map_or(false, |ext| ext == "hwp"))
-            .map(|e| e.path());
+/// 형식 판별에 읽는 선두 바이트 수 (BOM·공백 뒤의 XML 선언까지 포함)
+const FORMAT_PROBE_LEN: usize = 1024;
+
+/// Detects the document format from the leading bytes of a file.
+///
+/// - `D0 CF 11 E0` — HWP (OLE compound document)
+/// - `50 4B 03 04` — HWPX (ZIP-based OWPML)
+/// - `<?xml` or `<HWPML` — HWPML (plain XML), optionally preceded by a UTF-8
+///   BOM and ASCII whitespace
+///
+/// # Examples
+///
+/// ```
+/// use hwarang::{detect_format, DocumentFormat};
+///
+/// assert_eq!(detect_format(b"\xEF\xBB\xBF\n<?xml version=\"1.0\"?>"), DocumentFormat::Hwpml);
+/// assert_eq!(detect_format(b"PK\x03\x04"), DocumentFormat::Hwpx);
+/// assert_eq!(detect_format(b"plain text"), DocumentFormat::Unknown);
+/// ```
+pub fn detect_format(bytes: &[u8]) -> DocumentFormat {
+    if bytes.starts_with(&[0xD0, 0xCF, 0x11, 0xE0]) {
+        return DocumentFormat::Hwp;
+    }
+    if bytes.starts_with(&[0x50, 0x4B, 0x03, 0x04]) {
+        return DocumentFormat::Hwpx;
+    }
+
+    let xml = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+    let start = xml
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(xml.len());
+    let xml = &xml[start..];
+    if xml.starts_with(b"<?xml") || xml.starts_with(b"<HWPML") {
+        DocumentFormat::Hwpml
+    } else {
+        DocumentFormat::Unknown
+    }
+}
+
+/// Detects the format of a file from its content, as [`detect_format`] does
+/// for a byte slice. Only the first kilobyte is read.
+///
+/// Extraction routes files the same way, so a mislabeled file (say, an HWP
+/// document named `.hwpx`) is still read correctly; compare the result with
+/// [`DocumentFormat::extension`] to catch such files.
+pub fn detect_file_format(path: &Path) -> Result<DocumentFormat> {
+    let file = open_file(path)?;
+    let mut probe = Vec::with_capacity(FORMAT_PROBE_LEN);
+    file.take(FORMAT_PROBE_LEN as u64).read_to_end(&mut probe)?;
+    Ok(detect_format(&probe))
+}
+
+/// HWP(OLE 컨테이너) 파일에서 텍스트를 추출한다.
+///
+/// 섹션별 병렬 처리: CFB 스트림 I/O 후 압축해제·파싱·텍스트 추출을
+/// rayon으로 병렬 수행한다.
+fn extract_text_from_hwp(path: &Path, opts: &ExtractOptions) -> Result<(String, Vec<Warning>)> {
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+    extract_hwp_text(&mut comp, &header, &doc_info, opts)
+}
+
+/// 열린 HWP 컴파운드 파일에서 텍스트를 추출하고, 섹션 순서대로 모은 추출 경고를 함께 돌려준다.
+fn extract_hwp_text<F: Read + Seek>(
+    comp: &mut cfb::CompoundFile<F>,
+    header: &FileHeader,
+    doc_info: &docinfo::DocInfo,
+    opts: &ExtractOptions,
+) -> Result<(String, Vec<Warning>)> {
+    // 그림 포함 시 BinData 스트림을 미리 읽어 둔다
+    let with_images = load_bin_data(comp, header, doc_info, opts);
+    let doc_info = with_images.as_ref().unwrap_or(doc_info);
+
+    // Phase 1: 모든 섹션의 raw 스트림 데이터를 순차 읽기 (CFB I/O)
+    let section_raw = read_raw_sections(comp, header, doc_info, opts.max_sections)?;
+    if opts.endnotes_at_end || opts.footnote_placement != FootnotePlacement::Inline {
+        return extract_hwp_text_with_notes(section_raw, header, doc_info, opts);
+    }
+
+    // Phase 2: 섹션별 병렬 처리 (압축해제 + 레코드 파싱 + 텍스트 추출)
+    // 압축 해제 버퍼와 레코드 벡터는 스레드별 스크래치를 재사용한다
+    let section_texts = par::map_sections(section_raw, |(i, raw)| {
+        par::with_scratch(|scratch| extract_section(i, raw, header, doc_info, opts, scratch))
+    });
+
+    // Phase 3: 섹션 순서대로 병합 (map_sections는 입력 순서를 유지한다)
+    let mut text = String::new();
+    let mut warnings = Vec::new();
+    for section in section_texts {
+        let (section_text, section_warnings) = section?;
+        text.push_str(&section_text);
+        warnings.extend(section_warnings);
+    }
+    Ok((text, warnings))
+}
+
+/// 각주·미주를 본문 밖으로 모으는 [`extract_hwp_text`]
+///
+/// 번호가 섹션을 넘어 이어지도록 모든 섹션을 먼저 레코드로 풀어 섹션별 각주·미주 수를
+/// 센 뒤, 각 섹션의 시작 번호를 정해 텍스트를 추출한다. 구역 끝 각주는 섹션 본문 바로
+/// 뒤에, 문서 끝 각주와 미주는 본문 전체 뒤에 붙인다.
+fn extract_hwp_text_with_notes(
+    section_raw: Vec<(u16, Vec<u8>)>,
+    header: &FileHeader,
+    doc_info: &docinfo::DocInfo,
+    opts: &ExtractOptions,
+) -> Result<(String, Vec<Warning>)> {
+    let sections = par::map_sections(section_raw, |(i, raw)| {
+        decode_section_with_warning(i, raw, header)
+    })
+    .into_iter()
+    .collect::<Result<Vec<_>>>()?;
+
+    let (mut first_footnote, mut first_endnote) = (1, 1);
+    let numbered: Vec<_> = sections
+        .into_iter()
+        .map(|(records, truncated)| {
+            let first = (first_footnote, first_endnote);
+            first_footnote += text_extract::count_footnotes(&records);
+            first_endnote += text_extract::count_endnotes(&records);
+            (records, truncated, first)
+        })
+        .collect();
+
+    let section_texts = par::map_sections(numbered, |(records, truncated, first)| {
+        let mut text = String::new();
+        let notes = text_extract::extract_section_text_with_notes(
+            &records, doc_info, opts, first.0, first.1, &mut text,
+        );
+        (text, truncated, first, notes)
+    });
+
+    let mut body = String::new();
+    let mut footnotes = String::new();
+    let mut endnotes = String::new();
+    let mut warnings = Vec::new();
+    for (text, truncated, (first_footnote, first_endnote), notes) in section_texts {
+        body.push_str(&text);
+        warnings.extend(truncated);
+        warnings.extend(notes.warnings);
+        let mut section_footnotes = String::new();
+        for (n, note) in (first_footnote..).zip(notes.footnotes) {
+            section_footnotes.push_str(&format!("[fn:{n}] {note}\n"));
+        }
+        for (n, note) in (first_endnote..).zip(notes.endnotes) {
+            endnotes.push_str(&format!("[en:{n}] {note}\n"));
+        }
+        if opts.footnote_placement == FootnotePlacement::SectionEnd {
+            append_notes(&mut body, &section_footnotes);
+        } else {
+            footnotes.push_str(&section_footnotes);
+        }
+    }
+    footnotes.push_str(&endnotes);
+    append_notes(&mut body, &footnotes);
+    Ok((body, warnings))
+}
+
+/// 본문 뒤에 빈 줄 하나를 두고 주석 줄을 붙인다.
+fn append_notes(body: &mut String, notes: &str) {
+    if notes.is_empty() {
+        return;
+    }
+    if !body.is_empty() && !body.ends_with("\n\n") {
+        body.push('\n');
+    }
+    body.push_str(notes);
+}
+
+/// 그림으로 넣을 BinData 항목 하나의 압축 해제 후 최대 크기 (64 MiB).
+///
+/// 작은 deflate 스트림이 수 GB로 풀리는 조작된 파일에서 메모리를 다 쓰지 않도록,
+/// 이보다 큰 그림은 내용 없이 둔다.
+const MAX_EMBEDDED_IMAGE_BYTES: usize = 64 * 1024 * 1024;
+
+/// `embed_images`가 켜져 있으면 DocInfo의 그림 BinData 항목마다 스트림 내용을 읽어 채운 사본을 돌려준다.
+/// 꺼져 있으면 아무것도 읽지 않고 `None`.
+///
+/// 확장자가 그림 형식이 아닌 항목(OLE 개체 등)은 읽지 않는다. 읽을 수 없거나 압축 해제에
+/// 실패한 항목, [`MAX_EMBEDDED_IMAGE_BYTES`]보다 크게 풀리는 항목은 내용 없이 둔다 (그림만 빠진다).
+fn load_bin_data<F: Read + Seek>(
+    comp: &mut cfb::CompoundFile<F>,
+    header: &FileHeader,
+    doc_info: &docinfo::DocInfo,
+    opts: &ExtractOptions,
+) -> Option<docinfo::DocInfo> {
+    if !opts.embed_images {
+        return None;
+    }
+    let mut doc_info = doc_info.clone();
+    for bin in &mut doc_info.bin_data {
+        if bin.mime_type().is_none() {
+            continue;
+        }
+        let compressed = match bin.compression {
+            BinCompression::Default => header.compressed,
+            BinCompression::Compressed => true,
+            BinCompression::Uncompressed => false,
+        };
+        bin.data = read_image_stream(comp, &bin.stream_path(), compressed);
+    }
+    Some(doc_info)
+}
+
+/// 그림 스트림 하나를 읽어 (필요하면) 압축 해제한다.
+///
+/// 압축 스트림은 풀기 전에 [`stream::decompressed_len`]으로 크기를 재어 상한을 넘으면 `None`.
+fn read_image_stream<F: Read + Seek>(
+    comp: &mut cfb::CompoundFile<F>,
+    path: &str,
+    compressed: bool,
+) -> Option<Vec<u8>> {
+    let mut s = comp.open_stream(path).ok()?;
+    let declared = s.len();
+    if !compressed && declared > MAX_EMBEDDED_IMAGE_BYTES as u64 {
+        return None;
+    }
+    let raw = stream::read_stream_data_limited(&mut s, path, declared).ok()?;
+    if !compressed {
+        return Some(raw);
+    }
+    if stream::decompressed_len(&raw).ok()? > MAX_EMBEDDED_IMAGE_BYTES {
+        return None;
+    }
+    stream::decompress(&raw).ok()
+}
+
+/// HWP 파일을 열어 FileHeader와 DocInfo를 파싱한다.
+fn open_hwp(path: &Path) -> Result<(cfb::CompoundFile<File>, FileHeader, docinfo::DocInfo)> {
+    open_hwp_compound(ole::open_compound(path)?)
+}
+
+/// FileHeader 스트림을 읽는다.
+fn read_file_header<F: Read + Seek>(comp: &mut cfb::CompoundFile<F>) -> Result<FileHeader> {
+    let mut stream = comp
+        .open_stream("/FileHeader")
+        .map_err(|_| HwpError::StreamNotFound("FileHeader".into()))?;
+    FileHeader::from_reader(&mut stream)
+}
+
+/// 열린 컴파운드 파일에서 FileHeader와 DocInfo를 파싱한다.
+fn open_hwp_compound<F: Read + Seek>(
+    mut comp: cfb::CompoundFile<F>,
+) -> Result<(cfb::CompoundFile<F>, FileHeader, docinfo::DocInfo)> {
+    let header = read_file_header(&mut comp)?;
+
+    // DocInfo 파싱 (section_count, 문단 모양, 스타일 등)
+    let doc_info = {
+        let mut s = comp
+            .open_stream("/DocInfo")
+            .map_err(|_| HwpError::StreamNotFound("DocInfo".into()))?;
+        let data = stream::read_and_decompress(&mut s, header.compressed)?;
+        let records = record::read_records(&data)?;
+        docinfo::parse_doc_info(&records)?
+    };
+
+    Ok((comp, header, doc_info))
+}
+
+/// BodyText(배포문서는 ViewText)의 섹션 스트림을 순서대로 읽는다.
+///
+/// 섹션 번호는 0부터 연속이므로 처음으로 없는 스트림에서 멈춘다 (손상된 section_count로
+/// 남은 번호를 모두 찾아보지 않도록). 있지만 비어 있는 스트림은 빈 섹션으로 포함한다.
+/// `max_sections`로 처리할 섹션 수를 제한한다.
+fn read_raw_sections<F: Read + Seek>(
+    comp: &mut cfb::CompoundFile<F>,
+    header: &FileHeader,
+    doc_info: &docinfo::DocInfo,
+    max_sections: Option<u16>,
+) -> Result<Vec<(u16, Vec<u8>)>> {
+    let storage = section_storage(comp, header);
+
+    let section_count = match max_sections {
+        Some(max) => doc_info.section_count.min(max),
+        None => doc_info.section_count,
+    };
+
+    let mut section_raw = Vec::new();
+    for i in 0..section_count {
+        let Some(raw) = read_raw_section(comp, &storage, i)? else {
+            break;
+        };
+        section_raw.push((i, raw));
+    }
+    Ok(section_raw)
+}
+
+/// 섹션 스트림이 든 스토리지 이름: BodyText(배포문서는 ViewText).
+///
+/// 이름의 대소문자가 다른 비표준 파일이면 실제 기록된 이름을, 스토리지가 없으면 표준
+/// 이름을 돌려준다.
+fn section_storage<F: Read + Seek>(comp: &cfb::CompoundFile<F>, header: &FileHeader) -> String {
+    let name = if header.distribution {
+        "ViewText"
+    } else {
+        "BodyText"
+    };
+    ole::find_root_storage(comp, name).unwrap_or_else(|| name.to_string())
+}
+
+/// 섹션 스트림 하나를 읽는다. 스트림이 없으면 `None`.
+///
+/// 스트림이 없는 경우만 `None`이고, 그 밖의 열기 실패(손상된 디렉터리 등)는 오류다.
+fn read_raw_section<F: Read + Seek>(
+    comp: &mut cfb::CompoundFile<F>,
+    storage: &str,
+    index: u16,
+) -> Result<Option<Vec<u8>>> {
+    let stream_name = format!("/{}/Section{}", storage, index);
+    let mut s = match comp.open_stream(&stream_name) {
+        Ok(s) => s,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let declared = s.len();
+    Ok(Some(stream::read_stream_data_limited(
+        &mut s,
+        &stream_name,
+        declared,
+    )?))
+}
+
+/// 섹션 스트림을 (복호화·)압축해제하고 레코드로 파싱한다.
+///
+/// 빈 스트림은 압축·암호화 여부와 관계없이 레코드가 없는 섹션이다. 압축 스트림이 끝 블록
+/// 전에 잘렸으면 실패하지 않고 온전한 레코드까지만 돌려준다 ([`decode_section_into`]).
+fn decode_section(index: u16, raw: Vec<u8>, header: &FileHeader) -> Result<Vec<record::Record>> {
+    decode_section_with_warning(index, raw, header).map(|(records, _)| records)
+}
+
+/// [`decode_section`]과 같되, 잘린 섹션이면 그 경고를 함께 돌려준다.
+fn decode_section_with_warning(
+    index: u16,
+    raw: Vec<u8>,
+    header: &FileHeader,
+) -> Result<(Vec<record::Record>, Option<Warning>)> {
+    let mut scratch = par::Scratch::default();
+    let warning = decode_section_into(index, raw, header, &mut scratch)?;
+    Ok((scratch.records, warning))
+}
+
+/// [`decode_section`]과 같되, 압축 해제와 레코드 파싱에 `scratch`의 버퍼를 재사용한다.
+/// 레코드는 `scratch.records`에 담긴다.
+///
+/// 압축 스트림이 잘렸으면 복원한 데이터에서 온전한 레코드만 남기고
+/// [`Warning::TruncatedSection`]을 반환한다. 배포문서 섹션은 잘림과 키 불일치를 구분할
+/// 수 없으므로 그대로 오류로 본다.
+fn decode_section_into(
+    index: u16,
+    raw: Vec<u8>,
+    header: &FileHeader,
+    scratch: &mut par::Scratch,
+) -> Result<Option<Warning>> {
+    if header.distribution && !raw.is_empty() {
+        scratch.records = decode_distribution_section(index, &raw, header.compressed)?;
+        return Ok(None);
+    }
+    let (data, truncated) = section_record_bytes(index, &raw, header, &mut scratch.inflated)?;
+    let parsed = record::read_records_into(data, &mut scratch.records);
+    // 잘린 스트림: 끝에 걸친 레코드는 버리고 그 앞까지 읽은 레코드를 쓴다
+    if truncated.is_none() {
+        parsed?;
+    }
+    Ok(truncated)
+}
+
+/// 배포문서가 아닌 섹션 스트림의 레코드 바이트를 돌려준다.
+///
+/// 압축 스트림은 `inflated`에 풀고 그 내용을, 아니면 `raw`를 그대로 돌려준다. 압축
+/// 스트림이 끝 블록 전에 잘렸으면 복원한 데이터와 [`Warning::TruncatedSection`]을 준다.
+fn section_record_bytes<'a>(
+    index: u16,
+    raw: &'a [u8],
+    header: &FileHeader,
+    inflated: &'a mut Vec<u8>,
+) -> Result<(&'a [u8], Option<Warning>)> {
+    if raw.is_empty() || !header.compressed {
+        return Ok((raw, None));
+    }
+    if !stream::decompress_partial_into(raw, inflated)? {
+        return Ok((inflated, None));
+    }
+    let warning = Warning::TruncatedSection {
+        section: index,
+        recovered: inflated.len(),
+    };
+    Ok((inflated, Some(warning)))
+}
+
+/// 섹션 하나를 디코딩하고 텍스트를 추출한다. 경고는 잘림 경고 다음에 추출 경고 순이다.
+///
+/// 레코드 바이트가 [`ExtractOptions::borrowed_records_threshold`] 이상이면 레코드 바디를
+/// 복사하지 않고 압축 해제 버퍼를 빌려 파싱한다 ([`record::read_records_borrowed_into`]).
+/// 배포문서 섹션은 복호화 단계에서 레코드를 만들므로 항상 소유 경로를 쓴다.
+fn extract_section(
+    index: u16,
+    raw: Vec<u8>,
+    header: &FileHeader,
+    doc_info: &docinfo::DocInfo,
+    opts: &ExtractOptions,
+    scratch: &mut par::Scratch,
+) -> Result<(String, Vec<Warning>)> {
+    let mut text = String::new();
+    if header.distribution {
+        let mut warnings: Vec<_> = decode_section_into(index, raw, header, scratch)?
+            .into_iter()
+            .collect();
+        warnings.extend(text_extract::extract_section_text_with_warnings(
+            &scratch.records,
+            doc_info,
+            opts,
+            &mut text,
+        ));
+        return Ok((text, warnings));
+    }
+
+    let (data, truncated) = section_record_bytes(index, &raw, header, &mut scratch.inflated)?;
+    let mut warnings: Vec<_> = truncated.into_iter().collect();
+    let section_warnings = if data.len() >= opts.borrowed_records_threshold {
+        let mut records = Vec::new();
+        let parsed = record::read_records_borrowed_into(data, &mut records);
+        if warnings.is_empty() {
+            parsed?;
+        }
+        text_extract::extract_section_text_with_warnings(&records, doc_info, opts, &mut text)
+    } else {
+        let parsed = record::read_records_into(data, &mut scratch.records);
+        if warnings.is_empty() {
+            parsed?;
+        }
+        text_extract::extract_section_text_with_warnings(
+            &scratch.records,
+            doc_info,
+            opts,
+            &mut text,
+        )
+    };
+    warnings.extend(section_warnings);
+    Ok((text, warnings))
+}
+
+/// 배포문서 섹션 스트림을 복호화하고 레코드로 파싱한다.
+///
+/// AES 키는 각 스트림의 256바이트 헤더에 들어 있어 섹션마다 독립적으로 복호화된다.
+/// 복호화 자체는 블록 정렬만 맞으면 항상 성공하므로, 이후 압축 해제·레코드 파싱이
+/// 실패하거나 첫 레코드가 최상위 PARA_HEADER가 아니면 키 불일치로 보고
+/// [`HwpError::InvalidDecryptedRecords`]를 반환한다.
+fn decode_distribution_section(
+    index: u16,
+    raw: &[u8],
+    compressed: bool,
+) -> Result<Vec<record::Record>> {
+    let decrypted = crypto::decrypt_distribution_stream(raw)?;
+    let invalid = |reason: String| {
+        HwpError::InvalidDecryptedRecords(format!("ViewText/Section{index}: {reason}"))
+    };
+
+    let data = if compressed {
+        stream::decompress(&decrypted).map_err(|e| invalid(e.to_string()))?
+    } else {
+        decrypted
+    };
+    let records = record::read_records(&data).map_err(|e| invalid(e.to_string()))?;
+
+    if let Some(first) = records.first() {
+        if first.header.tag_id != record::HWPTAG_PARA_HEADER || first.header.level != 0 {
+            return Err(invalid(format!(
+                "unexpected first record {} (level {})",
+                first.header.tag_name(),
+                first.header.level
+            )));
+        }
+    }
+    Ok(records)
+}
+
+/// Extracts the outline (heading tree) of an HWP or HWPX document.
+///
+/// A top-level paragraph is a heading when its paragraph shape carries an
+/// outline level or its style is a heading style such as `개요 1` /
+/// `Outline 1`. Entries are returned in document order, which makes them
+/// usable as a table of contents.
+///
+/// # Errors
+///
+/// Returns [`HwpError::UnsupportedFormat`] for HWPML documents, and the
+/// same errors as [`extract_text_from_file`] for malformed files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// for entry in hwarang::extract_outline(Path::new("report.hwp"))? {
+///     println!("{}{}", "  ".repeat(entry.level as usize - 1), entry.text);
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn extract_outline(path: &Path) -> Result<Vec<OutlineEntry>> {
+    match detect_file_format(path)? {
+        DocumentFormat::Hwp => {}
+        DocumentFormat::Hwpx => return hwpx::extract_outline_from_hwpx(path),
+        _ => return Err(HwpError::UnsupportedFormat),
+    }
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+    let section_raw = read_raw_sections(&mut comp, &header, &doc_info, None)?;
+
+    let mut outline = Vec::new();
+    for (i, raw) in section_raw {
+        let records = decode_section(i, raw, &header)?;
+        outline.extend(text_extract::section_outline(&records, &doc_info));
+    }
+    Ok(outline)
+}
+
+/// Returns the page layout of each section of an HWP document, in order.
+///
+/// Currently this is the number of columns the section starts with, which
+/// explains why text from multi-column (newspaper-style) pages may not read
+/// in visual order. Column changes in the middle of a section are not
+/// reported.
+///
+/// # Errors
+///
+/// Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML documents, and the
+/// same errors as [`extract_text_from_file`] for malformed HWP files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// for (i, layout) in hwarang::extract_section_layouts(Path::new("paper.hwp"))?
+///     .iter()
+///     .enumerate()
+/// {
+///     println!("section {i}: {} columns", layout.column_count);
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn extract_section_layouts(path: &Path) -> Result<Vec<SectionLayout>> {
+    if detect_file_format(path)? != DocumentFormat::Hwp {
+        return Err(HwpError::UnsupportedFormat);
+    }
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+    let section_raw = read_raw_sections(&mut comp, &header, &doc_info, None)?;
+    section_raw
+        .into_iter()
+        .map(|(i, raw)| {
+            Ok(text_extract::section_layout(&decode_section(
+                i, raw, &header,
+            )?))
+        })
+        .collect()
+}
+
+/// Returns the page number each section of an HWP document starts at, in
+/// order, as configured in the document.
+///
+/// The number comes from a page-number "new number" control in the
+/// section's first paragraph, or else from the section definition. `0`
+/// means the section continues the numbering of the previous one; its
+/// actual start page depends on page layout, which is not computed. The
+/// first section reports `1` in that case. Useful for citation tools that
+/// quote page numbers as printed.
+///
+/// # Errors
+///
+/// Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML documents, and the
+/// same errors as [`extract_text_from_file`] for malformed HWP files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let starts = hwarang::section_start_pages(Path::new("thesis.hwp"))?;
+/// if let Some(&first) = starts.first() {
+///     println!("numbering starts at page {first}");
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn section_start_pages(path: &Path) -> Result<Vec<u32>> {
+    if detect_file_format(path)? != DocumentFormat::Hwp {
+        return Err(HwpError::UnsupportedFormat);
+    }
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+    let section_raw = read_raw_sections(&mut comp, &header, &doc_info, None)?;
+    section_raw
+        .into_iter()
+        .map(|(i, raw)| {
+            let start = text_extract::section_start_page(&decode_section(i, raw, &header)?);
+            Ok(match (i, start) {
+                (0, 0) => 1,
+                _ => u32::from(start),
+            })
+        })
+        .collect()
+}
+
+/// Reports whether an HWP document has a watermark or page background
+/// object.
+///
+/// A watermark is a drawing object laid out behind the text, whether in the
+/// body or on a master page: typically a picture or a text art object. See
+/// [`watermark_text`] for the text of a text watermark.
+///
+/// # Errors
+///
+/// Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML documents, and the
+/// same errors as [`extract_text_from_file`] for malformed HWP files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// if hwarang::has_watermark(Path::new("draft.hwp"))? {
+///     println!("watermarked");
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn has_watermark(path: &Path) -> Result<bool> {
+    Ok(find_watermark(path)?.is_some())
+}
+
+/// Returns the text of an HWP document's watermark, such as `"대외비"` or
+/// `"DRAFT"`.
+///
+/// `None` when the document has no watermark (see [`has_watermark`]) or
+/// the watermark is a picture without text.
+///
+/// # Errors
+///
+/// Same as [`has_watermark`].
+pub fn watermark_text(path: &Path) -> Result<Option<String>> {
+    Ok(find_watermark(path)?.filter(|text| !text.is_empty()))
+}
+
+/// 앞 섹션부터 글 뒤로 배치된 첫 그리기 개체를 찾아 그 텍스트를 돌려준다.
+fn find_watermark(path: &Path) -> Result<Option<String>> {
+    if detect_file_format(path)? != DocumentFormat::Hwp {
+        return Err(HwpError::UnsupportedFormat);
+    }
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+    for (i, raw) in read_raw_sections(&mut comp, &header, &doc_info, None)? {
+        let records = decode_section(i, raw, &header)?;
+        if let Some(text) = text_extract::find_watermark(&records, &doc_info) {
+            return Ok(Some(text));
+        }
+    }
+    Ok(None)
+}
+
+/// Extracts the text of an HWP document together with the byte range of
+/// each paragraph in it.
+///
+/// The text is identical to [`extract_text_from_file`]. Every top-level
+/// paragraph yields a [`ParaKind::Body`] span (including its trailing
+/// newline); tables, footnotes and other controls anchored in it yield a
+/// nested span right after it. The innermost span containing an offset
+/// tells where a search hit came from.
+///
+/// # Errors
+///
+/// Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML documents, and the
+/// same errors as [`extract_text_from_file`] for malformed HWP files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use hwarang::ParaKind;
+///
+/// let (text, spans) = hwarang::extract_with_offsets(Path::new("document.hwp"))?;
+/// for span in spans.iter().filter(|s| s.kind == ParaKind::Table) {
+///     println!("{}", &text[span.start..span.end]);
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn extract_with_offsets(path: &Path) -> Result<(String, Vec<ParaSpan>)> {
+    if detect_file_format(path)? != DocumentFormat::Hwp {
+        return Err(HwpError::UnsupportedFormat);
+    }
+    let opts = ExtractOptions::default();
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+    let section_raw = read_raw_sections(&mut comp, &header, &doc_info, opts.max_sections)?;
+
+    let mut text = String::new();
+    let mut spans = Vec::new();
+    for (i, raw) in section_raw {
+        let records = decode_section(i, raw, &header)?;
+        text_extract::extract_section_text_with_spans(
+            &records, &doc_info, &opts, &mut text, &mut spans,
+        );
+    }
+    Ok((text, spans))
+}
+
+/// Character counts of a document's text by where it lives; see
+/// [`text_breakdown`].
+///
+/// Whitespace is not counted, so table layout and line breaks do not skew
+/// the numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextBreakdown {
+    /// Body paragraphs, including text boxes and other controls not listed
+    /// below.
+    pub body_chars: usize,
+    /// Table cells, including anything nested inside tables.
+    pub table_chars: usize,
+    /// Footnotes and endnotes.
+    pub footnote_chars: usize,
+    /// Headers and footers.
+    pub header_footer_chars: usize,
+    /// Equation scripts and their captions.
+    pub equation_chars: usize,
+}
+
+impl TextBreakdown {
+    /// 본문 문단 범위는 전부 본문에 더하고, 그 안에 중첩된 컨트롤 범위는 본문에서 빼서
+    /// 해당 분류로 옮긴다.
+    fn add_spans(&mut self, text: &str, spans: &[ParaSpan]) {
+        for span in spans {
+            let chars = text[span.start..span.end]
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .count();
+            let bucket = match span.kind {
+                ParaKind::Body => {
+                    self.body_chars += chars;
+                    continue;
+                }
+                ParaKind::Table => &mut self.table_chars,
+                ParaKind::Footnote | ParaKind::Endnote => &mut self.footnote_chars,
+                ParaKind::HeaderFooter => &mut self.header_footer_chars,
+                ParaKind::Equation => &mut self.equation_chars,
+                ParaKind::Other => continue,
+            };
+            *bucket += chars;
+            self.body_chars -= chars;
+        }
+    }
+}
+
+/// Counts how many characters of an HWP document's text are in the body,
+/// tables, footnotes, headers/footers and equations.
+///
+/// Meant for corpus profiling, e.g. finding documents that are mostly
+/// tables. Text is rendered as by [`extract_text_from_file`], with tables
+/// laid out tab-separated so markdown syntax is not counted.
+///
+/// # Errors
+///
+/// Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML documents, and the
+/// same errors as [`extract_text_from_file`] for malformed HWP files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let counts = hwarang::text_breakdown(Path::new("report.hwp"))?;
+/// if counts.table_chars > counts.body_chars {
+///     println!("mostly tables");
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn text_breakdown(path: &Path) -> Result<TextBreakdown> {
+    if detect_file_format(path)? != DocumentFormat::Hwp {
+        return Err(HwpError::UnsupportedFormat);
+    }
+    let opts = ExtractOptions {
+        table_mode: TableMode::TabSeparated,
+        ..Default::default()
+    };
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+    let section_raw = read_raw_sections(&mut comp, &header, &doc_info, opts.max_sections)?;
+
+    let mut breakdown = TextBreakdown::default();
+    for (i, raw) in section_raw {
+        let records = decode_section(i, raw, &header)?;
+        let mut text = String::new();
+        let mut spans = Vec::new();
+        text_extract::extract_section_text_with_spans(
+            &records, &doc_info, &opts, &mut text, &mut spans,
+        );
+        breakdown.add_spans(&text, &spans);
+    }
+    Ok(breakdown)
+}
+
+/// Extracts an HWP document with headers, footers and notes separated from
+/// the body text.
+///
+/// The body is rendered as by [`extract_text_from_file`], except that header,
+/// footer, footnote and endnote controls are left out of it and collected
+/// into the corresponding [`DocumentRegions`] lists instead, one entry per
+/// control in document order. Endnotes go to `footnotes`. Each header and
+/// footer is labelled in `header_pages`/`footer_pages` with the pages it
+/// applies to ([`HeaderFooterPages`]), so odd- and even-page variants can be
+/// told apart.
+///
+/// # Errors
+///
+/// Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML documents, and the
+/// same errors as [`extract_text_from_file`] for malformed HWP files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let regions = hwarang::extract_regions(Path::new("letter.hwp"))?;
+/// println!("{}", regions.body);
+/// for footer in &regions.footers {
+///     println!("footer: {footer}");
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn extract_regions(path: &Path) -> Result<DocumentRegions> {
+    if detect_file_format(path)? != DocumentFormat::Hwp {
+        return Err(HwpError::UnsupportedFormat);
+    }
+    let opts = ExtractOptions::default();
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+    let section_raw = read_raw_sections(&mut comp, &header, &doc_info, opts.max_sections)?;
+
+    let mut regions = DocumentRegions::default();
+    for (i, raw) in section_raw {
+        let records = decode_section(i, raw, &header)?;
+        text_extract::extract_section_regions(&records, &doc_info, &opts, &mut regions);
+    }
+    Ok(regions)
+}
+
+/// A document extracted section by section; see [`extract_document`].
+#[derive(Debug)]
+pub struct Document {
+    /// One entry per section stream found, in section order. A section that
+    /// fails to decompress, decrypt, or parse is reported as `Err` without
+    /// affecting the others.
+    pub sections: Vec<Result<Section>>,
+}
+
+/// The text of one body section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// Zero-based section number (`SectionN` stream index).
+    pub index: u16,
+    /// The section's extracted text, rendered with the given options.
+    pub text: String,
+    /// The section's top-level paragraphs in order, with their alignment.
+    /// Their texts concatenate to `text`, except for floating objects that
+    /// [`FloatingObjects::SectionEnd`] moves to the end of the section.
+    pub paragraphs: Vec<Paragraph>,
+}
+
+impl Document {
+    /// Concatenated text of all sections that extracted successfully.
+    pub fn text(&self) -> String {
+        self.sections
+            .iter()
+            .filter_map(|s| s.as_ref().ok())
+            .map(|s| s.text.as_str())
+            .collect()
+    }
+
+    /// Errors of the sections that failed, in section order.
+    pub fn errors(&self) -> impl Iterator<Item = &HwpError> {
+        self.sections.iter().filter_map(|s| s.as_ref().err())
+    }
+}
+
+/// Extracts an HWP document section by section, isolating failures.
+///
+/// Unlike [`extract_text_from_file_with_options`], which fails as a whole
+/// when any section is corrupt, this returns every section that could be
+/// read together with the errors of those that could not.
+///
+/// # Errors
+///
+/// Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML documents. Failures
+/// to open the file or to read its FileHeader/DocInfo are still returned as
+/// an error, since no section can be extracted without them.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use hwarang::ExtractOptions;
+///
+/// let doc = hwarang::extract_document(Path::new("document.hwp"), &ExtractOptions::default())?;
+/// for err in doc.errors() {
+///     eprintln!("warning: {err}");
+/// }
+/// println!("{}", doc.text());
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn extract_document(path: &Path, opts: &ExtractOptions) -> Result<Document> {
+    if detect_file_format(path)? != DocumentFormat::Hwp {
+        return Err(HwpError::UnsupportedFormat);
+    }
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+    let section_raw = read_raw_sections(&mut comp, &header, &doc_info, opts.max_sections)?;
+
+    let sections = par::map_sections(section_raw, |(index, raw)| {
+        let records = decode_section(index, raw, &header)?;
+        let mut text = String::new();
+        let mut paragraphs = Vec::new();
+        text_extract::extract_section_paragraphs(
+            &records,
+            &doc_info,
+            opts,
+            &mut text,
+            &mut paragraphs,
+        );
+        Ok(Section {
+            index,
+            text,
+            paragraphs,
+        })
+    });
+    Ok(Document { sections })
+}
+
+/// Extracts a short text preview of a document, at most `max_chars`
+/// characters long.
+///
+/// Intended for search-result snippets and file listings. The preview text
+/// Hancom stores alongside the document (`PrvText` in HWP,
+/// `Preview/PrvText.txt` in HWPX) is used when present, which avoids parsing
+/// the body at all. Otherwise HWP sections are extracted one at a time until
+/// enough text has been collected; HWPX and HWPML documents are extracted in
+/// full and truncated.
+///
+/// The stored preview may differ slightly from [`extract_text_from_file`]
+/// output, e.g. in how tables are rendered.
+///
+/// # Errors
+///
+/// Same as [`extract_text_from_file`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let snippet = hwarang::extract_preview(Path::new("document.hwp"), 200)?;
+/// println!("{snippet}…");
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn extract_preview(path: &Path, max_chars: usize) -> Result<String> {
+    let mut text = match detect_file_format(path)? {
+        DocumentFormat::Hwp => preview_from_hwp(path, max_chars)?,
+        DocumentFormat::Hwpx => match hwpx::read_preview_text(path)? {
+            Some(preview) => preview,
+            None => hwpx::extract_text_from_hwpx(path)?,
+        },
+        DocumentFormat::Hwpml => hwpx::extract_text_from_hwpml(path)?,
+        DocumentFormat::Unknown => return Err(HwpError::UnsupportedFormat),
+    };
+    if let Some((end, _)) = text.char_indices().nth(max_chars) {
+        text.truncate(end);
+    }
+    Ok(text)
+}
+
+/// HWP 미리보기: PrvText 스트림, 없으면 앞 섹션부터 `max_chars`를 채울 때까지 추출
+fn preview_from_hwp(path: &Path, max_chars: usize) -> Result<String> {
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+
+    // PrvText: 비압축 UTF-16LE
+    if let Ok(mut s) = comp.open_stream("/PrvText") {
+        let data = stream::read_stream_data(&mut s)?;
+        let units: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        let preview = String::from_utf16_lossy(&units);
+        let preview = preview.trim_end_matches('\0');
+        if !preview.trim().is_empty() {
+            return Ok(preview.to_string());
+        }
+    }
+
+    let storage = section_storage(&comp, &header);
+    let opts = ExtractOptions::default();
+    let section_count = doc_info
+        .section_count
+        .min(opts.max_sections.unwrap_or(u16::MAX));
+    let mut text = String::new();
+    let mut chars = 0;
+    for i in 0..section_count {
+        if chars >= max_chars {
+            break;
+        }
+        let Some(raw) = read_raw_section(&mut comp, &storage, i)? else {
+            break;
+        };
+        let records = decode_section(i, raw, &header)?;
+        let start = text.len();
+        text_extract::extract_section_text_with_doc_info(&records, &doc_info, &opts, &mut text);
+        chars += text[start..].chars().count();
+    }
+    Ok(text)
+}
+
+/// Extracts the text of a document's first `n` non-empty paragraphs.
+///
+/// Meant for showing a document's lead without parsing all of it: HWP
+/// sections are decoded one at a time and extraction stops as soon as `n`
+/// paragraphs with visible text have been emitted, so later sections are
+/// never read. Each paragraph includes the output of the controls anchored
+/// in it, such as tables. Whitespace-only paragraphs are not counted.
+///
+/// HWPX and HWPML documents are extracted in full and cut after the first
+/// `n` non-empty lines.
+///
+/// # Errors
+///
+/// Same as [`extract_text_from_file`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let lead = hwarang::extract_first_paragraphs(Path::new("document.hwp"), 3)?;
+/// println!("{lead}");
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn extract_first_paragraphs(path: &Path, n: usize) -> Result<String> {
+    let text = match detect_file_format(path)? {
+        DocumentFormat::Hwp => return first_paragraphs_from_hwp(path, n),
+        DocumentFormat::Hwpx => hwpx::extract_text_from_hwpx(path)?,
+        DocumentFormat::Hwpml => hwpx::extract_text_from_hwpml(path)?,
+        DocumentFormat::Unknown => return Err(HwpError::UnsupportedFormat),
+    };
+    let mut lead = String::new();
+    for line in text.lines().filter(|l| !l.trim().is_empty()).take(n) {
+        lead.push_str(line);
+        lead.push('\n');
+    }
+    Ok(lead)
+}
+
+/// HWP 앞 문단: 앞 섹션부터 비어 있지 않은 문단 `n`개를 채울 때까지 추출
+fn first_paragraphs_from_hwp(path: &Path, n: usize) -> Result<String> {
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+    let storage = section_storage(&comp, &header);
+    let opts = ExtractOptions::default();
+    let section_count = doc_info
+        .section_count
+        .min(opts.max_sections.unwrap_or(u16::MAX));
+    let mut text = String::new();
+    let mut remaining = n;
+    for i in 0..section_count {
+        if remaining == 0 {
+            break;
+        }
+        let Some(raw) = read_raw_section(&mut comp, &storage, i)? else {
+            break;
+        };
+        let records = decode_section(i, raw, &header)?;
+        remaining -= text_extract::extract_section_first_paragraphs(
+            &records, &doc_info, &opts, remaining, &mut text,
+        );
+    }
+    Ok(text)
+}
+
+/// Checks an HWP document for structural inconsistencies.
+///
+/// Currently compares the section count declared in DocInfo with the
+/// `SectionN` streams actually present in `BodyText` (`ViewText` for
+/// distribution documents); see [`ValidationIssue`].
+///
+/// # Errors
+///
+/// Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML documents. Files
+/// whose FileHeader or DocInfo cannot be read fail with the same errors as
+/// [`extract_text_from_file`] instead of producing a report.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let report = hwarang::validate(Path::new("document.hwp"))?;
+/// for issue in &report.issues {
+///     eprintln!("warning: {issue}");
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn validate(path: &Path) -> Result<ValidationReport> {
+    if detect_file_format(path)? != DocumentFormat::Hwp {
+        return Err(HwpError::UnsupportedFormat);
+    }
+    let (comp, header, doc_info) = open_hwp(path)?;
+    let storage = format!("/{}", section_storage(&comp, &header));
+
+    let mut report = ValidationReport::default();
+    let found = validation::count_section_streams(&comp, &storage);
+    report.issues.extend(validation::check_section_count(
+        doc_info.section_count,
+        found,
+    ));
+    Ok(report)
+}
+
+/// Reads the creation and modification times of an OLE compound file's
+/// root directory entry.
+///
+/// These come from the container itself rather than the `\x05HwpSummaryInformation`
+/// stream, so they survive editors that do not maintain the summary. Each
+/// time is `None` when the entry leaves its FILETIME unset (zero).
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or is not a valid OLE
+/// compound document.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let (created, modified) = hwarang::ole_timestamps(Path::new("document.hwp"))?;
+/// if let Some(modified) = modified {
+///     println!("last modified: {modified:?}");
+/// }
+/// # let _ = created;
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn ole_timestamps(
+    path: &Path,
+) -> Result<(Option<std::time::SystemTime>, Option<std::time::SystemTime>)> {
+    let comp = ole::open_compound(path)?;
+    Ok(ole::root_timestamps(&comp))
+}
+
+/// Lists all streams inside an OLE compound file.
+///
+/// Useful for inspecting the internal structure of an HWP file.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or is not a valid OLE
+/// compound document.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let streams = hwarang::list_streams(Path::new("document.hwp"))?;
+/// for s in &streams {
+///     println!("{s}");
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn list_streams(path: &Path) -> Result<Vec<String>> {
+    let comp = ole::open_compound(path)?;
+    Ok(comp
+        .walk()
+        .map(|e| e.path().to_string_lossy().into_owned())
+        .collect())
+}
+
+/// Low-level layout of an OLE compound file, as reported by [`ole_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OleInfo {
+    /// Compound file major version: 3 or 4.
+    pub version: u16,
+    /// Sector size in bytes: 512 for version 3, 4096 for version 4.
+    pub sector_size: usize,
+    /// Number of storages and streams reachable from the root, including
+    /// the root entry itself.
+    pub directory_entries: usize,
+    /// Streams smaller than this many bytes are stored in the mini stream.
+    pub mini_stream_cutoff: u32,
+}
+
+/// Reads the OLE container parameters of an HWP file for diagnostics.
+///
+/// Useful when debugging files written by unusual tools, for example a
+/// version 4 container with 4096-byte sectors where Hancom writes 512.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or is not a valid OLE
+/// compound document.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let info = hwarang::ole_info(Path::new("document.hwp"))?;
+/// println!("v{} with {}-byte sectors", info.version, info.sector_size);
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn ole_info(path: &Path) -> Result<OleInfo> {
+    let mut file = open_file(path)?;
+    let mini_stream_cutoff = ole::read_mini_stream_cutoff(&mut file)?;
+    let comp = ole::open_compound_reader(file)?;
+    Ok(OleInfo {
+        version: comp.version().number(),
+        sector_size: comp.version().sector_len(),
+        directory_entries: comp.walk().count(),
+        mini_stream_cutoff,
+    })
+}
+
+/// Reads the JScript macros embedded in an HWP document's `Scripts` storage.
+///
+/// Returns the script version together with the main, pre-document and
+/// post-document script sources, which lets security tooling inspect any
+/// automation a document carries. Use [`DocumentScripts::is_non_trivial`]
+/// to tell real macros apart from the empty skeleton Hancom writes by default.
+///
+/// Returns `Ok(None)` if the document has no `Scripts` storage.
+///
+/// # Errors
+///
+/// Returns an error if the file is not a valid HWP (OLE) document or the
+/// script streams are malformed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// if let Some(scripts) = hwarang::extract_scripts(Path::new("document.hwp"))? {
+///     if scripts.is_non_trivial() {
+///         println!("macro version {}", scripts.version);
+///     }
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn extract_scripts(path: &Path) -> Result<Option<DocumentScripts>> {
+    let mut comp = ole::open_compound(path)?;
+    let header = read_file_header(&mut comp)?;
+
+    if !comp.is_storage("/Scripts") {
+        return Ok(None);
+    }
+
+    let version = match comp.open_stream("/Scripts/JScriptVersion") {
+        Ok(mut s) => {
+            let data = stream::read_and_decompress(&mut s, header.compressed)?;
+            script::parse_script_version(&data)?
+        }
+        Err(_) => String::new(),
+    };
+
+    let (source, pre_script, post_script) = match comp.open_stream("/Scripts/DefaultJScript") {
+        Ok(mut s) => {
+            let data = stream::read_and_decompress(&mut s, header.compressed)?;
+            script::parse_default_script(&data)?
+        }
+        Err(_) => (None, None, None),
+    };
+
+    Ok(Some(DocumentScripts {
+        version,
+        source,
+        pre_script,
+        post_script,
+    }))
+}
+
+/// Reads the document-wide settings (`DocInfo`) of an HWP file.
+///
+/// Its [`Display`](std::fmt::Display) output is a one-line summary (section,
+/// font and style counts) suited to diagnostics.
+///
+/// # Errors
+///
+/// Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML documents, and the
+/// same errors as [`extract_text_from_file`] for malformed HWP files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let info = hwarang::read_doc_info(Path::new("document.hwp"))?;
+/// println!("{info}");
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn read_doc_info(path: &Path) -> Result<docinfo::DocInfo> {
+    if detect_file_format(path)? != DocumentFormat::Hwp {
+        return Err(HwpError::UnsupportedFormat);
+    }
+    let (_, _, doc_info) = open_hwp(path)?;
+    Ok(doc_info)
+}
+
+/// Returns the number of sections an HWP document declares.
+///
+/// Only the file header and the first DocInfo record are parsed, and no
+/// body text is read, so this is the cheapest way to gauge a document's
+/// size before deciding how to process it. Note that extraction still caps
+/// the sections it reads at [`ExtractOptions::max_sections`].
+///
+/// # Errors
+///
+/// Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML documents,
+/// [`HwpError::StreamNotFound`] if the `DocInfo` stream is missing, and an
+/// error if the file header or the first DocInfo record is malformed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let sections = hwarang::count_sections(Path::new("document.hwp"))?;
+/// println!("{sections} sections");
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn count_sections(path: &Path) -> Result<u16> {
+    if detect_file_format(path)? != DocumentFormat::Hwp {
+        return Err(HwpError::UnsupportedFormat);
+    }
+    let mut comp = ole::open_compound(path)?;
+    let header = read_file_header(&mut comp)?;
+    let mut s = comp
+        .open_stream("/DocInfo")
+        .map_err(|_| HwpError::StreamNotFound("DocInfo".into()))?;
+    let data = stream::read_and_decompress(&mut s, header.compressed)?;
+    docinfo::read_section_count(&data)
+}
+
+/// Returns the default language of an HWP document as a locale string such
+/// as `"ko-KR"` or `"en-US"`.
+///
+/// HWP has no document-level language field; the language of the default
+/// paragraph style (usually "바탕글") is used instead. Returns `Ok(None)` if
+/// the document has no styles or uses a language code without a known
+/// mapping. Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML
+/// documents.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// if let Some(lang) = hwarang::default_language(Path::new("document.hwp"))? {
+///     println!("language: {lang}");
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn default_language(path: &Path) -> Result<Option<String>> {
+    Ok(read_doc_info(path)?.default_language().map(String::from))
+}
+
+/// Returns a stable 64-bit hash of the text content of an HWP document, for
+/// finding duplicate documents in a corpus.
+///
+/// Only the decoded PARA_TEXT of the body sections is hashed, so documents
+/// with the same text but different formatting, metadata, compression or
+/// distribution encryption hash the same. The hash (64-bit FNV-1a) does not
+/// depend on the platform or crate version's internals and can be stored.
+/// Returns [`HwpError::UnsupportedFormat`] for HWPX/HWPML documents.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let a = hwarang::content_hash(Path::new("a.hwp"))?;
+/// let b = hwarang::content_hash(Path::new("b.hwp"))?;
+/// if a == b {
+///     println!("same text");
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn content_hash(path: &Path) -> Result<u64> {
+    if detect_file_format(path)? != DocumentFormat::Hwp {
+        return Err(HwpError::UnsupportedFormat);
+    }
+    let (mut comp, header, doc_info) = open_hwp(path)?;
+    let mut hash = FNV_OFFSET_BASIS;
+    for (i, raw) in read_raw_sections(&mut comp, &header, &doc_info, None)? {
+        for rec in decode_section(i, raw, &header)? {
+            if rec.header.tag_id == record::HWPTAG_PARA_TEXT {
+                let (text, _) = para_text::extract_text(&rec.data);
+                hash = fnv1a(hash, text.as_bytes());
+                // 문단 경계도 내용에 포함 ("ab"+"c"와 "a"+"bc"를 구분)
+                hash = fnv1a(hash, b"\n");
+            }
+        }
+    }
+    Ok(hash)
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64비트 FNV-1a 해시를 이어서 계산한다.
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &b in bytes {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Reads the document summary (title, author, ...) of an HWP file.
+///
+/// String properties are decoded using the code page the summary declares
+/// (typically CP949 or UTF-16), so Korean metadata is returned intact.
+/// Returns `Ok(None)` if the file has no `\x05HwpSummaryInformation` stream.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// if let Some(summary) = hwarang::extract_summary(Path::new("document.hwp"))? {
+///     println!("{}", summary.title.unwrap_or_default());
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn extract_summary(path: &Path) -> Result<Option<DocumentSummary>> {
+    let mut comp = ole::open_compound(path)?;
+    let Ok(mut stream) = comp.open_stream(summary::SUMMARY_STREAM) else {
+        return Ok(None);
+    };
+    let mut data = Vec::new();
+    stream.read_to_end(&mut data)?;
+    Ok(Some(summary::parse_summary(&data)?))
+}
+
+/// The image format of a document thumbnail, detected from its magic bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThumbnailFormat {
+    Bmp,
+    Png,
+    Gif,
+    /// None of the above; the bytes are returned as stored.
+    Unknown,
+}
+
+impl ThumbnailFormat {
+    /// Detects the image format from the leading bytes.
+    pub fn detect(data: &[u8]) -> Self {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            ThumbnailFormat::Png
+        } else if data.starts_with(b"GIF8") {
+            ThumbnailFormat::Gif
+        } else if data.starts_with(b"BM") {
+            ThumbnailFormat::Bmp
+        } else {
+            ThumbnailFormat::Unknown
+        }
+    }
+}
+
+/// A first-page thumbnail stored in a document; see [`extract_thumbnail`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Thumbnail {
+    pub format: ThumbnailFormat,
+    /// The raw image file bytes.
+    pub data: Vec<u8>,
+}
+
+/// Reads the first-page thumbnail Hancom stores alongside the document
+/// (`PrvImage` in HWP, `Preview/PrvImage.png` in HWPX), without rendering
+/// anything.
+///
+/// Returns `Ok(None)` if the document has no thumbnail or it is empty.
+/// Returns [`HwpError::UnsupportedFormat`] for HWPML documents, which have
+/// no thumbnail.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// if let Some(thumb) = hwarang::extract_thumbnail(Path::new("document.hwp"))? {
+///     let ext = match thumb.format {
+///         hwarang::ThumbnailFormat::Png => "png",
+///         hwarang::ThumbnailFormat::Gif => "gif",
+///         _ => "bmp",
+///     };
+///     std::fs::write(format!("thumb.{ext}"), &thumb.data)?;
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn extract_thumbnail(path: &Path) -> Result<Option<Thumbnail>> {
+    let data = match detect_file_format(path)? {
+        DocumentFormat::Hwp => {
+            let mut comp = ole::open_compound(path)?;
+            match comp.open_stream("/PrvImage") {
+                Ok(mut s) => Some(stream::read_stream_data(&mut s)?),
+                Err(_) => None,
+            }
+        }
+        DocumentFormat::Hwpx => hwpx::read_preview_image(path)?,
+        _ => return Err(HwpError::UnsupportedFormat),
+    };
+    Ok(data.filter(|d| !d.is_empty()).map(|data| Thumbnail {
+        format: ThumbnailFormat::detect(&data),
+        data,
+    }))
+}
+
+/// Statistics about the binary data (images, OLE objects, ...) embedded in
+/// a document; see [`embedded_data_summary`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BinSummary {
+    /// Number of embedded items whose data is present in the file.
+    pub count: usize,
+    /// Combined size of the items after decompression, in bytes.
+    pub total_decompressed_bytes: usize,
+    /// Distinct lowercase file extensions of the items (e.g. `"png"`), in
+    /// order of first appearance.
+    pub formats: Vec<String>,
+}
+
+impl BinSummary {
+    fn add(&mut self, extension: &str, size: usize) {
+        self.count += 1;
+        self.total_decompressed_bytes += size;
+        let extension = extension.to_ascii_lowercase();
+        if !extension.is_empty() && !self.formats.contains(&extension) {
+            self.formats.push(extension);
+        }
+    }
+}
+
+/// Summarizes a document's embedded binary data without returning it.
+///
+/// Useful for storage audits, e.g. flagging documents that carry huge
+/// attachments. For HWP the items come from DocInfo's `BIN_DATA` list and
+/// compressed streams are inflated only to measure them; linked (external)
+/// items and entries whose stream is missing are not counted. For HWPX the
+/// `BinData/` entries of the package are counted using the sizes recorded
+/// in the ZIP directory.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, an embedded stream fails to
+/// decompress, or the document is HWPML ([`HwpError::UnsupportedFormat`]).
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let summary = hwarang::embedded_data_summary(Path::new("document.hwp"))?;
+/// if summary.total_decompressed_bytes > 50 * 1024 * 1024 {
+///     println!("{} embedded items ({:?})", summary.count, summary.formats);
+/// }
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+pub fn embedded_data_summary(path: &Path) -> Result<BinSummary> {
+    let mut summary = BinSummary::default();
+    match detect_file_format(path)? {
+        DocumentFormat::Hwp => {
+            let (mut comp, header, doc_info) = open_hwp(path)?;
+            for bin in &doc_info.bin_data {
+                let Ok(mut s) = comp.open_stream(bin.stream_path()) else {
+                    continue;
+                };
+                let raw = stream::read_stream_data(&mut s)?;
+                let compressed = match bin.compression {
+                    BinCompression::Default => header.compressed,
+                    BinCompression::Compressed => true,
+                    BinCompression::Uncompressed => false,
+                };
+                let size = if compressed {
+                    stream::decompressed_len(&raw)?
+                } else {
+                    raw.len()
+                };
+                summary.add(&bin.extension, size);
+            }
+        }
+        DocumentFormat::Hwpx => {
+            for (name, size) in hwpx::bin_data_entries(path)? {
+                let extension = Path::new(&name)
+                    .extension()
+                    .map(|e| e.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                summary.add(&extension, size as usize);
+            }
+        }
+        _ => return Err(HwpError::UnsupportedFormat),
+    }
+    Ok(summary)
+}
+
+/// The outcome of extracting text from a single file in a batch operation.
+///
+/// Used by [`extract_text_batch`] to report per-file success or failure
+/// without aborting the entire batch.
+#[derive(Debug)]
+pub struct BatchResult {
+    /// The path of the processed file.
+    pub path: PathBuf,
+    /// Extracted text on success, or the error that occurred.
+    pub result: Result<String>,
+}
+
+/// How a file in a batch turned out; see [`BatchResult::outcome`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchOutcome {
+    /// Text was extracted.
+    Extracted,
+    /// The document was read successfully but contains no text (for
+    /// example a blank or image-only document).
+    Empty,
+    /// Extraction failed.
+    Failed,
+}
+
+impl BatchResult {
+    /// Classifies the result, telling documents without extractable text
+    /// apart from extraction failures.
+    pub fn outcome(&self) -> BatchOutcome {
+        match &self.result {
+            Ok(text) if text.trim().is_empty() => BatchOutcome::Empty,
+            Ok(_) => BatchOutcome::Extracted,
+            Err(_) => BatchOutcome::Failed,
+        }
+    }
+}
+
+/// Extracts text from multiple HWP/HWPX files in parallel.
+///
+/// Every file is processed concurrently using rayon's work-stealing
+/// scheduler. When there are fewer files than threads, sections within
+/// each file are also processed in parallel, so all available CPU cores
+/// are fully utilised even when the input contains only a handful of
+/// large documents. Larger batches process each file's sections on the
+/// thread that took the file, so the number of documents held in memory
+/// at once never exceeds the pool size.
+///
+/// The returned [`Vec<BatchResult>`] preserves the input order.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+///
+/// let paths = vec![
+///     PathBuf::from("a.hwp"),
+///     PathBuf::from("b.hwpx"),
+/// ];
+/// let results = hwarang::extract_text_batch(&paths);
+/// for br in &results {
+///     match &br.result {
+///         Ok(text) => println!("{}: {} chars", br.path.display(), text.len()),
+///         Err(e) => eprintln!("{}: {}", br.path.display(), e),
+///     }
+/// }
+/// ```
+pub fn extract_text_batch(paths: &[PathBuf]) -> Vec<BatchResult> {
+    // 파일이 풀을 채우면 섹션 병렬화는 파일을 더 많이 열게 할 뿐이다
+    let files_fill_pool = paths.len() >= par::current_num_threads();
+    paths
+        .par_iter()
+        .map(|path| {
+            let result = if files_fill_pool {
+                par::batch_item(|| extract_text_from_file(path))
+            } else {
+                extract_text_from_file(path)
+            };
+            BatchResult {
+                path: path.clone(),
+                result,
+            }
+        })
+        .collect()
+}
+
+/// Extracts text from multiple files in parallel while bounding peak memory.
+///
+/// Like [`extract_text_batch`], but files are processed in consecutive
+/// groups whose combined on-disk size stays within `max_concurrent_bytes`.
+/// Only one group is in flight at a time, so a batch containing several huge
+/// documents does not load them all at once. A single file larger than the
+/// budget is processed on its own.
+///
+/// The returned [`Vec<BatchResult>`] preserves the input order.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+///
+/// let paths = vec![PathBuf::from("a.hwp"), PathBuf::from("b.hwp")];
+/// // at most ~512 MiB of input in flight
+/// let results = hwarang::extract_text_batch_bounded(&paths, 512 << 20);
+/// assert_eq!(results.len(), paths.len());
+/// ```
+pub fn extract_text_batch_bounded(
+    paths: &[PathBuf],
+    max_concurrent_bytes: u64,
+) -> Vec<BatchResult> {
+    // 크기를 알 수 없는 파일은 0으로 두고, 오류는 추출 단계에서 보고한다.
+    let sizes: Vec<u64> = paths
+        .iter()
+        .map(|p| std::fs::metadata(p).map(|m| m.len()).unwrap_or(0))
+        .collect();
+
+    let mut results = Vec::with_capacity(paths.len());
+    for range in chunk_by_budget(&sizes, max_concurrent_bytes) {
+        results.extend(extract_text_batch(&paths[range]));
+    }
+    results
+}
+
+/// 연속된 파일들을 크기 합이 예산 이하가 되도록 묶는다.
+///
+/// 예산보다 큰 파일은 단독 그룹이 된다. 반환: 입력 인덱스 범위 목록
+fn chunk_by_budget(sizes: &[u64], max_bytes: u64) -> Vec<std::ops::Range<usize>> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut total = 0u64;
+    for (i, &size) in sizes.iter().enumerate() {
+        if i > start && total.saturating_add(size) > max_bytes {
+            chunks.push(start..i);
+            start = i;
+            total = 0;
+        }
+        total = total.saturating_add(size);
+    }
+    if start < sizes.len() {
+        chunks.push(start..sizes.len());
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hwp::{docinfo, record, stream};
+    use crate::test_util;
+
+    fn sample_path(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("hwplib")
+            .join("sample_hwp")
+            .join(name)
+    }
+
+    fn open_hwp(name: &str) -> Option<(cfb::CompoundFile<File>, FileHeader)> {
+        let path = sample_path(name);
+        if !path.exists() {
+            eprintln!("Skipping: {:?} not found", path);
+            return None;
+        }
+        let file = File::open(&path).unwrap();
+        let mut comp = cfb::CompoundFile::open(file).unwrap();
+        let header = {
+            let mut s = comp.open_stream("/FileHeader").unwrap();
+            FileHeader::from_reader(&mut s).unwrap()
+        };
+        Some((comp, header))
+    }
+
+    #[test]
+    fn test_list_streams_blank_hwp() {
+        let path = sample_path("basic/blank.hwp");
+        if !path.exists() {
+            return;
+        }
+        let streams = list_streams(&path).unwrap();
+        assert!(!streams.is_empty());
+        let names: Vec<&str> = streams.iter().map(|s| s.as_str()).collect();
+        assert!(names.iter().any(|n| n.contains("FileHeader")));
+        assert!(names.iter().any(|n| n.contains("DocInfo")));
+    }
+
+    #[test]
+    fn test_ole_info() {
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(1)),
+            ("/BodyText/Section0", vec![]),
+        ]);
+        let path = test_util::temp_file("ole_info.hwp", &bytes);
+        let info = ole_info(&path);
+        std::fs::remove_file(&path).ok();
+        let info = info.unwrap();
+        // 루트, FileHeader, DocInfo, BodyText, Section0
+        assert_eq!(info.directory_entries, 5);
+        assert_eq!(info.mini_stream_cutoff, 4096);
+        let expected_sector_size = if info.version == 3 { 512 } else { 4096 };
+        assert_eq!(info.sector_size, expected_sector_size);
+
+        let path = sample_path("basic/blank.hwp");
+        if !path.exists() {
+            return;
+        }
+        let info = ole_info(&path).unwrap();
+        assert!(info.sector_size == 512 || info.sector_size == 4096);
+        assert_eq!(info.directory_entries, list_streams(&path).unwrap().len());
+    }
+
+    #[test]
+    fn test_extract_text_blank_hwp() {
+        let path = sample_path("basic/blank.hwp");
+        if !path.exists() {
+            return;
+        }
+        // blank.hwp는 빈 문서이므로 빈 텍스트 또는 줄바꿈만
+        let text = extract_text_from_file(&path).unwrap();
+        assert!(text.trim().is_empty() || text.chars().all(|c| c.is_whitespace()));
+    }
+
+    #[test]
+    fn test_extract_text_table_hwp() {
+        let path = sample_path("basic/표.hwp");
+        if !path.exists() {
+            return;
+        }
+        let text = extract_text_from_file(&path).unwrap();
+        // 표 안의 셀 텍스트도 추출되어야 함
+        assert!(!text.trim().is_empty(), "Table HWP should have text");
+        eprintln!("=== 표.hwp ===\n{}", text);
+    }
+
+    #[test]
+    fn test_extract_text_header_footer_hwp() {
+        let path = sample_path("basic/머리글꼬리글.hwp");
+        if !path.exists() {
+            return;
+        }
+        let text = extract_text_from_file(&path).unwrap();
+        assert!(!text.trim().is_empty());
+        eprintln!("=== 머리글꼬리글.hwp ===\n{}", text);
+
+        let regions = extract_regions(&path).unwrap();
+        assert!(!regions.headers.is_empty() || !regions.footers.is_empty());
+    }
+
+    #[test]
+    fn test_extract_text_footnote_hwp() {
+        let path = sample_path("basic/각주미주.hwp");
+        if !path.exists() {
+            return;
+        }
+        let text = extract_text_from_file(&path).unwrap();
+        assert!(!text.trim().is_empty());
+        eprintln!("=== 각주미주.hwp ===\n{}", text);
+    }
+
+    #[test]
+    fn test_extract_text_hidden_comment_hwp() {
+        let path = sample_path("basic/숨은설명.hwp");
+        if !path.exists() {
+            return;
+        }
+        let text = extract_text_from_file(&path).unwrap();
+        assert!(!text.trim().is_empty());
+        eprintln!("=== 숨은설명.hwp ===\n{}", text);
+    }
+
+    #[test]
+    fn test_extract_text_textbox_hwp() {
+        let path = sample_path("basic/글상자.hwp");
+        if !path.exists() {
+            return;
+        }
+        let text = extract_text_from_file(&path).unwrap();
+        assert!(!text.trim().is_empty());
+        eprintln!("=== 글상자.hwp ===\n{}", text);
+    }
+
+    #[test]
+    fn test_extract_text_equation_hwp() {
+        let path = sample_path("basic/수식.hwp");
+        if !path.exists() {
+            return;
+        }
+        let text = extract_text_from_file(&path).unwrap();
+        eprintln!("=== 수식.hwp ===\n{}", text);
+    }
+
+    #[test]
+    fn test_extract_text_distribution_hwp() {
+        let path = sample_path("distribution.hwp");
+        if !path.exists() {
+            return;
+        }
+        let text = extract_text_from_file(&path).unwrap();
+        eprintln!("=== distribution.hwp ===\n{}", text);
+        // 배포문서도 텍스트 추출이 가능해야 함
+    }
+
+    #[test]
+    fn test_extract_text_compressed() {
+        // superboard 실제 compressed 파일
+        let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("superboard/data/raw/files/20160322124");
+        if !path.exists() {
+            return;
+        }
+        let hwp_path = std::fs::read_dir(&path)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .find(|e| e.path().extension().is_some_and(|ext| ext == "hwp"))
+            .map(|e| e.path());
 
         let Some(hwp_path) = hwp_path else { return };
         let text = extract_text_from_file(&hwp_path).unwrap();
@@ -369,123 +2284,1519 @@ mod tests {
     }
 
     #[test]
-    fn test_docinfo_section_count() {
-        let Some((mut comp, header)) = open_hwp("basic/blank.hwp") else {
-            return;
+    fn test_docinfo_section_count() {
+        let Some((mut comp, header)) = open_hwp("basic/blank.hwp") else {
+            return;
+        };
+        let mut s = comp.open_stream("/DocInfo").unwrap();
+        let data = stream::read_and_decompress(&mut s, header.compressed).unwrap();
+        let records = record::read_records(&data).unwrap();
+        let info = docinfo::parse_doc_info(&records).unwrap();
+        assert_eq!(info.section_count, 1);
+    }
+
+    #[test]
+    fn test_docinfo_records() {
+        let Some((mut comp, header)) = open_hwp("basic/blank.hwp") else {
+            return;
+        };
+        let mut s = comp.open_stream("/DocInfo").unwrap();
+        let data = stream::read_and_decompress(&mut s, header.compressed).unwrap();
+        let records = record::read_records(&data).unwrap();
+
+        assert!(!records.is_empty());
+        // 첫 번째 레코드는 DOCUMENT_PROPERTIES
+        assert_eq!(records[0].header.tag_id, record::HWPTAG_DOCUMENT_PROPERTIES);
+        assert_eq!(records[0].header.level, 0);
+    }
+
+    #[test]
+    fn test_section0_records() {
+        let Some((mut comp, header)) = open_hwp("basic/blank.hwp") else {
+            return;
+        };
+        let mut s = comp.open_stream("/BodyText/Section0").unwrap();
+        let data = stream::read_and_decompress(&mut s, header.compressed).unwrap();
+        let records = record::read_records(&data).unwrap();
+
+        assert!(!records.is_empty());
+        // Section은 PARA_HEADER로 시작
+        assert_eq!(records[0].header.tag_id, record::HWPTAG_PARA_HEADER);
+    }
+
+    #[test]
+    fn test_compressed_file_records() {
+        // superboard의 실제 compressed 파일 테스트
+        let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("superboard/data/raw/files/20160322124");
+        if !path.exists() {
+            return;
+        }
+        // 디렉토리 안의 첫 번째 .hwp 파일 찾기
+        let hwp_path = std::fs::read_dir(&path)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .find(|e| e.path().extension().is_some_and(|ext| ext == "hwp"))
+            .map(|e| e.path());
+
+        let Some(hwp_path) = hwp_path else { return };
+
+        let file = File::open(&hwp_path).unwrap();
+        let mut comp = cfb::CompoundFile::open(file).unwrap();
+        let header = {
+            let mut s = comp.open_stream("/FileHeader").unwrap();
+            FileHeader::from_reader(&mut s).unwrap()
+        };
+        assert!(header.compressed);
+
+        // DocInfo 레코드 파싱
+        let mut s = comp.open_stream("/DocInfo").unwrap();
+        let data = stream::read_and_decompress(&mut s, header.compressed).unwrap();
+        let records = record::read_records(&data).unwrap();
+        assert!(!records.is_empty());
+        assert_eq!(records[0].header.tag_id, record::HWPTAG_DOCUMENT_PROPERTIES);
+
+        // Section0 레코드 파싱
+        let mut s = comp.open_stream("/BodyText/Section0").unwrap();
+        let data = stream::read_and_decompress(&mut s, header.compressed).unwrap();
+        let records = record::read_records(&data).unwrap();
+        assert!(!records.is_empty());
+        assert_eq!(records[0].header.tag_id, record::HWPTAG_PARA_HEADER);
+    }
+
+    #[test]
+    fn test_unsupported_format() {
+        // 임시 파일에 잘못된 매직 바이트 기록
+        let dir = std::env::temp_dir();
+        let path = dir.join("test_unsupported.bin");
+        std::fs::write(&path, b"NOT_A_VALID_FORMAT").unwrap();
+        let result = extract_text_from_file(&path);
+        assert!(result.is_err());
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_nonexistent_file() {
+        let path = Path::new("/tmp/does_not_exist_hwp_test_12345.hwp");
+        let result = extract_text_from_file(path);
+        assert!(result.is_err());
+
+        // 일괄 처리 오류 메시지에 어느 파일인지 나온다
+        let results = extract_text_batch(&[path.to_path_buf()]);
+        let msg = results[0].result.as_ref().unwrap_err().to_string();
+        assert!(
+            msg.contains("does_not_exist_hwp_test_12345.hwp"),
+            "got: {msg}"
+        );
+    }
+
+    #[test]
+    fn test_file_too_short() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("test_too_short.hwp");
+        std::fs::write(&path, b"AB").unwrap(); // 4바이트 미만
+        let result = extract_text_from_file(&path);
+        assert!(matches!(result, Err(HwpError::UnsupportedFormat)));
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_empty_file() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("test_empty.hwp");
+        std::fs::write(&path, b"").unwrap();
+        let result = extract_text_from_file(&path);
+        assert!(matches!(result, Err(HwpError::UnsupportedFormat)));
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_extract_scripts_synthetic() {
+        let mut version = 1u32.to_le_bytes().to_vec();
+        version.extend_from_slice(&0u32.to_le_bytes());
+
+        let mut script = test_util::script_string("var Document;");
+        script.extend(test_util::script_string("function OnDocument_New() {}"));
+        script.extend(test_util::script_string("Init();"));
+        script.extend(test_util::script_string(""));
+        script.extend_from_slice(&(-1i32).to_le_bytes());
+
+        // 압축 플래그 → Scripts 스트림도 raw deflate
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(1)),
+            ("/Scripts/JScriptVersion", test_util::deflate(&version)),
+            ("/Scripts/DefaultJScript", test_util::deflate(&script)),
+        ]);
+        let path = test_util::temp_file("scripts.hwp", &bytes);
+
+        let scripts = extract_scripts(&path).unwrap().expect("Scripts storage");
+        assert_eq!(scripts.version, "1.0");
+        assert_eq!(
+            scripts.source.as_deref(),
+            Some("function OnDocument_New() {}")
+        );
+        assert_eq!(scripts.pre_script.as_deref(), Some("Init();"));
+        assert_eq!(scripts.post_script, None);
+        assert!(scripts.is_non_trivial());
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_extract_scripts_absent() {
+        let bytes = test_util::build_compound(&[("/FileHeader", test_util::file_header_bytes(0))]);
+        let path = test_util::temp_file("no_scripts.hwp", &bytes);
+        assert!(extract_scripts(&path).unwrap().is_none());
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_read_doc_info_missing_stream() {
+        let bytes = test_util::build_compound(&[("/FileHeader", test_util::file_header_bytes(0))]);
+        let path = test_util::temp_file("no_doc_info.hwp", &bytes);
+        let doc_info = read_doc_info(&path);
+        std::fs::remove_file(&path).ok();
+        assert!(doc_info.is_err());
+    }
+
+    #[test]
+    fn test_extract_summary_absent() {
+        let bytes = test_util::build_compound(&[("/FileHeader", test_util::file_header_bytes(0))]);
+        let path = test_util::temp_file("no_summary.hwp", &bytes);
+        let summary = extract_summary(&path);
+        std::fs::remove_file(&path).ok();
+        assert!(summary.unwrap().is_none());
+    }
+
+    /// 섹션마다 다른 키로 암호화된 배포문서 (압축 + 배포 플래그)
+    fn distribution_doc(sections: &[(&str, [u8; 16], [u8; 16])]) -> Vec<u8> {
+        let mut streams = vec![
+            (
+                "/FileHeader".to_string(),
+                test_util::file_header_bytes(0b101),
+            ),
+            (
+                "/DocInfo".to_string(),
+                test_util::deflate(&test_util::doc_info_bytes(sections.len() as u16)),
+            ),
+        ];
+        for (i, (body, key, cipher_key)) in sections.iter().enumerate() {
+            let section = test_util::records_bytes(&[
+                test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![0u8; 22]),
+                test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le(body)),
+            ]);
+            let seed = 0x1234_5670 + i as u32 * 3;
+            streams.push((
+                format!("/ViewText/Section{i}"),
+                test_util::distribution_stream(
+                    &test_util::deflate(&section),
+                    seed,
+                    *key,
+                    *cipher_key,
+                ),
+            ));
+        }
+        let refs: Vec<(&str, Vec<u8>)> = streams
+            .iter()
+            .map(|(n, d)| (n.as_str(), d.clone()))
+            .collect();
+        test_util::build_compound(&refs)
+    }
+
+    #[test]
+    fn test_distribution_multi_section_per_stream_keys() {
+        let bytes = distribution_doc(&[
+            ("첫째 구역", [1u8; 16], [1u8; 16]),
+            ("둘째 구역", [7u8; 16], [7u8; 16]),
+        ]);
+        let path = test_util::temp_file("dist_multi.hwp", &bytes);
+        let text = extract_text_from_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let first = text.find("첫째 구역").expect("section 0 text");
+        let second = text.find("둘째 구역").expect("section 1 text");
+        assert!(first < second);
+    }
+
+    #[test]
+    fn test_batch_many_multi_section_files() {
+        let key = [5u8; 16];
+        let paths: Vec<PathBuf> = (0..48)
+            .map(|f| {
+                let bodies: Vec<String> = (0..4).map(|s| format!("파일{f} 구역{s}")).collect();
+                let sections: Vec<(&str, [u8; 16], [u8; 16])> =
+                    bodies.iter().map(|b| (b.as_str(), key, key)).collect();
+                test_util::temp_file(
+                    &format!("batch_stress_{f}.hwp"),
+                    &distribution_doc(&sections),
+                )
+            })
+            .collect();
+
+        let results = extract_text_batch(&paths);
+        for path in &paths {
+            std::fs::remove_file(path).ok();
+        }
+
+        assert_eq!(results.len(), paths.len());
+        for (f, br) in results.iter().enumerate() {
+            assert_eq!(br.path, paths[f]);
+            let text = br.result.as_ref().unwrap();
+            let positions: Vec<usize> = (0..4)
+                .map(|s| {
+                    text.find(&format!("파일{f} 구역{s}"))
+                        .expect("section text")
+                })
+                .collect();
+            assert!(positions.windows(2).all(|w| w[0] < w[1]), "got: {text:?}");
+        }
+    }
+
+    #[test]
+    fn test_count_sections_matches_extraction() {
+        let bytes = distribution_doc(&[
+            ("첫째 구역", [1u8; 16], [1u8; 16]),
+            ("둘째 구역", [7u8; 16], [7u8; 16]),
+        ]);
+        let path = test_util::temp_file("count_sections.hwp", &bytes);
+        let count = count_sections(&path).unwrap();
+        let document = extract_document(&path, &ExtractOptions::default()).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(count, 2);
+        assert_eq!(document.sections.len(), count as usize);
+    }
+
+    #[test]
+    fn test_embed_images_reads_bin_data_stream() {
+        use test_util::{rec, records_bytes, utf16le};
+
+        let mut bin_data = 1u16.to_le_bytes().to_vec(); // EMBEDDING, 스토리지 압축 설정
+        bin_data.extend_from_slice(&1u16.to_le_bytes());
+        bin_data.extend_from_slice(&3u16.to_le_bytes());
+        bin_data.extend(utf16le("png"));
+        let mut doc_info = test_util::doc_info_bytes(1);
+        doc_info.extend(records_bytes(&[rec(record::HWPTAG_BIN_DATA, 0, bin_data)]));
+
+        let mut para_text = utf16le("그림");
+        para_text.extend_from_slice(&[0x0B, 0x00]);
+        para_text.extend_from_slice(&[0u8; 14]);
+        let mut picture = vec![0u8; 80];
+        picture[71..73].copy_from_slice(&1u16.to_le_bytes());
+        let section = records_bytes(&[
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, para_text),
+            rec(
+                record::HWPTAG_CTRL_HEADER,
+                1,
+                crate::hwp::control::CTRL_GSO.to_le_bytes().to_vec(),
+            ),
+            rec(record::HWPTAG_SHAPE_COMPONENT_PICTURE, 2, picture),
+        ]);
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", doc_info),
+            ("/BodyText/Section0", section),
+            ("/BinData/BIN0001.png", b"foo".to_vec()),
+        ]);
+        let path = test_util::temp_file("embed_images.hwp", &bytes);
+        let opts = ExtractOptions {
+            embed_images: true,
+            ..Default::default()
+        };
+        let embedded = extract_text_from_file_with_options(&path, &opts);
+        let plain = extract_text_from_file(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert!(embedded
+            .unwrap()
+            .contains("<img src=\"data:image/png;base64,Zm9v\">"));
+        assert!(!plain.unwrap().contains("<img"));
+    }
+
+    #[test]
+    fn test_load_bin_data_only_reads_bounded_images() {
+        use crate::hwp::bindata::BinData;
+
+        let bin = |id: u16, extension: &str| BinData {
+            id,
+            extension: extension.to_string(),
+            compression: BinCompression::Default,
+            data: None,
+        };
+        let bomb = test_util::deflate(&vec![0u8; MAX_EMBEDDED_IMAGE_BYTES + 1]);
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(1)),
+            (
+                "/DocInfo",
+                test_util::deflate(&test_util::doc_info_bytes(1)),
+            ),
+            ("/BinData/BIN0001.png", test_util::deflate(b"foo")),
+            ("/BinData/BIN0002.ole", test_util::deflate(b"bar")),
+            ("/BinData/BIN0003.png", bomb),
+        ]);
+        let (mut comp, header, mut doc_info) =
+            open_hwp_compound(ole::open_compound_reader(Cursor::new(bytes)).unwrap()).unwrap();
+        doc_info.bin_data = vec![bin(1, "png"), bin(2, "ole"), bin(3, "png")];
+
+        assert!(load_bin_data(&mut comp, &header, &doc_info, &ExtractOptions::default()).is_none());
+
+        let opts = ExtractOptions {
+            embed_images: true,
+            ..Default::default()
+        };
+        let loaded = load_bin_data(&mut comp, &header, &doc_info, &opts).unwrap();
+        let data: Vec<_> = loaded.bin_data.iter().map(|b| b.data.as_deref()).collect();
+        assert_eq!(data, [Some(&b"foo"[..]), None, None]);
+    }
+
+    #[test]
+    fn test_embedded_data_summary() {
+        use test_util::{rec, records_bytes, utf16le};
+
+        let bin_data = |attr: u16, id: u16, ext: &str| {
+            let mut data = attr.to_le_bytes().to_vec();
+            data.extend_from_slice(&id.to_le_bytes());
+            data.extend_from_slice(&(ext.len() as u16).to_le_bytes());
+            data.extend(utf16le(ext));
+            rec(record::HWPTAG_BIN_DATA, 0, data)
+        };
+        // 1: 스토리지 설정(압축), 2: 비압축 지정, 3: 스트림 없음
+        let mut doc_info = test_util::doc_info_bytes(1);
+        doc_info.extend(records_bytes(&[
+            bin_data(1, 1, "PNG"),
+            bin_data(1 | 2 << 4, 2, "jpg"),
+            bin_data(1, 3, "png"),
+        ]));
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(1)),
+            ("/DocInfo", test_util::deflate(&doc_info)),
+            ("/BinData/BIN0001.PNG", test_util::deflate(&[7; 5000])),
+            ("/BinData/BIN0002.jpg", vec![1; 300]),
+        ]);
+        let path = test_util::temp_file("bin_summary.hwp", &bytes);
+        let summary = embedded_data_summary(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(
+            summary.unwrap(),
+            BinSummary {
+                count: 2,
+                total_decompressed_bytes: 5300,
+                formats: vec!["png".to_string(), "jpg".to_string()],
+            }
+        );
+
+        let image = "x".repeat(1000);
+        let bytes = test_util::build_zip(&[
+            ("Contents/section0.xml", "<hs:sec/>"),
+            ("BinData/image1.png", &image),
+            ("BinData/image2.bmp", "bm"),
+        ]);
+        let path = test_util::temp_file("bin_summary.hwpx", &bytes);
+        let summary = embedded_data_summary(&path);
+        std::fs::remove_file(&path).ok();
+        let summary = summary.unwrap();
+        assert_eq!(summary.count, 2);
+        assert_eq!(summary.total_decompressed_bytes, 1002);
+        assert_eq!(summary.formats, ["png", "bmp"]);
+    }
+
+    #[test]
+    fn test_content_hash_ignores_compression_and_metadata() {
+        use test_util::{rec, records_bytes, utf16le};
+
+        let paragraphs = |texts: &[&str]| {
+            let records: Vec<_> = texts
+                .iter()
+                .flat_map(|t| {
+                    [
+                        rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                        rec(record::HWPTAG_PARA_TEXT, 1, utf16le(t)),
+                    ]
+                })
+                .collect();
+            records_bytes(&records)
+        };
+        let plain = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(1)),
+            ("/BodyText/Section0", paragraphs(&["가나", "다라"])),
+        ]);
+        let mut styled_info = test_util::doc_info_bytes(1);
+        styled_info.extend(records_bytes(&[rec(
+            record::HWPTAG_STYLE,
+            0,
+            test_util::style_bytes("바탕글", "Normal", 0),
+        )]));
+        let compressed = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(1)),
+            ("/DocInfo", test_util::deflate(&styled_info)),
+            (
+                "/BodyText/Section0",
+                test_util::deflate(&paragraphs(&["가나", "다라"])),
+            ),
+        ]);
+        let regrouped = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(1)),
+            ("/BodyText/Section0", paragraphs(&["가", "나다라"])),
+        ]);
+
+        let hash = |name: &str, bytes: &[u8]| {
+            let path = test_util::temp_file(name, bytes);
+            let hash = content_hash(&path);
+            std::fs::remove_file(&path).ok();
+            hash.unwrap()
+        };
+        let a = hash("content_hash_plain.hwp", &plain);
+        assert_eq!(a, hash("content_hash_compressed.hwp", &compressed));
+        assert_ne!(a, hash("content_hash_regrouped.hwp", &regrouped));
+    }
+
+    #[test]
+    fn test_endnotes_at_end() {
+        use crate::hwp::control;
+        use test_util::{rec, records_bytes, utf16le};
+
+        // "본문{미주}" 문단 하나와 미주 내용을 담은 섹션
+        let section = |body: &str, note: &str| {
+            let mut pt = utf16le(body);
+            pt.extend_from_slice(&[17, 0x00]);
+            pt.extend_from_slice(&[0u8; 14]);
+            records_bytes(&[
+                rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                rec(record::HWPTAG_PARA_TEXT, 1, pt),
+                rec(
+                    record::HWPTAG_CTRL_HEADER,
+                    1,
+                    control::CTRL_ENDNOTE.to_le_bytes().to_vec(),
+                ),
+                rec(record::HWPTAG_LIST_HEADER, 2, vec![0u8; 8]),
+                rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+                rec(record::HWPTAG_PARA_TEXT, 3, utf16le(note)),
+            ])
         };
-        let mut s = comp.open_stream("/DocInfo").unwrap();
-        let data = stream::read_and_decompress(&mut s, header.compressed).unwrap();
-        let records = record::read_records(&data).unwrap();
-        let info = docinfo::parse_doc_info(&records).unwrap();
-        assert_eq!(info.section_count, 1);
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(2)),
+            ("/BodyText/Section0", section("첫째", "출처 하나")),
+            ("/BodyText/Section1", section("둘째", "출처 둘")),
+        ]);
+        let path = test_util::temp_file("endnotes_at_end.hwp", &bytes);
+        let opts = ExtractOptions {
+            endnotes_at_end: true,
+            ..Default::default()
+        };
+        let moved = extract_text_from_file_with_options(&path, &opts);
+        let inline = extract_text_from_file(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(
+            moved.unwrap(),
+            "첫째[en:1]\n둘째[en:2]\n\n[en:1] 출처 하나\n[en:2] 출처 둘\n"
+        );
+        let inline = inline.unwrap();
+        assert!(inline.contains("출처 하나") && !inline.contains("[en:"));
     }
 
     #[test]
-    fn test_docinfo_records() {
-        let Some((mut comp, header)) = open_hwp("basic/blank.hwp") else {
-            return;
+    fn test_footnote_placement() {
+        use crate::hwp::control;
+        use test_util::{rec, records_bytes, utf16le};
+
+        // "본문{주석}" 문단 하나와 주석 내용을 담은 섹션
+        let section = |body: &str, ctrl_id: u32, note: &str| {
+            let mut pt = utf16le(body);
+            pt.extend_from_slice(&[17, 0x00]);
+            pt.extend_from_slice(&[0u8; 14]);
+            records_bytes(&[
+                rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                rec(record::HWPTAG_PARA_TEXT, 1, pt),
+                rec(
+                    record::HWPTAG_CTRL_HEADER,
+                    1,
+                    ctrl_id.to_le_bytes().to_vec(),
+                ),
+                rec(record::HWPTAG_LIST_HEADER, 2, vec![0u8; 8]),
+                rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+                rec(record::HWPTAG_PARA_TEXT, 3, utf16le(note)),
+            ])
         };
-        let mut s = comp.open_stream("/DocInfo").unwrap();
-        let data = stream::read_and_decompress(&mut s, header.compressed).unwrap();
-        let records = record::read_records(&data).unwrap();
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(3)),
+            (
+                "/BodyText/Section0",
+                section("첫째", control::CTRL_FOOTNOTE, "각주 하나"),
+            ),
+            (
+                "/BodyText/Section1",
+                section("둘째", control::CTRL_ENDNOTE, "미주 하나"),
+            ),
+            (
+                "/BodyText/Section2",
+                section("셋째", control::CTRL_FOOTNOTE, "각주 둘"),
+            ),
+        ]);
+        let path = test_util::temp_file("footnote_placement.hwp", &bytes);
+        let extract = |footnote_placement, endnotes_at_end| {
+            let opts = ExtractOptions {
+                footnote_placement,
+                endnotes_at_end,
+                ..Default::default()
+            };
+            extract_text_from_file_with_options(&path, &opts).unwrap()
+        };
+        let inline = extract(FootnotePlacement::Inline, false);
+        let section_end = extract(FootnotePlacement::SectionEnd, false);
+        let document_end = extract(FootnotePlacement::DocumentEnd, false);
+        let with_endnotes = extract(FootnotePlacement::DocumentEnd, true);
+        std::fs::remove_file(&path).ok();
 
-        assert!(!records.is_empty());
-        // 첫 번째 레코드는 DOCUMENT_PROPERTIES
-        assert_eq!(records[0].header.tag_id, record::HWPTAG_DOCUMENT_PROPERTIES);
-        assert_eq!(records[0].header.level, 0);
+        assert!(inline.contains("각주 하나") && !inline.contains("[fn:"));
+        assert_eq!(
+            section_end,
+            "첫째[fn:1]\n\n[fn:1] 각주 하나\n둘째미주 하나\n\n셋째[fn:2]\n\n[fn:2] 각주 둘\n"
+        );
+        assert_eq!(
+            document_end,
+            "첫째[fn:1]\n둘째미주 하나\n\n셋째[fn:2]\n\n[fn:1] 각주 하나\n[fn:2] 각주 둘\n"
+        );
+        assert_eq!(
+            with_endnotes,
+            "첫째[fn:1]\n둘째[en:1]\n셋째[fn:2]\n\n[fn:1] 각주 하나\n[fn:2] 각주 둘\n[en:1] 미주 하나\n"
+        );
     }
 
     #[test]
-    fn test_section0_records() {
-        let Some((mut comp, header)) = open_hwp("basic/blank.hwp") else {
-            return;
+    fn test_extract_thumbnail() {
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        let bmp = b"BM\x36\0\0\0".to_vec();
+        let with_png = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/PrvImage", png.clone()),
+        ]);
+        let with_bmp = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/PrvImage", bmp.clone()),
+        ]);
+        let without =
+            test_util::build_compound(&[("/FileHeader", test_util::file_header_bytes(0))]);
+
+        let thumbnail = |name: &str, bytes: &[u8]| {
+            let path = test_util::temp_file(name, bytes);
+            let thumb = extract_thumbnail(&path);
+            std::fs::remove_file(&path).ok();
+            thumb.unwrap()
         };
-        let mut s = comp.open_stream("/BodyText/Section0").unwrap();
-        let data = stream::read_and_decompress(&mut s, header.compressed).unwrap();
-        let records = record::read_records(&data).unwrap();
+        assert_eq!(
+            thumbnail("thumb_png.hwp", &with_png),
+            Some(Thumbnail {
+                format: ThumbnailFormat::Png,
+                data: png,
+            })
+        );
+        let bmp_thumb = thumbnail("thumb_bmp.hwp", &with_bmp).unwrap();
+        assert_eq!(bmp_thumb.format, ThumbnailFormat::Bmp);
+        assert_eq!(bmp_thumb.data, bmp);
+        assert_eq!(thumbnail("thumb_none.hwp", &without), None);
+        assert_eq!(ThumbnailFormat::detect(b"GIF89a"), ThumbnailFormat::Gif);
+        assert_eq!(ThumbnailFormat::detect(b"??"), ThumbnailFormat::Unknown);
+    }
 
-        assert!(!records.is_empty());
-        // Section은 PARA_HEADER로 시작
-        assert_eq!(records[0].header.tag_id, record::HWPTAG_PARA_HEADER);
+    #[test]
+    fn test_default_language() {
+        let mut doc_info = test_util::doc_info_bytes(1);
+        doc_info.extend(test_util::records_bytes(&[test_util::rec(
+            record::HWPTAG_STYLE,
+            0,
+            test_util::style_bytes("바탕글", "Normal", 0),
+        )]));
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", doc_info),
+            ("/BodyText/Section0", Vec::new()),
+        ]);
+        let path = test_util::temp_file("default_language.hwp", &bytes);
+        let lang = default_language(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(lang.unwrap().as_deref(), Some("ko-KR"));
     }
 
     #[test]
-    fn test_compressed_file_records() {
-        // superboard의 실제 compressed 파일 테스트
-        let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .join("..")
-            .join("superboard/data/raw/files/20160322124");
-        if !path.exists() {
-            return;
+    fn test_distribution_key_mismatch_reported_distinctly() {
+        // 두 번째 섹션: 헤더에 기록된 키와 실제 암호화 키가 다름
+        let bytes = distribution_doc(&[
+            ("정상", [1u8; 16], [1u8; 16]),
+            ("손상", [2u8; 16], [9u8; 16]),
+        ]);
+        let path = test_util::temp_file("dist_mismatch.hwp", &bytes);
+        let result = extract_text_from_file(&path);
+        std::fs::remove_file(&path).ok();
+
+        match result {
+            Err(HwpError::InvalidDecryptedRecords(msg)) => {
+                assert!(msg.contains("Section1"), "got: {msg}");
+            }
+            other => panic!("expected InvalidDecryptedRecords, got {other:?}"),
         }
-        // 디렉토리 안의 첫 번째 .hwp 파일 찾기
-        let hwp_path = std::fs::read_dir(&path)
-            .unwrap()
-            .filter_map(|e| e.ok())
-            .find(|e| e.path().extension().map_or(false, |ext| ext == "hwp"))
-            .map(|e| e.path());
+    }
 
-        let Some(hwp_path) = hwp_path else { return };
+    #[test]
+    fn test_detect_format_magic() {
+        assert_eq!(
+            detect_format(&[0xD0, 0xCF, 0x11, 0xE0, 0]),
+            DocumentFormat::Hwp
+        );
+        assert_eq!(detect_format(b"PK\x03\x04rest"), DocumentFormat::Hwpx);
+        assert_eq!(
+            detect_format(b"<?xml version=\"1.0\"?>"),
+            DocumentFormat::Hwpml
+        );
+        assert_eq!(
+            detect_format(b"<HWPML Version=\"2.8\">"),
+            DocumentFormat::Hwpml
+        );
+        assert_eq!(detect_format(b""), DocumentFormat::Unknown);
+        assert_eq!(detect_format(b"\xEF\xBB\xBF"), DocumentFormat::Unknown);
+        assert_eq!(detect_format(b"  <html>"), DocumentFormat::Unknown);
+    }
 
-        let file = File::open(&hwp_path).unwrap();
-        let mut comp = cfb::CompoundFile::open(file).unwrap();
-        let header = {
-            let mut s = comp.open_stream("/FileHeader").unwrap();
-            FileHeader::from_reader(&mut s).unwrap()
-        };
-        assert!(header.compressed);
+    const HWPML_BODY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<HWPML><BODY><SECTION><P><TEXT><CHAR>본문</CHAR></TEXT></P></SECTION></BODY></HWPML>"#;
 
-        // DocInfo 레코드 파싱
-        let mut s = comp.open_stream("/DocInfo").unwrap();
-        let data = stream::read_and_decompress(&mut s, header.compressed).unwrap();
-        let records = record::read_records(&data).unwrap();
-        assert!(!records.is_empty());
-        assert_eq!(records[0].header.tag_id, record::HWPTAG_DOCUMENT_PROPERTIES);
+    #[test]
+    fn test_extract_hwpml_with_bom() {
+        let mut data = vec![0xEF, 0xBB, 0xBF];
+        data.extend_from_slice(HWPML_BODY.as_bytes());
+        assert_eq!(detect_format(&data), DocumentFormat::Hwpml);
 
-        // Section0 레코드 파싱
-        let mut s = comp.open_stream("/BodyText/Section0").unwrap();
-        let data = stream::read_and_decompress(&mut s, header.compressed).unwrap();
-        let records = record::read_records(&data).unwrap();
-        assert!(!records.is_empty());
-        assert_eq!(records[0].header.tag_id, record::HWPTAG_PARA_HEADER);
+        let path = test_util::temp_file("bom.hwpml", &data);
+        let text = extract_text_from_file(&path);
+        std::fs::remove_file(&path).ok();
+        assert!(text.unwrap().contains("본문"));
     }
 
     #[test]
-    fn test_unsupported_format() {
-        // 임시 파일에 잘못된 매직 바이트 기록
-        let dir = std::env::temp_dir();
-        let path = dir.join("test_unsupported.bin");
-        std::fs::write(&path, b"NOT_A_VALID_FORMAT").unwrap();
-        let result = extract_text_from_file(&path);
-        assert!(result.is_err());
+    fn test_extract_hwpml_with_leading_whitespace() {
+        let data = format!("\r\n \t{HWPML_BODY}");
+        assert_eq!(detect_format(data.as_bytes()), DocumentFormat::Hwpml);
+
+        let path = test_util::temp_file("ws.hwpml", data.as_bytes());
+        let text = extract_text_from_file(&path);
         std::fs::remove_file(&path).ok();
+        assert!(text.unwrap().contains("본문"));
     }
 
     #[test]
-    fn test_nonexistent_file() {
-        let path = Path::new("/tmp/does_not_exist_hwp_test_12345.hwp");
-        let result = extract_text_from_file(path);
-        assert!(result.is_err());
+    fn test_extract_hml_without_declaration() {
+        let data = HWPML_BODY.split_once('\n').unwrap().1;
+        assert!(data.starts_with("<HWPML"));
+        assert_eq!(detect_format(data.as_bytes()), DocumentFormat::Hwpml);
+
+        let path = test_util::temp_file("no_decl.hml", data.as_bytes());
+        let format = detect_file_format(&path);
+        let text = extract_text_from_file(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(format.unwrap(), DocumentFormat::Hwpml);
+        assert!(DocumentFormat::Hwpml.matches_extension("HML"));
+        assert!(text.unwrap().contains("본문"));
     }
 
     #[test]
-    fn test_file_too_short() {
-        let dir = std::env::temp_dir();
-        let path = dir.join("test_too_short.hwp");
-        std::fs::write(&path, b"AB").unwrap(); // 4바이트 미만
+    fn test_extract_text_and_format() {
+        let hwp = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(1)),
+            (
+                "/BodyText/Section0",
+                test_util::records_bytes(&[
+                    test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                    test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le("한글")),
+                ]),
+            ),
+        ]);
+        let hwpx = test_util::build_zip(&[
+            ("mimetype", "application/hwp+zip"),
+            (
+                "Contents/section0.xml",
+                r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>한글</hp:t></hp:run></hp:p></hs:sec>"#,
+            ),
+        ]);
+        for (name, bytes, expected) in [
+            ("format.hwp", hwp, DocumentFormat::Hwp),
+            ("format.hwpx", hwpx, DocumentFormat::Hwpx),
+            (
+                "format.hml",
+                HWPML_BODY.as_bytes().to_vec(),
+                DocumentFormat::Hwpml,
+            ),
+        ] {
+            let path = test_util::temp_file(name, &bytes);
+            let result = extract_text_and_format(&path);
+            std::fs::remove_file(&path).ok();
+            let (text, format) = result.unwrap();
+            assert_eq!(format, expected, "{name}");
+            assert!(!text.trim().is_empty(), "{name}: {text:?}");
+        }
+    }
+
+    #[test]
+    fn test_extract_truncated_hwp() {
+        let mut bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(1)),
+            ("/BodyText/Section0", vec![0u8; 20_000]),
+        ]);
+        bytes.truncate(bytes.len() / 2);
+        let path = test_util::temp_file("truncated.hwp", &bytes);
         let result = extract_text_from_file(&path);
-        assert!(matches!(result, Err(HwpError::UnsupportedFormat)));
         std::fs::remove_file(&path).ok();
+
+        match result {
+            Err(HwpError::Parse(msg)) => assert!(msg.contains("truncated"), "got: {msg}"),
+            other => panic!("expected truncation error, got {other:?}"),
+        }
     }
 
     #[test]
-    fn test_empty_file() {
-        let dir = std::env::temp_dir();
-        let path = dir.join("test_empty.hwp");
-        std::fs::write(&path, b"").unwrap();
-        let result = extract_text_from_file(&path);
-        assert!(matches!(result, Err(HwpError::UnsupportedFormat)));
+    fn test_scratch_reuse_keeps_output() {
+        let compressed_doc = |bodies: &[&str]| {
+            let records: Vec<_> = bodies
+                .iter()
+                .flat_map(|body| {
+                    [
+                        test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                        test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le(body)),
+                    ]
+                })
+                .collect();
+            test_util::build_compound(&[
+                ("/FileHeader", test_util::file_header_bytes(1)),
+                (
+                    "/DocInfo",
+                    test_util::deflate(&test_util::doc_info_bytes(1)),
+                ),
+                (
+                    "/BodyText/Section0",
+                    test_util::deflate(&test_util::records_bytes(&records)),
+                ),
+            ])
+        };
+        let long_line = "긴 문단 ".repeat(500);
+        let large = compressed_doc(&[long_line.as_str(); 20]);
+        let small = compressed_doc(&["짧은 문서"]);
+        let extract = |bytes: &[u8]| HwpDocument::from_bytes(bytes).unwrap().extract_text();
+
+        // 일괄 처리 안에서는 섹션이 같은 스레드에서 돌아 스크래치 버퍼를 이어 쓴다
+        let (first, second, third) =
+            par::batch_item(|| (extract(&large), extract(&small), extract(&large)));
+        let expected = format!("{long_line}\n").repeat(20);
+        assert_eq!(first.unwrap(), expected);
+        assert_eq!(second.unwrap(), "짧은 문서\n");
+        assert_eq!(third.unwrap(), expected);
+    }
+
+    #[test]
+    fn test_max_sections_limits_processing() {
+        let section = |body: &str| {
+            test_util::records_bytes(&[
+                test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le(body)),
+            ])
+        };
+        // 손상된 section_count: 65535
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(u16::MAX)),
+            ("/BodyText/Section0", section("zero")),
+            ("/BodyText/Section1", section("one")),
+            ("/BodyText/Section2", section("two")),
+        ]);
+        let path = test_util::temp_file("max_sections.hwp", &bytes);
+
+        let opts = ExtractOptions {
+            max_sections: Some(2),
+            ..Default::default()
+        };
+        let limited = extract_text_from_file_with_options(&path, &opts).unwrap();
+        let full = extract_text_from_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(limited, "zero\none\n");
+        assert_eq!(full, "zero\none\ntwo\n");
+    }
+
+    #[test]
+    fn test_empty_section_stream_is_kept() {
+        let section = test_util::records_bytes(&[
+            test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le("둘째")),
+        ]);
+        // 압축 문서: 빈 스트림은 압축 해제하지 않고 빈 섹션으로 본다
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(1)),
+            (
+                "/DocInfo",
+                test_util::deflate(&test_util::doc_info_bytes(2)),
+            ),
+            ("/BodyText/Section0", vec![]),
+            ("/BodyText/Section1", test_util::deflate(&section)),
+        ]);
+        let path = test_util::temp_file("empty_section.hwp", &bytes);
+        let text = extract_text_from_file(&path);
+        let doc = extract_document(&path, &ExtractOptions::default());
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(text.unwrap(), "둘째\n");
+        let doc = doc.unwrap();
+        assert_eq!(doc.sections.len(), 2);
+        assert_eq!(doc.sections[0].as_ref().unwrap().text, "");
+    }
+
+    #[test]
+    fn test_missing_section_stream_stops_probing() {
+        let section = |body: &str| {
+            test_util::records_bytes(&[
+                test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le(body)),
+            ])
+        };
+        // Section1이 없으면 그 뒤의 Section2는 읽지 않는다
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(3)),
+            ("/BodyText/Section0", section("zero")),
+            ("/BodyText/Section2", section("two")),
+        ]);
+        let path = test_util::temp_file("missing_section.hwp", &bytes);
+        let text = extract_text_from_file(&path);
+        let doc = extract_document(&path, &ExtractOptions::default());
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(text.unwrap(), "zero\n");
+        assert_eq!(doc.unwrap().sections.len(), 1);
+    }
+
+    #[test]
+    fn test_extract_outline_two_levels() {
+        use test_util::{para_header_bytes, rec, utf16le};
+        let doc_info = test_util::records_bytes(&[
+            rec(record::HWPTAG_DOCUMENT_PROPERTIES, 0, vec![1, 0]),
+            rec(record::HWPTAG_PARA_SHAPE, 1, 0u32.to_le_bytes().to_vec()),
+            rec(
+                record::HWPTAG_PARA_SHAPE,
+                1,
+                test_util::outline_attr1(2).to_le_bytes().to_vec(),
+            ),
+            rec(
+                record::HWPTAG_STYLE,
+                1,
+                test_util::style_bytes("바탕글", "Normal", 0),
+            ),
+            rec(
+                record::HWPTAG_STYLE,
+                1,
+                test_util::style_bytes("개요 1", "Outline 1", 0),
+            ),
+        ]);
+        let para = |shape: u16, style: u8, body: &str| {
+            vec![
+                rec(
+                    record::HWPTAG_PARA_HEADER,
+                    0,
+                    para_header_bytes(shape, style),
+                ),
+                rec(record::HWPTAG_PARA_TEXT, 1, utf16le(body)),
+            ]
+        };
+        let section: Vec<_> = [
+            para(0, 1, "1. 서론"),
+            para(0, 0, "본문"),
+            para(1, 0, "1.1 배경"),
+            para(0, 1, "2. 결론"),
+        ]
+        .concat();
+
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", doc_info),
+            ("/BodyText/Section0", test_util::records_bytes(&section)),
+        ]);
+        let path = test_util::temp_file("outline.hwp", &bytes);
+        let outline = extract_outline(&path);
+        std::fs::remove_file(&path).ok();
+
+        let entry = |level, text: &str| OutlineEntry {
+            level,
+            text: text.into(),
+        };
+        assert_eq!(
+            outline.unwrap(),
+            vec![
+                entry(1, "1. 서론"),
+                entry(2, "1.1 배경"),
+                entry(1, "2. 결론"),
+            ]
+        );
+    }
+
+    /// 글자처럼 취급하는 그림 하나만 있는 문서
+    fn image_only_hwp() -> Vec<u8> {
+        let mut pt = vec![0x0B, 0x00]; // 그리기 개체
+        pt.extend_from_slice(&[0u8; 14]);
+        pt.extend_from_slice(&[0x0D, 0x00]); // 문단 끝
+        let mut gso = crate::hwp::control::CTRL_GSO.to_le_bytes().to_vec();
+        gso.extend_from_slice(&1u32.to_le_bytes());
+
+        let section = test_util::records_bytes(&[
+            test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            test_util::rec(record::HWPTAG_PARA_TEXT, 1, pt),
+            test_util::rec(record::HWPTAG_CTRL_HEADER, 1, gso),
+            test_util::rec(record::HWPTAG_SHAPE_COMPONENT, 2, vec![0u8; 8]),
+            test_util::rec(record::HWPTAG_SHAPE_COMPONENT_PICTURE, 3, vec![0u8; 8]),
+        ]);
+        test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(1)),
+            ("/BodyText/Section0", section),
+        ])
+    }
+
+    #[test]
+    fn test_image_only_document_is_empty_not_failed() {
+        let path = test_util::temp_file("image_only.hwp", &image_only_hwp());
+        let missing = path.with_extension("missing");
+        let results = extract_text_batch(&[path.clone(), missing]);
+
+        let opts = ExtractOptions {
+            placeholder_for_empty: Some("[no text]".into()),
+            ..Default::default()
+        };
+        let placeholder = extract_text_from_file_with_options(&path, &opts);
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(results[0].outcome(), BatchOutcome::Empty);
+        assert_eq!(results[1].outcome(), BatchOutcome::Failed);
+        assert_eq!(placeholder.unwrap(), "[no text]");
+    }
+
+    #[test]
+    fn test_normalize_spaces() {
+        let section = test_util::records_bytes(&[
+            test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            test_util::rec(
+                record::HWPTAG_PARA_TEXT,
+                1,
+                test_util::utf16le("제\u{00A0}1조\u{3000}목적\u{2009}및 범위"),
+            ),
+        ]);
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(1)),
+            ("/BodyText/Section0", section),
+        ]);
+        let path = test_util::temp_file("nbsp.hwp", &bytes);
+        let opts = ExtractOptions {
+            normalize_spaces: true,
+            ..Default::default()
+        };
+        let normalized = extract_text_from_file_with_options(&path, &opts);
+        let raw = extract_text_from_file(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(normalized.unwrap(), "제 1조 목적 및 범위\n");
+        assert_eq!(raw.unwrap(), "제\u{00A0}1조\u{3000}목적\u{2009}및 범위\n");
+    }
+
+    #[test]
+    fn test_borrowed_records_match_owned() {
+        use test_util::{rec, records_bytes, utf16le};
+
+        let mut para_text = utf16le("본문");
+        para_text.extend_from_slice(&[0x11, 0x00]); // 각주
+        para_text.extend_from_slice(&[0u8; 14]);
+        para_text.extend(utf16le(" 뒤"));
+        let filler: String = (0..5_000u32)
+            .map(|i| char::from_u32(0xAC00 + (i * 7919) % 11_172).unwrap())
+            .collect();
+        let section = records_bytes(&[
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, para_text),
+            rec(
+                record::HWPTAG_CTRL_HEADER,
+                1,
+                crate::hwp::control::CTRL_FOOTNOTE.to_le_bytes().to_vec(),
+            ),
+            rec(record::HWPTAG_LIST_HEADER, 2, vec![0u8; 8]),
+            rec(record::HWPTAG_PARA_HEADER, 2, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 3, utf16le("각주 내용")),
+            rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            rec(record::HWPTAG_PARA_TEXT, 1, utf16le(&filler)),
+        ]);
+        let compressed = test_util::deflate(&section);
+        let compressed_doc = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(1)),
+            (
+                "/DocInfo",
+                test_util::deflate(&test_util::doc_info_bytes(2)),
+            ),
+            ("/BodyText/Section0", compressed.clone()),
+            (
+                "/BodyText/Section1",
+                compressed[..compressed.len() / 2].to_vec(),
+            ),
+        ]);
+        let plain_doc = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(1)),
+            ("/BodyText/Section0", section),
+        ]);
+
+        for (name, bytes) in [
+            ("borrowed_compressed.hwp", compressed_doc),
+            ("borrowed_plain.hwp", plain_doc),
+        ] {
+            let path = test_util::temp_file(name, &bytes);
+            let extract = |threshold| {
+                let opts = ExtractOptions {
+                    borrowed_records_threshold: threshold,
+                    ..Default::default()
+                };
+                extract_text_with_warnings(&path, &opts).unwrap()
+            };
+            let (borrowed, owned) = (extract(0), extract(usize::MAX));
+            std::fs::remove_file(&path).ok();
+
+            assert!(
+                borrowed.0.starts_with("본문각주 내용\n 뒤\n"),
+                "got: {:?}",
+                borrowed.0
+            );
+            assert_eq!(borrowed, owned);
+        }
+    }
+
+    #[test]
+    fn test_truncated_section_keeps_leading_text() {
+        // 잘 압축되지 않는 긴 문단을 사이에 두어 스트림 중간에서 자른다
+        let filler: String = (0..20_000u32)
+            .map(|i| char::from_u32(0xAC00 + (i * 7919) % 11_172).unwrap())
+            .collect();
+        let section = test_util::records_bytes(&[
+            test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le("첫 문단")),
+            test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le(&filler)),
+            test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le("끝")),
+        ]);
+        let compressed = test_util::deflate(&section);
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(1)),
+            (
+                "/DocInfo",
+                test_util::deflate(&test_util::doc_info_bytes(1)),
+            ),
+            (
+                "/BodyText/Section0",
+                compressed[..compressed.len() / 2].to_vec(),
+            ),
+        ]);
+        let path = test_util::temp_file("truncated_section.hwp", &bytes);
+        let result = extract_text_with_warnings(&path, &ExtractOptions::default());
+        let plain = extract_text_from_file(&path);
+        std::fs::remove_file(&path).ok();
+
+        let (text, warnings) = result.unwrap();
+        assert!(text.starts_with("첫 문단\n"), "got: {text:?}");
+        assert!(!text.contains('끝'));
+        assert!(matches!(
+            warnings.as_slice(),
+            [Warning::TruncatedSection { section: 0, recovered }] if *recovered < section.len()
+        ));
+        assert_eq!(plain.unwrap(), text);
+    }
+
+    #[test]
+    fn test_batch_outcome_extracted() {
+        let br = BatchResult {
+            path: PathBuf::from("a.hwp"),
+            result: Ok("본문\n".into()),
+        };
+        assert_eq!(br.outcome(), BatchOutcome::Extracted);
+    }
+
+    #[test]
+    fn test_chunk_by_budget() {
+        assert_eq!(
+            chunk_by_budget(&[30, 30, 30, 50, 10], 100),
+            vec![0..3, 3..5]
+        );
+        // 예산보다 큰 파일은 단독 그룹
+        assert_eq!(
+            chunk_by_budget(&[10, 500, 10, 10], 100),
+            vec![0..1, 1..2, 2..4]
+        );
+        assert_eq!(chunk_by_budget(&[100, 100], 100), vec![0..1, 1..2]);
+        assert_eq!(chunk_by_budget(&[0, 0, 0], 0), vec![0..3]);
+        assert!(chunk_by_budget(&[], 100).is_empty());
+    }
+
+    #[test]
+    fn test_extract_text_batch_bounded_preserves_order() {
+        let path = test_util::temp_file("bounded.hwp", &image_only_hwp());
+        let paths = vec![path.clone(), path.with_extension("missing"), path.clone()];
+        let results = extract_text_batch_bounded(&paths, 1);
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[1].path, paths[1]);
+        assert_eq!(results[0].outcome(), BatchOutcome::Empty);
+        assert_eq!(results[1].outcome(), BatchOutcome::Failed);
+        assert_eq!(results[2].outcome(), BatchOutcome::Empty);
+    }
+
+    #[test]
+    fn test_lowercase_storage_names() {
+        let section = test_util::records_bytes(&[
+            test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le("본문")),
+        ]);
+        let data = test_util::build_compound(&[
+            ("/fileheader", test_util::file_header_bytes(0)),
+            ("/docinfo", test_util::doc_info_bytes(1)),
+            ("/bodytext/section0", section),
+        ]);
+        let comp = ole::open_compound_reader(std::io::Cursor::new(data.clone())).unwrap();
+        assert_eq!(
+            ole::find_root_storage(&comp, "BodyText").as_deref(),
+            Some("bodytext")
+        );
+        assert_eq!(ole::find_root_storage(&comp, "ViewText"), None);
+
+        let path = test_util::temp_file("lowercase_storage.hwp", &data);
+        let text = extract_text_from_file(&path);
+        let report = validate(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(text.unwrap(), "본문\n");
+        assert!(report.unwrap().is_valid());
+    }
+
+    #[test]
+    fn test_validate_section_count_mismatch() {
+        let section = test_util::records_bytes(&[
+            test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le("본문")),
+        ]);
+        let build = |declared: u16| {
+            test_util::build_compound(&[
+                ("/FileHeader", test_util::file_header_bytes(0)),
+                ("/DocInfo", test_util::doc_info_bytes(declared)),
+                ("/BodyText/Section0", section.clone()),
+                ("/BodyText/Section1", section.clone()),
+            ])
+        };
+
+        let path = test_util::temp_file("validate_ok.hwp", &build(2));
+        let report = validate(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert!(report.is_valid(), "{report:?}");
+
+        let path = test_util::temp_file("validate_mismatch.hwp", &build(3));
+        let report = validate(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(
+            report.issues,
+            [ValidationIssue::SectionCountMismatch {
+                declared: 3,
+                found: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn test_section_start_pages() {
+        let section = |start: u16| {
+            let mut secd = crate::hwp::control::CTRL_SECTION_DEF.to_le_bytes().to_vec();
+            secd.resize(20, 0);
+            secd.extend_from_slice(&start.to_le_bytes());
+            let mut para_text = vec![0x02, 0x00];
+            para_text.extend_from_slice(&[0u8; 14]);
+            test_util::records_bytes(&[
+                test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                test_util::rec(record::HWPTAG_PARA_TEXT, 1, para_text),
+                test_util::rec(record::HWPTAG_CTRL_HEADER, 1, secd),
+            ])
+        };
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(3)),
+            ("/BodyText/Section0", section(0)),
+            ("/BodyText/Section1", section(10)),
+            ("/BodyText/Section2", section(0)),
+        ]);
+        let path = test_util::temp_file("start_pages.hwp", &bytes);
+        let starts = section_start_pages(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(starts.unwrap(), [1, 10, 0]);
+    }
+
+    #[test]
+    fn test_text_watermark() {
+        let section = |wrap: u32| {
+            let mut para_text = test_util::utf16le("본문");
+            para_text.extend_from_slice(&[0x0B, 0x00]);
+            para_text.extend_from_slice(&[0u8; 14]);
+            let mut gso = crate::hwp::control::CTRL_GSO.to_le_bytes().to_vec();
+            gso.extend_from_slice(&(wrap << 21).to_le_bytes());
+            let mut art = 3u16.to_le_bytes().to_vec();
+            art.extend(test_util::utf16le("대외비"));
+            test_util::records_bytes(&[
+                test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                test_util::rec(record::HWPTAG_PARA_TEXT, 1, para_text),
+                test_util::rec(record::HWPTAG_CTRL_HEADER, 1, gso),
+                test_util::rec(record::HWPTAG_SHAPE_COMPONENT, 2, vec![0; 8]),
+                test_util::rec(record::HWPTAG_SHAPE_COMPONENT_TEXTART, 3, art),
+            ])
+        };
+        let check = |name: &str, wrap: u32| {
+            let bytes = test_util::build_compound(&[
+                ("/FileHeader", test_util::file_header_bytes(0)),
+                ("/DocInfo", test_util::doc_info_bytes(1)),
+                ("/BodyText/Section0", section(wrap)),
+            ]);
+            let path = test_util::temp_file(name, &bytes);
+            let result = (
+                has_watermark(&path).unwrap(),
+                watermark_text(&path).unwrap(),
+            );
+            std::fs::remove_file(&path).ok();
+            result
+        };
+        // 글 뒤로 배치된 글맵시
+        assert_eq!(
+            check("watermark.hwp", 4),
+            (true, Some("대외비".to_string()))
+        );
+        // 어울림 배치는 본문 개체
+        assert_eq!(check("no_watermark.hwp", 0), (false, None));
+    }
+
+    #[test]
+    fn test_text_breakdown_counts_tables_separately() {
+        // "본문 글" 문단 + 1x2 표 ("이름", "값")
+        let mut para_text = test_util::utf16le("본문 글");
+        para_text.extend_from_slice(&[0x0B, 0x00]);
+        para_text.extend_from_slice(&[0u8; 14]);
+        let mut table = vec![0u8; 4];
+        table.extend_from_slice(&1u16.to_le_bytes());
+        table.extend_from_slice(&2u16.to_le_bytes());
+        let mut records = vec![
+            test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            test_util::rec(record::HWPTAG_PARA_TEXT, 1, para_text),
+            test_util::rec(
+                record::HWPTAG_CTRL_HEADER,
+                1,
+                crate::hwp::control::CTRL_TABLE.to_le_bytes().to_vec(),
+            ),
+            test_util::rec(record::HWPTAG_TABLE, 2, table),
+        ];
+        for (col, cell_text) in [(0u16, "이름"), (1, "값")] {
+            let mut lh = vec![0u8; 8];
+            for v in [col, 0, 1, 1] {
+                lh.extend_from_slice(&v.to_le_bytes());
+            }
+            records.push(test_util::rec(record::HWPTAG_LIST_HEADER, 2, lh));
+            records.push(test_util::rec(record::HWPTAG_PARA_HEADER, 2, vec![]));
+            records.push(test_util::rec(
+                record::HWPTAG_PARA_TEXT,
+                3,
+                test_util::utf16le(cell_text),
+            ));
+        }
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(1)),
+            ("/BodyText/Section0", test_util::records_bytes(&records)),
+        ]);
+        let path = test_util::temp_file("breakdown.hwp", &bytes);
+        let breakdown = text_breakdown(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(
+            breakdown.unwrap(),
+            TextBreakdown {
+                body_chars: 3,
+                table_chars: 3,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_text_breakdown_table_hwp() {
+        let path = sample_path("basic/표.hwp");
+        if !path.exists() {
+            return;
+        }
+        let breakdown = text_breakdown(&path).unwrap();
+        assert!(breakdown.table_chars > 0, "{breakdown:?}");
+
+        // 표 밖의 글자는 모두 본문으로 집계
+        let text = extract_text_from_file(&path).unwrap();
+        let total = text.chars().filter(|c| !c.is_whitespace()).count();
+        assert!(breakdown.body_chars + breakdown.table_chars <= total);
+        assert_eq!(breakdown.footnote_chars, 0);
+    }
+
+    #[test]
+    fn test_extract_first_paragraphs_stops_early() {
+        let paragraphs = |bodies: &[&str]| {
+            let records: Vec<_> = bodies
+                .iter()
+                .flat_map(|body| {
+                    [
+                        test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                        test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le(body)),
+                    ]
+                })
+                .collect();
+            test_util::records_bytes(&records)
+        };
+        let streams = [
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(3)),
+            ("/BodyText/Section0", paragraphs(&["하나", " ", "둘"])),
+            ("/BodyText/Section1", paragraphs(&["셋", "넷"])),
+            // 앞 문단만 필요하면 읽지 않는 깨진 섹션
+            ("/BodyText/Section2", vec![0x42, 0x00, 0x40, 0x06]),
+        ];
+        let path = test_util::temp_file("first_paras.hwp", &test_util::build_compound(&streams));
+        let two = extract_first_paragraphs(&path, 2);
+        let three = extract_first_paragraphs(&path, 3);
+        let none = extract_first_paragraphs(&path, 0);
+        let all = extract_first_paragraphs(&path, 10);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(two.unwrap(), "하나\n \n둘\n");
+        assert_eq!(three.unwrap(), "하나\n \n둘\n셋\n");
+        assert_eq!(none.unwrap(), "");
+        assert!(all.is_err());
+
+        let path = test_util::temp_file("first_paras.hml", HWPML_BODY.as_bytes());
+        let lead = extract_first_paragraphs(&path, 1);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(lead.unwrap(), "본문\n");
+    }
+
+    #[test]
+    fn test_extract_preview_truncates() {
+        let section = |body: &str| {
+            test_util::records_bytes(&[
+                test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+                test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le(body)),
+            ])
+        };
+        let mut streams = vec![
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(2)),
+            ("/BodyText/Section0", section("첫째 구역 본문")),
+            ("/BodyText/Section1", section("둘째 구역")),
+        ];
+
+        // PrvText 없음: 섹션 추출 후 자르기
+        let path = test_util::temp_file("preview_body.hwp", &test_util::build_compound(&streams));
+        let preview = extract_preview(&path, 5);
+        let full = extract_preview(&path, 1000);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(preview.unwrap(), "첫째 구역");
+        assert_eq!(full.unwrap(), "첫째 구역 본문\n둘째 구역\n");
+
+        // PrvText 우선
+        streams.push(("/PrvText", test_util::utf16le("미리보기 텍스트\0")));
+        let path = test_util::temp_file("preview_prv.hwp", &test_util::build_compound(&streams));
+        let preview = extract_preview(&path, 4);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(preview.unwrap(), "미리보기");
+    }
+
+    #[test]
+    fn test_extract_document_isolates_corrupt_section() {
+        let good = test_util::records_bytes(&[
+            test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le("본문")),
+        ]);
+        // 본문 100바이트를 선언했지만 데이터가 없는 레코드
+        let corrupt = ((100u32 << 20) | record::HWPTAG_PARA_HEADER as u32)
+            .to_le_bytes()
+            .to_vec();
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(0)),
+            ("/DocInfo", test_util::doc_info_bytes(2)),
+            ("/BodyText/Section0", corrupt),
+            ("/BodyText/Section1", good),
+        ]);
+        let path = test_util::temp_file("partial_sections.hwp", &bytes);
+        let doc = extract_document(&path, &ExtractOptions::default());
+        let whole = extract_text_from_file(&path);
         std::fs::remove_file(&path).ok();
+
+        assert!(whole.is_err());
+        let doc = doc.unwrap();
+        assert_eq!(doc.sections.len(), 2);
+        assert!(matches!(doc.sections[0], Err(HwpError::Parse(_))));
+        assert_eq!(
+            doc.sections[1].as_ref().unwrap(),
+            &Section {
+                index: 1,
+                text: "본문\n".into(),
+                paragraphs: vec![Paragraph {
+                    text: "본문\n".into(),
+                    alignment: Alignment::Justify,
+                }],
+            }
+        );
+        assert_eq!(doc.text(), "본문\n");
+        assert_eq!(doc.errors().count(), 1);
+    }
+
+    #[test]
+    fn test_hwp_document_from_bytes_and_reader() {
+        let section = test_util::records_bytes(&[
+            test_util::rec(record::HWPTAG_PARA_HEADER, 0, vec![]),
+            test_util::rec(record::HWPTAG_PARA_TEXT, 1, test_util::utf16le("메모리")),
+        ]);
+        let bytes = test_util::build_compound(&[
+            ("/FileHeader", test_util::file_header_bytes(1)),
+            (
+                "/DocInfo",
+                test_util::deflate(&test_util::doc_info_bytes(1)),
+            ),
+            ("/BodyText/Section0", test_util::deflate(&section)),
+        ]);
+
+        let mut doc = HwpDocument::from_bytes(&bytes).unwrap();
+        assert!(doc.header().compressed);
+        assert_eq!(doc.doc_info().section_count, 1);
+        assert_eq!(doc.extract_text().unwrap(), "메모리\n");
+
+        let mut doc = HwpDocument::from_reader(Cursor::new(bytes.clone())).unwrap();
+        assert_eq!(doc.extract_text().unwrap(), "메모리\n");
+
+        assert!(HwpDocument::from_bytes(&bytes[..100]).is_err());
+    }
+
+    #[test]
+    fn test_hwp_document_from_sample_bytes() {
+        let path = sample_path("basic/blank.hwp");
+        if !path.exists() {
+            return;
+        }
+        let bytes = std::fs::read(&path).unwrap();
+        let text = HwpDocument::from_bytes(&bytes)
+            .unwrap()
+            .extract_text()
+            .unwrap();
+        assert_eq!(text, extract_text_from_file(&path).unwrap());
     }
 
     #[test]
@@ -1,4 +1,6 @@
+use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process;
 use std::time::Instant;
@@ -15,7 +17,7 @@ struct Args {
     #[arg(short, long)]
     output: Option<PathBuf>,
 
-    /// 병렬 처리 스레드 수 (기본: CPU 코어 수)
+    /// 병렬 처리 스레드 수 (기본: CPU 코어 수, `parallel` 기능이 꺼져 있으면 무시)
     #[arg(short = 'j', long)]
     threads: Option<usize>,
 
@@ -26,9 +28,38 @@ struct Args {
     /// 스트림 목록만 출력
     #[arg(long)]
     list_streams: bool,
+
+    /// 문서 정보(DocInfo 요약, 문서 요약 정보)만 출력 (HWP 전용)
+    #[arg(long)]
+    metadata: bool,
+
+    /// 문단 하나를 한 줄로 출력 (문단 안 줄바꿈과 표는 공백으로 이어 붙임, 단일 파일 표준 출력 전용)
+    #[arg(long)]
+    by_paragraph: bool,
+
+    /// 디렉토리 모드에서 처리할 확장자 (쉼표로 구분, 대소문자 무시)
+    #[arg(long, value_delimiter = ',', default_value = "hwp,hwpx,hwpml,hml")]
+    extensions: Vec<String>,
+
+    /// 출력 파일 이름에서 일부 파일 시스템이 허용하지 않는 문자(<>:"/\|?* 등)를 _로 바꿈
+    #[arg(long)]
+    sanitize_names: bool,
+
+    /// 출력 .txt 파일 앞에 UTF-8 BOM(EF BB BF)을 붙임 (BOM이 있어야 UTF-8로 여는 Windows 프로그램용)
+    #[arg(long)]
+    bom: bool,
+
+    /// 실패한 파일의 경로와 오류만 출력 (추출 결과는 쓰지 않음, 하나라도 실패하면 종료 코드 1)
+    #[arg(long)]
+    errors_only: bool,
+
+    /// 모든 추출 결과를 파일 하나에 이어 씀 (문서마다 `=== 경로 ===` 머리 줄, 실패는 그 자리에 오류 기록)
+    #[arg(long, value_name = "OUTPUT.txt")]
+    concat: Option<PathBuf>,
 }
 
-fn collect_hwp_files(dir: &Path, recursive: bool) -> Vec<PathBuf> {
+/// 디렉토리에서 확장자가 `extensions`(소문자, 점 없이) 중 하나인 파일을 모은다.
+fn collect_hwp_files(dir: &Path, recursive: bool, extensions: &[String]) -> Vec<PathBuf> {
     let mut files = Vec::new();
     let entries = match fs::read_dir(dir) {
         Ok(e) => e,
@@ -42,32 +73,146 @@ fn collect_hwp_files(dir: &Path, recursive: bool) -> Vec<PathBuf> {
         let path = entry.path();
         if path.is_dir() {
             if recursive {
-                files.extend(collect_hwp_files(&path, true));
+                files.extend(collect_hwp_files(&path, true, extensions));
             }
-        } else if path
-            .extension()
-            .is_some_and(|ext| ext.eq_ignore_ascii_case("hwp") || ext.eq_ignore_ascii_case("hwpx"))
-        {
+        } else if path.extension().is_some_and(|ext| {
+            let ext = ext.to_string_lossy().to_ascii_lowercase();
+            extensions.contains(&ext)
+        }) {
             files.push(path);
         }
     }
     files
 }
 
-fn process_batch(files: &[PathBuf], output_dir: &Path) {
+/// 확장자와 내용으로 판별한 형식이 다르면 그 설명을 반환한다.
+///
+/// 추출은 내용으로 형식을 고르므로 결과에는 영향이 없지만, 잘못 붙은 확장자를
+/// 알려 준다. 형식을 알 수 없거나 파일을 읽지 못하면 `None`.
+fn extension_mismatch(path: &Path) -> Option<String> {
+    let format = hwarang::detect_file_format(path).ok()?;
+    let detected = format.extension()?;
+    let ext = path.extension()?.to_string_lossy().to_ascii_lowercase();
+    (!format.matches_extension(&ext))
+        .then(|| format!(".{ext} file contains {} data", detected.to_uppercase()))
+}
+
+/// 확장자가 내용과 맞지 않는 파일마다 경고를 출력한다.
+fn warn_extension_mismatches(files: &[PathBuf]) {
+    for path in files {
+        if let Some(msg) = extension_mismatch(path) {
+            eprintln!("MISLABELED\t{}\t{}", path.display(), msg);
+        }
+    }
+}
+
+/// UTF-8 바이트 순서 표시
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// 추출 텍스트를 파일에 쓴다. `bom`이면 UTF-8 BOM을 앞에 붙인다.
+fn write_output(path: &Path, text: &str, bom: bool) -> std::io::Result<()> {
+    if bom {
+        let mut data = Vec::with_capacity(UTF8_BOM.len() + text.len());
+        data.extend_from_slice(UTF8_BOM);
+        data.extend_from_slice(text.as_bytes());
+        fs::write(path, data)
+    } else {
+        fs::write(path, text)
+    }
+}
+
+/// 파일 이름에 쓸 수 없는 문자를 `_`로 바꾸고 끝의 점·공백을 뗀다.
+fn sanitize_stem(stem: &str) -> String {
+    let cleaned: String = stem
+        .chars()
+        .map(|c| match c {
+            '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    let trimmed = cleaned.trim_end_matches(['.', ' ']);
+    if trimmed.is_empty() {
+        "_".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// 입력 파일마다 겹치지 않는 출력 파일 이름(`이름.txt`)을 만든다.
+///
+/// 다른 디렉토리의 같은 이름 파일이 서로 덮어쓰지 않도록, 이미 쓰인 이름이면
+/// `이름_2.txt`, `이름_3.txt`, ...처럼 번호를 붙인다. 대소문자만 다른 이름도
+/// 충돌로 본다 (대소문자를 구분하지 않는 파일 시스템 대비).
+fn output_file_names(files: &[PathBuf], sanitize: bool) -> Vec<String> {
+    let mut used = HashSet::new();
+    files
+        .iter()
+        .map(|path| unique_output_name(path, sanitize, &mut used))
+        .collect()
+}
+
+/// [`output_file_names`]와 같되, 입력 디렉토리 구조를 유지한 출력 경로를 만든다.
+///
+/// 이름 충돌(`a?.hwp`와 `a*.hwp`가 정리 후 같은 이름이 되는 경우 등)은 출력
+/// 디렉토리마다 따로 검사한다.
+fn structured_output_paths(
+    files: &[PathBuf],
+    base_dir: &Path,
+    output_dir: &Path,
+    sanitize: bool,
+) -> Vec<PathBuf> {
+    // 대소문자를 구분하지 않는 파일 시스템에서는 X/와 x/가 같은 디렉토리이므로 소문자로 묶는다
+    let mut used: HashMap<String, HashSet<String>> = HashMap::new();
+    files
+        .iter()
+        .map(|path| {
+            // 입력 디렉토리 기준 상대 경로 유지
+            let rel = path.strip_prefix(base_dir).unwrap_or(path);
+            let parent = rel.parent().unwrap_or(Path::new(""));
+            let key = parent.to_string_lossy().to_lowercase();
+            let name = unique_output_name(path, sanitize, used.entry(key).or_default());
+            output_dir.join(parent).join(name)
+        })
+        .collect()
+}
+
+/// `used`에 없는 출력 파일 이름을 만들어 등록한다 (`이름.txt`, `이름_2.txt`, ...).
+fn unique_output_name(path: &Path, sanitize: bool, used: &mut HashSet<String>) -> String {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let stem = if sanitize {
+        sanitize_stem(&stem)
+    } else {
+        stem.into_owned()
+    };
+    let mut name = format!("{stem}.txt");
+    let mut n = 1;
+    while !used.insert(name.to_lowercase()) {
+        n += 1;
+        name = format!("{stem}_{n}.txt");
+    }
+    name
+}
+
+fn process_batch(files: &[PathBuf], output_dir: &Path, sanitize: bool, bom: bool) {
     let start = Instant::now();
     let total = files.len();
 
     let results = hwarang::extract_text_batch(files);
+    let names = output_file_names(files, sanitize);
 
     let mut success = 0usize;
+    let mut empty = 0usize;
     let mut failed = 0usize;
-    for br in &results {
+    for (br, name) in results.iter().zip(&names) {
+        if br.outcome() == hwarang::BatchOutcome::Empty {
+            eprintln!("EMPTY\t{}", br.path.display());
+            empty += 1;
+        }
         match &br.result {
             Ok(text) => {
-                let stem = br.path.file_stem().unwrap_or_default().to_string_lossy();
-                let out_path = output_dir.join(format!("{}.txt", stem));
-                if let Err(e) = fs::write(&out_path, text) {
+                let out_path = output_dir.join(name);
+                if let Err(e) = write_output(&out_path, text, bom) {
                     eprintln!("WRITE_ERR\t{}\t{}", br.path.display(), e);
                     failed += 1;
                 } else {
@@ -83,36 +228,44 @@ fn process_batch(files: &[PathBuf], output_dir: &Path) {
 
     let elapsed = start.elapsed();
     eprintln!(
-        "Done: {}/{} succeeded, {} failed, {:.2}s ({:.0} files/s)",
+        "Done: {}/{} succeeded ({} without text), {} failed, {:.2}s ({:.0} files/s)",
         success,
         total,
+        empty,
         failed,
         elapsed.as_secs_f64(),
         total as f64 / elapsed.as_secs_f64()
     );
 }
 
-fn process_batch_with_structure(files: &[PathBuf], base_dir: &Path, output_dir: &Path) {
+fn process_batch_with_structure(
+    files: &[PathBuf],
+    base_dir: &Path,
+    output_dir: &Path,
+    sanitize: bool,
+    bom: bool,
+) {
     let start = Instant::now();
     let total = files.len();
 
     let results = hwarang::extract_text_batch(files);
+    let out_paths = structured_output_paths(files, base_dir, output_dir, sanitize);
 
     let mut success = 0usize;
+    let mut empty = 0usize;
     let mut failed = 0usize;
-    for br in &results {
+    for (br, out_path) in results.iter().zip(&out_paths) {
+        if br.outcome() == hwarang::BatchOutcome::Empty {
+            eprintln!("EMPTY\t{}", br.path.display());
+            empty += 1;
+        }
         match &br.result {
             Ok(text) => {
-                // 입력 디렉토리 기준 상대 경로 유지
-                let rel = br.path.strip_prefix(base_dir).unwrap_or(&br.path);
-                let mut out_path = output_dir.join(rel);
-                out_path.set_extension("txt");
-
                 if let Some(parent) = out_path.parent() {
                     let _ = fs::create_dir_all(parent);
                 }
 
-                if let Err(e) = fs::write(&out_path, text) {
+                if let Err(e) = write_output(out_path, text, bom) {
                     eprintln!("WRITE_ERR\t{}\t{}", br.path.display(), e);
                     failed += 1;
                 } else {
@@ -128,19 +281,133 @@ fn process_batch_with_structure(files: &[PathBuf], base_dir: &Path, output_dir:
 
     let elapsed = start.elapsed();
     eprintln!(
-        "Done: {}/{} succeeded, {} failed, {:.2}s ({:.0} files/s)",
+        "Done: {}/{} succeeded ({} without text), {} failed, {:.2}s ({:.0} files/s)",
         success,
         total,
+        empty,
         failed,
         elapsed.as_secs_f64(),
         total as f64 / elapsed.as_secs_f64()
     );
 }
 
+/// 추출에 실패한 파일마다 `경로\t오류` 한 줄을 쓰고 실패 수를 반환한다.
+///
+/// 성공한 파일의 텍스트는 버린다. 코퍼스에서 깨진 파일을 골라낼 때 쓴다.
+fn report_failures(files: &[PathBuf], out: &mut impl Write) -> std::io::Result<usize> {
+    let mut failed = 0usize;
+    for br in hwarang::extract_text_batch(files) {
+        if let Err(e) = &br.result {
+            writeln!(out, "{}\t{}", br.path.display(), e)?;
+            failed += 1;
+        }
+    }
+    Ok(failed)
+}
+
+/// 추출 결과를 입력 순서대로 `out` 하나에 이어 쓰고 (성공, 실패) 수를 반환한다.
+///
+/// 문서마다 `=== 경로 ===` 머리 줄 뒤에 텍스트를 쓰고, 실패한 문서는 텍스트 대신
+/// `[error: 오류]` 한 줄을 남긴다. 텍스트가 줄바꿈으로 끝나지 않으면 덧붙인다.
+fn write_concatenated(
+    files: &[PathBuf],
+    out: &mut impl Write,
+    bom: bool,
+) -> std::io::Result<(usize, usize)> {
+    if bom {
+        out.write_all(UTF8_BOM)?;
+    }
+    let (mut success, mut failed) = (0usize, 0usize);
+    for br in hwarang::extract_text_batch(files) {
+        writeln!(out, "=== {} ===", br.path.display())?;
+        match &br.result {
+            Ok(text) => {
+                out.write_all(text.as_bytes())?;
+                if !text.is_empty() && !text.ends_with('\n') {
+                    out.write_all(b"\n")?;
+                }
+                success += 1;
+            }
+            Err(e) => {
+                writeln!(out, "[error: {e}]")?;
+                eprintln!("EXTRACT_ERR\t{}\t{}", br.path.display(), e);
+                failed += 1;
+            }
+        }
+    }
+    Ok((success, failed))
+}
+
+/// 문단마다 한 줄씩 출력한다.
+///
+/// HWP는 최상위 문단 범위(그 문단에 앵커된 표 포함)를 한 줄로 합치고,
+/// 문단 범위가 없는 HWPX/HWPML은 추출 텍스트의 비어 있지 않은 줄을 그대로 쓴다.
+fn print_paragraphs(path: &Path) -> hwarang::error::Result<()> {
+    let lines = match hwarang::extract_with_offsets(path) {
+        Ok((text, spans)) => paragraph_lines(&text, &spans),
+        Err(hwarang::error::HwpError::UnsupportedFormat) => {
+            let text = hwarang::extract_text_from_file(path)?;
+            text.lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(String::from)
+                .collect()
+        }
+        Err(e) => return Err(e),
+    };
+    for line in lines {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+/// 본문 문단 범위마다 줄을 공백으로 이어 한 줄로 만든다. 빈 문단은 뺀다.
+fn paragraph_lines(text: &str, spans: &[hwarang::ParaSpan]) -> Vec<String> {
+    spans
+        .iter()
+        .filter(|span| span.kind == hwarang::ParaKind::Body)
+        .map(|span| {
+            text[span.start..span.end]
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// DocInfo 요약과 문서 요약 정보를 출력한다.
+fn print_metadata(path: &Path) -> hwarang::error::Result<()> {
+    println!("{}", hwarang::read_doc_info(path)?);
+    if let Some(summary) = hwarang::extract_summary(path)? {
+        let fields = [
+            ("title", &summary.title),
+            ("subject", &summary.subject),
+            ("author", &summary.author),
+            ("keywords", &summary.keywords),
+            ("comments", &summary.comments),
+            ("last author", &summary.last_author),
+            ("application", &summary.authoring_app),
+        ];
+        for (name, value) in fields {
+            if let Some(value) = value {
+                println!("{name}: {value}");
+            }
+        }
+        if let Some(revisions) = summary.revision_count {
+            println!("revisions: {revisions}");
+        }
+    }
+    Ok(())
+}
+
 fn main() {
     let args = Args::parse();
 
     // rayon 스레드풀 설정 (4MB 스택 사이즈: 깊은 중첩 문서 대비)
+    #[cfg(feature = "parallel")]
     {
         let mut builder = rayon::ThreadPoolBuilder::new().stack_size(4 * 1024 * 1024);
         if let Some(n) = args.threads {
@@ -148,6 +415,10 @@ fn main() {
         }
         builder.build_global().unwrap();
     }
+    #[cfg(not(feature = "parallel"))]
+    if args.threads.is_some() {
+        eprintln!("Warning: -j ignored (built without the `parallel` feature)");
+    }
 
     if args.list_streams {
         match hwarang::list_streams(&args.input) {
@@ -164,17 +435,99 @@ fn main() {
         return;
     }
 
+    if args.metadata {
+        if let Err(e) = print_metadata(&args.input) {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+
     // 단일 파일 모드
+    let extensions: Vec<String> = args
+        .extensions
+        .iter()
+        .map(|ext| ext.trim().trim_start_matches('.').to_ascii_lowercase())
+        .filter(|ext| !ext.is_empty())
+        .collect();
+
+    let input_files = || {
+        if args.input.is_dir() {
+            collect_hwp_files(&args.input, args.recursive, &extensions)
+        } else {
+            vec![args.input.clone()]
+        }
+    };
+
+    if args.errors_only {
+        let files = input_files();
+        match report_failures(&files, &mut std::io::stdout().lock()) {
+            Ok(0) => eprintln!("Checked {} files, none failed", files.len()),
+            Ok(failed) => {
+                eprintln!("Checked {} files, {} failed", files.len(), failed);
+                process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if let Some(ref concat_path) = args.concat {
+        let start = Instant::now();
+        let files = input_files();
+        warn_extension_mismatches(&files);
+        let written = fs::File::create(concat_path).and_then(|file| {
+            let mut out = std::io::BufWriter::new(file);
+            let counts = write_concatenated(&files, &mut out, args.bom)?;
+            out.flush()?;
+            Ok(counts)
+        });
+        match written {
+            Ok((success, failed)) => eprintln!(
+                "Done: {}/{} succeeded, {} failed, {:.2}s -> {}",
+                success,
+                files.len(),
+                failed,
+                start.elapsed().as_secs_f64(),
+                concat_path.display()
+            ),
+            Err(e) => {
+                eprintln!("Error writing {}: {}", concat_path.display(), e);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
     if args.input.is_file() {
+        warn_extension_mismatches(std::slice::from_ref(&args.input));
         if let Some(ref out_dir) = args.output {
             fs::create_dir_all(out_dir).unwrap_or_else(|e| {
                 eprintln!("Error creating output directory: {}", e);
                 process::exit(1);
             });
-            process_batch(std::slice::from_ref(&args.input), out_dir);
+            process_batch(
+                std::slice::from_ref(&args.input),
+                out_dir,
+                args.sanitize_names,
+                args.bom,
+            );
+        } else if args.by_paragraph {
+            if let Err(e) = print_paragraphs(&args.input) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
         } else {
-            match hwarang::extract_text_from_file(&args.input) {
-                Ok(text) => print!("{}", text),
+            match hwarang::extract_text_with_warnings(&args.input, &Default::default()) {
+                Ok((text, warnings)) => {
+                    for warning in warnings {
+                        eprintln!("Warning: {}", warning);
+                    }
+                    print!("{}", text);
+                }
                 Err(e) => {
                     eprintln!("Error: {}", e);
                     process::exit(1);
@@ -203,16 +556,238 @@ fn main() {
         process::exit(1);
     });
 
-    let files = collect_hwp_files(&args.input, args.recursive);
-    eprintln!("Found {} HWP files", files.len());
+    let files = collect_hwp_files(&args.input, args.recursive, &extensions);
+    eprintln!("Found {} files", files.len());
 
     if files.is_empty() {
         return;
     }
+    warn_extension_mismatches(&files);
 
     if args.recursive {
-        process_batch_with_structure(&files, &args.input, &output_dir);
+        process_batch_with_structure(
+            &files,
+            &args.input,
+            &output_dir,
+            args.sanitize_names,
+            args.bom,
+        );
     } else {
-        process_batch(&files, &output_dir);
+        process_batch(&files, &output_dir, args.sanitize_names, args.bom);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwarang::{ParaKind, ParaSpan};
+
+    fn span(start: usize, end: usize, kind: ParaKind) -> ParaSpan {
+        ParaSpan { start, end, kind }
+    }
+
+    #[test]
+    fn test_paragraph_lines_joins_tables() {
+        let text = "첫 문단\n표 앞\n| 가 | 나 |\n| --- | --- |\n\n\n끝\n";
+        let table_start = text.find('|').unwrap();
+        let table_end = text.find("\n\n").unwrap() + 1;
+        let second_end = table_end + 1;
+        let spans = [
+            span(0, 11, ParaKind::Body),
+            span(11, second_end, ParaKind::Body),
+            span(table_start, table_end, ParaKind::Table),
+            span(second_end, second_end + 1, ParaKind::Body),
+            span(second_end + 1, text.len(), ParaKind::Body),
+        ];
+        assert_eq!(
+            paragraph_lines(text, &spans),
+            ["첫 문단", "표 앞 | 가 | 나 | | --- | --- |", "끝"]
+        );
+    }
+
+    #[test]
+    fn test_by_paragraph_on_sample() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../hwplib/sample_hwp/basic/표.hwp");
+        if !path.exists() {
+            return;
+        }
+        let (text, spans) = hwarang::extract_with_offsets(&path).unwrap();
+        let lines = paragraph_lines(&text, &spans);
+        assert!(!lines.is_empty());
+        assert!(lines.iter().all(|l| !l.contains('\n')));
+        assert!(lines.iter().any(|l| l.contains("| --- |")));
+    }
+
+    #[test]
+    fn test_collect_respects_extensions() {
+        let dir = std::env::temp_dir().join(format!("hwarang-collect-{}", process::id()));
+        let sub = dir.join("sub");
+        fs::create_dir_all(&sub).unwrap();
+        for name in ["a.hwp", "b.HWPX", "c.hwpml", "d.txt", "sub/e.hwp"] {
+            fs::write(dir.join(name), b"").unwrap();
+        }
+        let names = |exts: &[&str], recursive: bool| {
+            let exts: Vec<String> = exts.iter().map(|e| e.to_string()).collect();
+            let mut names: Vec<String> = collect_hwp_files(&dir, recursive, &exts)
+                .iter()
+                .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(
+            names(&["hwp", "hwpx", "hwpml"], false),
+            ["a.hwp", "b.HWPX", "c.hwpml"]
+        );
+        assert_eq!(names(&["hwpx"], false), ["b.HWPX"]);
+        assert_eq!(names(&["hwp"], true), ["a.hwp", "e.hwp"]);
+        assert!(names(&[], true).is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_extension_mismatch() {
+        let dir = std::env::temp_dir().join(format!("hwarang-mismatch-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let ole = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+        let mislabeled = dir.join("report.hwpx");
+        let labeled = dir.join("report.HWP");
+        let unknown = dir.join("notes.hwp");
+        fs::write(&mislabeled, ole).unwrap();
+        fs::write(&labeled, ole).unwrap();
+        fs::write(&unknown, b"plain text").unwrap();
+
+        assert_eq!(
+            extension_mismatch(&mislabeled).as_deref(),
+            Some(".hwpx file contains HWP data")
+        );
+        assert_eq!(extension_mismatch(&labeled), None);
+        assert_eq!(extension_mismatch(&unknown), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_output_file_names_avoid_collisions() {
+        let files = [
+            PathBuf::from("a/report.hwp"),
+            PathBuf::from("b/report.hwpx"),
+            PathBuf::from("c/REPORT.hwp"),
+            PathBuf::from("d/memo.hwp"),
+        ];
+        assert_eq!(
+            output_file_names(&files, false),
+            ["report.txt", "report_2.txt", "REPORT_3.txt", "memo.txt"]
+        );
+    }
+
+    #[test]
+    fn test_structured_output_paths_avoid_collisions() {
+        let files = [
+            PathBuf::from("in/x/a?.hwp"),
+            PathBuf::from("in/x/a*.hwp"),
+            PathBuf::from("in/y/a?.hwp"),
+            PathBuf::from("in/x/a.hwpx"),
+            PathBuf::from("in/X/a.hwp"),
+        ];
+        let paths = structured_output_paths(&files, Path::new("in"), Path::new("out"), true);
+        assert_eq!(
+            paths,
+            [
+                Path::new("out/x/a_.txt"),
+                Path::new("out/x/a__2.txt"),
+                Path::new("out/y/a_.txt"),
+                Path::new("out/x/a.txt"),
+                Path::new("out/X/a_2.txt"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_report_failures_lists_only_bad_files() {
+        let dir = std::env::temp_dir().join(format!("hwarang-errors-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("good.hml"),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<HWPML><BODY><SECTION><P><TEXT><CHAR>본문</CHAR></TEXT></P></SECTION></BODY></HWPML>"#,
+        )
+        .unwrap();
+        fs::write(dir.join("broken.hwp"), b"not a document").unwrap();
+        fs::write(dir.join("truncated.hwpx"), b"PK\x03\x04").unwrap();
+
+        let exts = ["hwp", "hwpx", "hml"].map(String::from);
+        let mut files = collect_hwp_files(&dir, false, &exts);
+        files.sort();
+        let mut out = Vec::new();
+        let failed = report_failures(&files, &mut out).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(failed, 2);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with(&format!("{}\t", dir.join("broken.hwp").display())));
+        assert!(lines[1].starts_with(&format!("{}\t", dir.join("truncated.hwpx").display())));
+        assert!(!out.contains("good.hml"));
+        assert!(!out.contains("본문"));
+    }
+
+    #[test]
+    fn test_write_concatenated_with_headers() {
+        let dir = std::env::temp_dir().join(format!("hwarang-concat-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let hwpml = |body: &str| {
+            format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+<HWPML><BODY><SECTION><P><TEXT><CHAR>{body}</CHAR></TEXT></P></SECTION></BODY></HWPML>"#
+            )
+        };
+        let files = [dir.join("a.hml"), dir.join("b.hwp"), dir.join("c.hml")];
+        fs::write(&files[0], hwpml("첫 문서")).unwrap();
+        fs::write(&files[1], b"not a document").unwrap();
+        fs::write(&files[2], hwpml("셋째 문서")).unwrap();
+
+        let mut out = Vec::new();
+        let counts = write_concatenated(&files, &mut out, true).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(counts, (2, 1));
+        assert!(out.starts_with(UTF8_BOM));
+        let out = String::from_utf8(out[UTF8_BOM.len()..].to_vec()).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], format!("=== {} ===", files[0].display()));
+        assert_eq!(lines[1], "첫 문서");
+        assert_eq!(lines[2], format!("=== {} ===", files[1].display()));
+        assert!(lines[3].starts_with("[error: "));
+        assert_eq!(lines[4], format!("=== {} ===", files[2].display()));
+        assert_eq!(lines[5], "셋째 문서");
+        assert_eq!(lines.len(), 6);
+    }
+
+    #[test]
+    fn test_sanitize_stem() {
+        assert_eq!(sanitize_stem("보고서: 2024?"), "보고서_ 2024_");
+        assert_eq!(sanitize_stem("a|b*c\u{1}"), "a_b_c_");
+        assert_eq!(sanitize_stem("끝. "), "끝");
+        assert_eq!(sanitize_stem(".."), "_");
+        let files = [PathBuf::from("x/a?.hwp"), PathBuf::from("y/a*.hwp")];
+        assert_eq!(output_file_names(&files, true), ["a_.txt", "a__2.txt"]);
+    }
+
+    #[test]
+    fn test_write_output_bom() {
+        let dir = std::env::temp_dir().join(format!("hwarang-bom-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let with_bom = dir.join("with.txt");
+        let without_bom = dir.join("without.txt");
+        write_output(&with_bom, "한글", true).unwrap();
+        write_output(&without_bom, "한글", false).unwrap();
+
+        let data = fs::read(&with_bom).unwrap();
+        assert_eq!(&data[..3], [0xEF, 0xBB, 0xBF]);
+        assert_eq!(&data[3..], "한글".as_bytes());
+        assert_eq!(fs::read(&without_bom).unwrap(), "한글".as_bytes());
+        fs::remove_dir_all(&dir).unwrap();
     }
 }
@@ -0,0 +1,129 @@
+//! Markdown rendering shared by the HWP and HWPX table output.
+//!
+//! [`TableMode::Markdown`](crate::TableMode::Markdown) tables are rendered
+//! with these functions, so callers of the structured API can produce
+//! markdown that matches the extracted text.
+
+use crate::extract::replace_cell_newlines;
+use crate::options::CellNewlines;
+
+/// Escapes text for use inside a markdown table cell.
+///
+/// `|` becomes `\|` and line breaks are replaced with spaces, the
+/// [`CellNewlines`] default. Use [`escape_cell_with`] for another policy.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(hwarang::markdown::escape_cell("a|b\nc"), "a\\|b c");
+/// ```
+pub fn escape_cell(s: &str) -> String {
+    escape_cell_with(s, CellNewlines::default())
+}
+
+/// [`escape_cell`] with an explicit policy for line breaks inside the cell.
+pub fn escape_cell_with(s: &str, newlines: CellNewlines) -> String {
+    replace_cell_newlines(&s.replace('|', "\\|"), newlines)
+}
+
+/// Renders rows of cell text as a markdown table.
+///
+/// The first row becomes the header and is followed by the `| --- |`
+/// separator. Short rows are padded with empty cells to the widest row.
+/// Every cell is escaped with [`escape_cell_with`]. Returns an empty string
+/// when there are no cells.
+///
+/// # Examples
+///
+/// ```
+/// use hwarang::CellNewlines;
+///
+/// let rows = vec![
+///     vec!["이름".to_string(), "값".to_string()],
+///     vec!["a|b".to_string()],
+/// ];
+/// assert_eq!(
+///     hwarang::markdown::format_table(&rows, CellNewlines::Space),
+///     "| 이름 | 값 |\n| --- | --- |\n| a\\|b |  |\n"
+/// );
+/// ```
+pub fn format_table(rows: &[Vec<String>], newlines: CellNewlines) -> String {
+    let col_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if col_count == 0 {
+        return String::new();
+    }
+
+    let mut text = String::new();
+    for (i, row) in rows.iter().enumerate() {
+        text.push('|');
+        for j in 0..col_count {
+            let cell = row.get(j).map_or("", String::as_str);
+            text.push(' ');
+            text.push_str(&escape_cell_with(cell, newlines));
+            text.push_str(" |");
+        }
+        text.push('\n');
+
+        // 첫 행 뒤에 구분선
+        if i == 0 {
+            text.push('|');
+            for _ in 0..col_count {
+                text.push_str(" --- |");
+            }
+            text.push('\n');
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_cell() {
+        assert_eq!(escape_cell(""), "");
+        assert_eq!(escape_cell("hello"), "hello");
+        assert_eq!(escape_cell("a|b"), "a\\|b");
+        assert_eq!(escape_cell("line1\nline2"), "line1 line2");
+        assert_eq!(escape_cell("a|b\nc"), "a\\|b c");
+    }
+
+    #[test]
+    fn test_escape_cell_newline_policies() {
+        assert_eq!(
+            escape_cell_with("a|b\nc", CellNewlines::LiteralBackslashN),
+            "a\\|b\\nc"
+        );
+        assert_eq!(
+            escape_cell_with("a|b\nc", CellNewlines::Preserve),
+            "a\\|b\nc"
+        );
+    }
+
+    #[test]
+    fn test_format_table_empty() {
+        assert_eq!(format_table(&[], CellNewlines::Space), "");
+        assert_eq!(format_table(&[vec![], vec![]], CellNewlines::Space), "");
+    }
+
+    #[test]
+    fn test_format_table_basic() {
+        let rows = vec![
+            vec!["A".to_string(), "B".to_string()],
+            vec!["C".to_string(), "D".to_string()],
+        ];
+        let table = format_table(&rows, CellNewlines::Space);
+        assert_eq!(table, "| A | B |\n| --- | --- |\n| C | D |\n");
+    }
+
+    #[test]
+    fn test_format_table_pads_short_rows() {
+        let rows = vec![
+            vec!["A".to_string()],
+            vec!["C".to_string(), "D".to_string()],
+        ];
+        let table = format_table(&rows, CellNewlines::Space);
+        assert_eq!(table, "| A |  |\n| --- | --- |\n| C | D |\n");
+    }
+}
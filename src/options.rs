@@ -0,0 +1,256 @@
+/// How tables are rendered in the extracted text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableMode {
+    /// A markdown table (the default).
+    #[default]
+    Markdown,
+    /// One `R{row}C{col}: text` line per non-empty cell, using 1-based
+    /// coordinates taken from the document's own cell addresses, so merged
+    /// cells keep their exact positions.
+    Coordinates,
+    /// One line per row with cells separated by `\t`, for loading into
+    /// spreadsheets or data tools. Tabs inside cells become spaces.
+    TabSeparated,
+}
+
+/// How line breaks inside a table cell are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CellNewlines {
+    /// Replace each line break with a space (the default), keeping one
+    /// cell per line of output.
+    #[default]
+    Space,
+    /// Replace each line break with the two characters `\n`, so the
+    /// cell's line structure can be restored downstream.
+    LiteralBackslashN,
+    /// Keep line breaks as they are. This breaks the one-row-per-line
+    /// layout of every table mode; the consumer must know the cell bounds.
+    Preserve,
+}
+
+/// Where floating objects are placed in the extracted text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FloatingObjects {
+    /// At the paragraph the object is anchored to (the default).
+    #[default]
+    AtAnchor,
+    /// Grouped at the end of the section, so the body reads linearly.
+    ///
+    /// Applies to tables, drawing objects and equations that are positioned
+    /// relative to the page or paper rather than laid out inline.
+    SectionEnd,
+}
+
+/// Where footnotes are placed in the extracted text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FootnotePlacement {
+    /// At the reference point, as part of the sentence (the default).
+    #[default]
+    Inline,
+    /// After the body of the section that references them.
+    ///
+    /// Each reference is replaced by a `[fn:N]` marker and the notes follow
+    /// the section as `[fn:N] text` lines. Numbers continue across sections.
+    SectionEnd,
+    /// After the body of the whole document, with the same markers as
+    /// [`SectionEnd`](Self::SectionEnd). Endnotes moved by
+    /// [`ExtractOptions::endnotes_at_end`] follow the footnotes.
+    DocumentEnd,
+}
+
+/// Default for [`ExtractOptions::max_sections`].
+pub const DEFAULT_MAX_SECTIONS: u16 = 1024;
+
+/// Default for [`ExtractOptions::max_table_cells`].
+pub const DEFAULT_MAX_TABLE_CELLS: usize = 10_000;
+
+/// Default for [`ExtractOptions::borrowed_records_threshold`] (1 MiB).
+pub const DEFAULT_BORROWED_RECORDS_THRESHOLD: usize = 1024 * 1024;
+
+/// Options controlling text extraction.
+///
+/// The [`Default`] value reproduces the output of
+/// [`extract_text_from_file`](crate::extract_text_from_file).
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use hwarang::{ExtractOptions, TableMode};
+///
+/// let opts = ExtractOptions {
+///     table_mode: TableMode::Coordinates,
+///     ..Default::default()
+/// };
+/// let text = hwarang::extract_text_from_file_with_options(Path::new("document.hwp"), &opts)?;
+/// # Ok::<(), hwarang::error::HwpError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExtractOptions {
+    /// How tables are rendered.
+    pub table_mode: TableMode,
+    /// How line breaks inside table cells are written, in every table mode.
+    pub cell_newlines: CellNewlines,
+    /// Render tab characters as runs of spaces instead of `\t`.
+    ///
+    /// Each tab's recorded width is converted to spaces; tabs without a
+    /// recorded width use the section's default tab stop. Only affects HWP
+    /// documents.
+    pub expand_tabs: bool,
+    /// Emit the cached result of auto-fields (date, file name, document
+    /// summary, ...) when the paragraph itself shows nothing for the field.
+    /// Date fields are re-rendered with the field's own format code, e.g.
+    /// `2024년 5월 1일`, when the cached value can be read as a date.
+    pub expand_fields: bool,
+    /// Process at most this many sections of an HWP document, regardless of
+    /// the section count it declares. `None` removes the limit.
+    ///
+    /// Guards against corrupt counts (up to 65535 stream lookups) and allows
+    /// sampling the start of large documents. Defaults to
+    /// [`DEFAULT_MAX_SECTIONS`].
+    pub max_sections: Option<u16>,
+    /// Where page-anchored floating objects are placed. Only affects HWP
+    /// documents.
+    pub floating_objects: FloatingObjects,
+    /// Text returned instead of an empty or whitespace-only result, so
+    /// documents without extractable text (blank or image-only) are easy to
+    /// tell apart downstream. `None` returns the text unchanged.
+    pub placeholder_for_empty: Option<String>,
+    /// Replace non-breaking spaces (U+00A0) and the other Unicode space
+    /// variants, such as U+2009 thin space and U+3000 ideographic space,
+    /// with an ASCII space, so search indexes match them against ordinary
+    /// spaces. Line breaks and tabs are left as they are.
+    pub normalize_spaces: bool,
+    /// Render hyperlinks as markdown links, `[text](target)`, instead of
+    /// their display text alone. Affects HWP and HWPX documents.
+    pub include_hyperlinks: bool,
+    /// Prefix each body paragraph with spaces proportional to the left
+    /// margin of its paragraph shape (one space per 10pt), so nested quotes
+    /// and indented outlines keep their structure. Only affects HWP documents.
+    pub indent_paragraphs: bool,
+    /// Emit table captions (e.g. `표 1. ...`) as a line above or below the
+    /// table, following the caption's position in the document. Only
+    /// affects HWP documents.
+    pub include_table_captions: bool,
+    /// Prefix numbered paragraphs with `1. `, `2. `, ... and bulleted ones
+    /// with `- `, indenting nested levels by three spaces. Numbering
+    /// continues across consecutive list paragraphs and restarts after a
+    /// non-list paragraph or a different numbering. Only affects HWP
+    /// documents.
+    pub list_markers: bool,
+    /// Insert a visible `⟦ctrl:N⟧` placeholder (N = control code) at each
+    /// extended control position in paragraph text. Meant for diagnosing
+    /// missing or misplaced content; only affects HWP documents.
+    pub debug_control_markers: bool,
+    /// Emit the name and entered value of each form object (text input,
+    /// combo box, check box, ...) as a `label: value` line, where the label
+    /// is the object's caption or, failing that, its name. Only affects HWP
+    /// documents.
+    pub include_form_values: bool,
+    /// Largest `rows × cols` an HWP table may declare to be laid out as a
+    /// grid. Larger tables have their cell text extracted linearly instead.
+    /// `None` removes the limit.
+    ///
+    /// Guards against corrupt TABLE records whose dimensions would make the
+    /// grid allocation exhaust memory. Defaults to
+    /// [`DEFAULT_MAX_TABLE_CELLS`].
+    pub max_table_cells: Option<usize>,
+    /// Strings placed before and after each equation script, e.g.
+    /// `("$".into(), "$".into())` for inline or `("$$\n".into(), "\n$$".into())`
+    /// for display math. Empty strings (the default) emit the bare script.
+    /// Only affects HWP documents.
+    pub equation_delimiters: (String, String),
+    /// Wrap runs whose character shape has a strikethrough (typically
+    /// deleted text) in markdown `~~...~~`. Markers are closed at line
+    /// breaks and controls so every line stays balanced. Only affects HWP
+    /// documents.
+    pub strikethrough_markers: bool,
+    /// Strings placed before and after underlined runs, e.g.
+    /// `("<u>".into(), "</u>".into())`. `None` (the default) leaves
+    /// underlined text unmarked. Markers are balanced per line like
+    /// [`strikethrough_markers`](Self::strikethrough_markers). Only affects
+    /// HWP documents.
+    pub underline_markers: Option<(String, String)>,
+    /// Strings placed before and after runs with a shade (highlighter)
+    /// color, e.g. `("==".into(), "==".into())` or
+    /// `("<mark>".into(), "</mark>".into())`. `None` (the default) leaves
+    /// highlighted text unmarked. Only affects HWP documents.
+    pub highlight_markers: Option<(String, String)>,
+    /// Emit the alternate text of each picture as `[image: text]`, taken
+    /// from the picture's `alt`/`desc` attribute or its shape comment.
+    /// Pictures without alternate text emit nothing. Only affects HWPX
+    /// documents.
+    pub include_image_alt: bool,
+    /// Render cross-reference fields ("see Figure 3") as markdown links to
+    /// their target bookmark, `[Figure 3](#target)`, instead of their
+    /// display text alone. Only affects HWP documents.
+    pub include_cross_references: bool,
+    /// Emit each embedded picture as an HTML `<img>` tag whose `src` is a
+    /// base64 `data:` URI, so the output is self-contained. Pictures whose
+    /// data is missing or not an image format are skipped. This can make the
+    /// output many times larger. Only affects HWP documents.
+    pub embed_images: bool,
+    /// Move endnotes to the end of the document. Each endnote reference is
+    /// replaced by a `[en:N]` marker, numbered across all sections, and the
+    /// notes are emitted after the body as `[en:N] text` lines. Footnotes are
+    /// placed by [`footnote_placement`](Self::footnote_placement). Only
+    /// affects HWP documents.
+    pub endnotes_at_end: bool,
+    /// Where footnotes go: inline at their reference (the default), or
+    /// moved after the section or the document behind `[fn:N]` markers.
+    /// Only affects HWP documents.
+    pub footnote_placement: FootnotePlacement,
+    /// Break each paragraph into lines where the document's own layout
+    /// wrapped it, as recorded when the file was saved, so the output
+    /// follows the visual lines of the page. The space at a wrap point stays
+    /// at the end of its line. Paragraphs without layout information are
+    /// left as they are. Only affects HWP documents.
+    pub visual_line_breaks: bool,
+    /// Emit the reviewer comment (memo) attached to a tracked change right
+    /// after the changed text, as `[reviewer: comment]`. A memo counts as
+    /// attached when one of its paragraph's range tags carries the memo's
+    /// field ID. Only affects HWP documents.
+    pub include_change_comments: bool,
+    /// Decompressed size, in bytes, from which an HWP section's records
+    /// borrow their bodies from the decompressed stream instead of copying
+    /// each body into its own allocation. Large sections then avoid holding
+    /// a second copy of their data; smaller ones keep the simpler owning
+    /// path. The extracted text is the same either way. `0` always borrows
+    /// and `usize::MAX` never does. Defaults to
+    /// [`DEFAULT_BORROWED_RECORDS_THRESHOLD`].
+    pub borrowed_records_threshold: usize,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        ExtractOptions {
+            table_mode: TableMode::default(),
+            cell_newlines: CellNewlines::default(),
+            expand_tabs: false,
+            expand_fields: false,
+            max_sections: Some(DEFAULT_MAX_SECTIONS),
+            floating_objects: FloatingObjects::default(),
+            placeholder_for_empty: None,
+            normalize_spaces: false,
+            include_hyperlinks: false,
+            indent_paragraphs: false,
+            include_table_captions: false,
+            list_markers: false,
+            debug_control_markers: false,
+            include_form_values: false,
+            max_table_cells: Some(DEFAULT_MAX_TABLE_CELLS),
+            equation_delimiters: (String::new(), String::new()),
+            strikethrough_markers: false,
+            underline_markers: None,
+            highlight_markers: None,
+            include_image_alt: false,
+            include_cross_references: false,
+            embed_images: false,
+            endnotes_at_end: false,
+            footnote_placement: FootnotePlacement::default(),
+            visual_line_breaks: false,
+            include_change_comments: false,
+            borrowed_records_threshold: DEFAULT_BORROWED_RECORDS_THRESHOLD,
+        }
+    }
+}
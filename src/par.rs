@@ -0,0 +1,209 @@
+//! 병렬 반복 추상화
+//!
+//! `parallel` 기능이 켜져 있으면 rayon의 `par_iter`/`into_par_iter`를 쓰고,
+//! 꺼져 있으면 같은 이름의 메서드가 순차 반복자를 돌려준다.
+//! 스레드가 없는 환경(WASM 등)에서도 호출부를 바꾸지 않고 빌드할 수 있다.
+//!
+//! 일괄 처리는 파일 단위로 병렬화하고, 파일 하나 안의 섹션은 [`map_sections`]로
+//! 병렬화한다. 둘 다 같은 스레드풀을 쓰므로 스레드 수는 풀 크기를 넘지 않지만,
+//! 섹션 작업을 기다리는 워커가 다른 파일 작업을 훔쳐 오면 동시에 열린 파일 수가
+//! 스레드 수를 넘어 메모리를 과하게 쓸 수 있다. 그래서 파일 수가 스레드 수 이상인
+//! 일괄 처리는 [`batch_item`] 안에서 섹션을 순차 처리하여, 동시에 처리 중인 파일 수를
+//! 풀 크기 이하로 묶는다. 파일이 스레드보다 적으면 섹션 병렬화를 그대로 둔다.
+//!
+//! 섹션 압축 해제 버퍼와 레코드 벡터는 [`with_scratch`]로 워커 스레드마다 재사용한다.
+
+use std::cell::{Cell, RefCell};
+
+use crate::hwp::record::Record;
+
+#[cfg(feature = "parallel")]
+pub(crate) use rayon::prelude::*;
+
+#[cfg(not(feature = "parallel"))]
+pub(crate) use sequential::*;
+
+thread_local! {
+    /// 현재 스레드가 일괄 처리의 파일 작업을 실행 중인지
+    static IN_BATCH: Cell<bool> = const { Cell::new(false) };
+    /// 현재 스레드의 섹션 디코딩 버퍼
+    static SCRATCH: RefCell<Scratch> = RefCell::new(Scratch::default());
+}
+
+/// 스레드에 남겨 둘 스크래치 버퍼의 최대 크기 (바이트). 넘으면 사용 후 해제한다.
+const SCRATCH_RETAIN_LIMIT: usize = 16 * 1024 * 1024;
+
+/// 섹션을 디코딩할 때 워커 스레드마다 재사용하는 버퍼
+#[derive(Debug, Default)]
+pub(crate) struct Scratch {
+    /// 섹션 스트림 압축 해제 결과
+    pub(crate) inflated: Vec<u8>,
+    /// 섹션 레코드 목록
+    pub(crate) records: Vec<Record>,
+}
+
+impl Scratch {
+    /// 너무 커진 버퍼를 해제하고 레코드를 비운다.
+    fn recycle(&mut self) {
+        if self.inflated.capacity() > SCRATCH_RETAIN_LIMIT {
+            self.inflated = Vec::new();
+        }
+        if self.records.capacity() * std::mem::size_of::<Record>() > SCRATCH_RETAIN_LIMIT {
+            self.records = Vec::new();
+        }
+        self.records.clear();
+    }
+}
+
+/// 현재 스레드의 스크래치 버퍼로 `f`를 실행한다.
+///
+/// 대량 처리에서 섹션마다 압축 해제 버퍼와 레코드 벡터를 새로 할당하지 않도록
+/// 스레드별로 유지한다. 내용은 `f`가 채우며 호출 사이에 보존되지 않는다.
+/// 이미 사용 중인 스레드에서 다시 호출되면 임시 버퍼를 쓴다.
+pub(crate) fn with_scratch<R>(f: impl FnOnce(&mut Scratch) -> R) -> R {
+    SCRATCH.with(|cell| match cell.try_borrow_mut() {
+        Ok(mut scratch) => {
+            let result = f(&mut scratch);
+            scratch.recycle();
+            result
+        }
+        Err(_) => f(&mut Scratch::default()),
+    })
+}
+
+/// 작업이 끝나면(패닉 포함) 이전 일괄 처리 상태로 되돌린다.
+struct BatchGuard(bool);
+
+impl Drop for BatchGuard {
+    fn drop(&mut self) {
+        IN_BATCH.with(|b| b.set(self.0));
+    }
+}
+
+/// 일괄 처리의 파일 작업 하나를 실행한다. 그 안의 [`map_sections`]는 순차로 돈다.
+pub(crate) fn batch_item<R>(f: impl FnOnce() -> R) -> R {
+    let _guard = BatchGuard(IN_BATCH.with(|b| b.replace(true)));
+    f()
+}
+
+/// 병렬 처리에 쓰이는 스레드 수 (`parallel` 기능이 꺼져 있으면 1)
+pub(crate) fn current_num_threads() -> usize {
+    #[cfg(feature = "parallel")]
+    return rayon::current_num_threads();
+    #[cfg(not(feature = "parallel"))]
+    1
+}
+
+/// 섹션별 작업을 병렬로 실행하고 입력 순서대로 결과를 모은다.
+///
+/// [`batch_item`] 안에서 호출되면 파일 단위 병렬 처리가 이미 풀을 채우고 있으므로
+/// 현재 스레드에서 순차로 실행한다.
+pub(crate) fn map_sections<T: Send, R: Send>(
+    items: Vec<T>,
+    f: impl Fn(T) -> R + Sync + Send,
+) -> Vec<R> {
+    if IN_BATCH.with(Cell::get) {
+        return items.into_iter().map(f).collect();
+    }
+    items.into_par_iter().map(f).collect()
+}
+
+/// rayon 반복자 메서드의 순차 버전
+#[cfg(any(not(feature = "parallel"), test))]
+pub(crate) mod sequential {
+    /// `into_par_iter()` → `into_iter()`
+    pub(crate) trait IntoParallelIterator: IntoIterator + Sized {
+        fn into_par_iter(self) -> Self::IntoIter {
+            self.into_iter()
+        }
+    }
+
+    impl<I: IntoIterator> IntoParallelIterator for I {}
+
+    /// `par_iter()` → `iter()`
+    pub(crate) trait IntoParallelRefIterator<T> {
+        fn par_iter(&self) -> std::slice::Iter<'_, T>;
+    }
+
+    impl<T> IntoParallelRefIterator<T> for [T] {
+        fn par_iter(&self) -> std::slice::Iter<'_, T> {
+            self.iter()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_sequential_matches_parallel() {
+        let items: Vec<u32> = (0..1000).collect();
+
+        let sequential: Vec<(usize, u32)> = {
+            use super::sequential::*;
+            items
+                .clone()
+                .into_par_iter()
+                .enumerate()
+                .map(|(i, v)| (i, v * 3))
+                .collect()
+        };
+        let parallel: Vec<(usize, u32)> = {
+            use super::*;
+            items
+                .clone()
+                .into_par_iter()
+                .enumerate()
+                .map(|(i, v)| (i, v * 3))
+                .collect()
+        };
+        assert_eq!(sequential, parallel);
+
+        let sequential: Result<Vec<u32>, u32> = {
+            use super::sequential::*;
+            items[..]
+                .par_iter()
+                .map(|&v| if v < 2000 { Ok(v) } else { Err(v) })
+                .collect()
+        };
+        let parallel: Result<Vec<u32>, u32> = {
+            use super::*;
+            items[..]
+                .par_iter()
+                .map(|&v| if v < 2000 { Ok(v) } else { Err(v) })
+                .collect()
+        };
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    fn test_with_scratch_reuses_and_nests() {
+        let capacity = super::with_scratch(|scratch| {
+            scratch.inflated.extend_from_slice(&[1; 4096]);
+            scratch.inflated.capacity()
+        });
+        super::with_scratch(|scratch| {
+            assert!(scratch.inflated.capacity() >= capacity);
+            assert!(scratch.records.is_empty());
+            // 중첩 호출은 별도 버퍼
+            super::with_scratch(|inner| assert_eq!(inner.inflated.capacity(), 0));
+        });
+
+        super::with_scratch(|scratch| scratch.inflated = vec![0; super::SCRATCH_RETAIN_LIMIT + 1]);
+        super::with_scratch(|scratch| assert_eq!(scratch.inflated.capacity(), 0));
+    }
+
+    #[test]
+    fn test_map_sections_sequential_in_batch() {
+        let items: Vec<u32> = (0..256).collect();
+        let caller = std::thread::current().id();
+        let threads = super::batch_item(|| {
+            super::map_sections(items.clone(), |_| std::thread::current().id())
+        });
+        assert!(threads.iter().all(|&id| id == caller));
+
+        // 일괄 처리 밖에서는 순서를 유지한 채 (병렬로) 실행하고, 상태는 복원된다
+        let doubled = super::map_sections(items.clone(), |v| v * 2);
+        assert_eq!(doubled, items.iter().map(|v| v * 2).collect::<Vec<_>>());
+        assert!(!super::IN_BATCH.with(std::cell::Cell::get));
+    }
+}
@@ -0,0 +1,184 @@
+//! 테스트용 합성 문서 생성 헬퍼
+//!
+//! 샘플 파일 없이도 OLE 컨테이너 경로를 검증할 수 있도록
+//! 레코드/스트림/컴파운드 파일을 메모리에서 조립한다.
+
+use std::io::{Cursor, Write};
+use std::path::PathBuf;
+
+use aes::cipher::{block_padding::NoPadding, BlockEncryptMut, KeyInit};
+
+use crate::hwp::crypto;
+use crate::hwp::record::{self, Record, RecordHeader};
+
+/// 유효한 FileHeader 시그니처
+const HWP_SIGNATURE: &[u8; 32] = b"HWP Document File\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0";
+
+/// 메모리 상의 레코드 생성
+pub fn rec(tag_id: u16, level: u16, data: Vec<u8>) -> Record {
+    Record {
+        header: RecordHeader {
+            tag_id,
+            level,
+            size: data.len() as u32,
+        },
+        data,
+    }
+}
+
+/// 문자열 → UTF-16LE 바이트열
+pub fn utf16le(s: &str) -> Vec<u8> {
+    s.encode_utf16().flat_map(|c| c.to_le_bytes()).collect()
+}
+
+/// Scripts 스트림 문자열: DWORD 길이(WCHAR 수) + UTF-16LE
+pub fn script_string(s: &str) -> Vec<u8> {
+    let mut out = (s.encode_utf16().count() as u32).to_le_bytes().to_vec();
+    out.extend_from_slice(&utf16le(s));
+    out
+}
+
+/// 레코드 목록을 스트림 바이트열로 직렬화 (4095 이상은 확장 크기 사용)
+pub fn records_bytes(records: &[Record]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for r in records {
+        let size = r.data.len() as u32;
+        let packed_size = size.min(0xFFF);
+        let header =
+            (r.header.tag_id as u32) | ((r.header.level as u32) << 10) | (packed_size << 20);
+        out.extend_from_slice(&header.to_le_bytes());
+        if packed_size == 0xFFF {
+            out.extend_from_slice(&size.to_le_bytes());
+        }
+        out.extend_from_slice(&r.data);
+    }
+    out
+}
+
+/// section_count만 채운 DocInfo 스트림 바이트열 (비압축)
+pub fn doc_info_bytes(section_count: u16) -> Vec<u8> {
+    let mut props = vec![0u8; 26];
+    props[..2].copy_from_slice(&section_count.to_le_bytes());
+    records_bytes(&[rec(record::HWPTAG_DOCUMENT_PROPERTIES, 0, props)])
+}
+
+/// STYLE 레코드 바이트열
+pub fn style_bytes(name: &str, english_name: &str, para_shape_id: u16) -> Vec<u8> {
+    let mut data = Vec::new();
+    for s in [name, english_name] {
+        data.extend_from_slice(&(s.encode_utf16().count() as u16).to_le_bytes());
+        data.extend(utf16le(s));
+    }
+    data.extend_from_slice(&[0, 0, 0x12, 0x04]); // 속성, 다음 스타일, 언어
+    data.extend_from_slice(&para_shape_id.to_le_bytes());
+    data.extend_from_slice(&0u16.to_le_bytes());
+    data
+}
+
+/// 개요 문단 모양의 속성 1 (level: 1부터)
+pub fn outline_attr1(level: u8) -> u32 {
+    (1 << 23) | (((level - 1) as u32) << 25)
+}
+
+/// CHAR_SHAPE 바디 (72바이트): 속성만 채우고 음영은 없음으로 둔다.
+pub fn char_shape_bytes(attr: u32) -> Vec<u8> {
+    let mut data = vec![0u8; 72];
+    data[46..50].copy_from_slice(&attr.to_le_bytes());
+    data[60..64].copy_from_slice(&0xFFFF_FFFFu32.to_le_bytes()); // 음영 없음
+    data
+}
+
+/// 속성 문자열로 FORM_OBJECT 레코드 데이터를 만든다 (형식 ID 8바이트는 0).
+pub fn form_object_bytes(props: &str) -> Vec<u8> {
+    let mut data = vec![0u8; 8];
+    data.extend_from_slice(&(props.encode_utf16().count() as u16).to_le_bytes());
+    data.extend(utf16le(props));
+    data
+}
+
+/// PARA_HEADER 바디 (22바이트): 문단 모양 ID와 스타일 ID만 채운다.
+pub fn para_header_bytes(para_shape_id: u16, style_id: u8) -> Vec<u8> {
+    let mut data = vec![0u8; 22];
+    data[8..10].copy_from_slice(&para_shape_id.to_le_bytes());
+    data[10] = style_id;
+    data
+}
+
+/// 배포문서 스트림 생성: 레코드 헤더(4) + 난독화된 메타데이터(256) + AES-128/ECB 본문
+///
+/// `seed`가 키 위치를 결정하고, `key`는 그 위치에 기록된다.
+/// `cipher_key`로 본문을 암호화하므로 `key`와 다르게 주면 키 불일치 스트림이 된다.
+pub fn distribution_stream(
+    plain: &[u8],
+    seed: u32,
+    key: [u8; 16],
+    cipher_key: [u8; 16],
+) -> Vec<u8> {
+    let mut meta = [0u8; 256];
+    meta[..4].copy_from_slice(&seed.to_le_bytes());
+    let key_offset = 4 + (meta[0] & 0xF) as usize;
+    meta[key_offset..key_offset + 16].copy_from_slice(&key);
+    crypto::deobfuscate(&mut meta);
+
+    let mut body = plain.to_vec();
+    body.resize(plain.len().div_ceil(16) * 16, 0);
+    let encrypted = ecb::Encryptor::<aes::Aes128>::new_from_slice(&cipher_key)
+        .unwrap()
+        .encrypt_padded_vec_mut::<NoPadding>(&body);
+
+    // HWPTAG_DISTRIBUTE_DOC_DATA (0x1C), size 256
+    let mut out = (0x1Cu32 | (256 << 20)).to_le_bytes().to_vec();
+    out.extend_from_slice(&meta);
+    out.extend(encrypted);
+    out
+}
+
+/// FileHeader 스트림 바이트열 (버전 5.1.0.0)
+pub fn file_header_bytes(flags: u32) -> Vec<u8> {
+    let mut data = HWP_SIGNATURE.to_vec();
+    data.extend_from_slice(&0x0501_0000u32.to_le_bytes());
+    data.extend_from_slice(&flags.to_le_bytes());
+    data.resize(256, 0);
+    data
+}
+
+/// raw deflate 압축 (HWP 스트림 형식)
+pub fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder =
+        flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// (경로, 내용) 목록으로 OLE 컴파운드 파일을 조립한다.
+pub fn build_compound(streams: &[(&str, Vec<u8>)]) -> Vec<u8> {
+    let mut comp = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+    for (path, data) in streams {
+        let parent = std::path::Path::new(path).parent().unwrap();
+        if parent != std::path::Path::new("/") {
+            comp.create_storage_all(parent).unwrap();
+        }
+        let mut s = comp.create_stream(path).unwrap();
+        s.write_all(data).unwrap();
+    }
+    comp.flush().unwrap();
+    comp.into_inner().into_inner()
+}
+
+/// 테스트 간 충돌하지 않는 임시 파일에 바이트열을 기록한다.
+pub fn temp_file(name: &str, data: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("hwarang_{}_{}", std::process::id(), name));
+    std::fs::write(&path, data).unwrap();
+    path
+}
+
+/// (경로, 내용) 목록으로 ZIP 아카이브(HWPX 패키지)를 조립한다.
+pub fn build_zip(entries: &[(&str, &str)]) -> Vec<u8> {
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, content) in entries {
+        zip.start_file(*name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+use std::io::{Read, Seek};
+
+/// A structural problem found by [`validate`](crate::validate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationIssue {
+    /// DocInfo declares a different number of sections than there are
+    /// `SectionN` streams in the body storage. Usually a sign of corruption
+    /// or tampering: missing sections are silently skipped on extraction,
+    /// and surplus ones are never read.
+    SectionCountMismatch {
+        /// `section_count` from DocInfo.
+        declared: u16,
+        /// Number of `SectionN` streams actually present.
+        found: usize,
+    },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationIssue::SectionCountMismatch { declared, found } => write!(
+                f,
+                "section count mismatch: DocInfo declares {declared}, found {found} section streams"
+            ),
+        }
+    }
+}
+
+/// The result of [`validate`](crate::validate).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Problems found, in the order they were checked.
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Returns `true` if no problems were found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// 본문 저장소(BodyText/ViewText)에 있는 `SectionN` 스트림 수를 센다.
+pub(crate) fn count_section_streams<F: Read + Seek>(
+    comp: &cfb::CompoundFile<F>,
+    storage: &str,
+) -> usize {
+    let Ok(entries) = comp.read_storage(storage) else {
+        return 0;
+    };
+    entries
+        .filter(|e| e.is_stream() && is_section_stream_name(e.name()))
+        .count()
+}
+
+/// `Section{N}` 형식의 스트림 이름인지 확인 (OLE 이름처럼 대소문자 구분 없음)
+fn is_section_stream_name(name: &str) -> bool {
+    let prefix = "Section".len();
+    name.get(..prefix)
+        .is_some_and(|p| p.eq_ignore_ascii_case("Section"))
+        && name[prefix..].bytes().all(|b| b.is_ascii_digit())
+        && name.len() > prefix
+}
+
+/// DocInfo의 구역 수와 실제 섹션 스트림 수를 비교한다.
+pub(crate) fn check_section_count(declared: u16, found: usize) -> Option<ValidationIssue> {
+    (declared as usize != found)
+        .then_some(ValidationIssue::SectionCountMismatch { declared, found })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_section_stream_name() {
+        assert!(is_section_stream_name("Section0"));
+        assert!(is_section_stream_name("Section12"));
+        assert!(is_section_stream_name("section3"));
+        assert!(!is_section_stream_name("Section"));
+        assert!(!is_section_stream_name("Section1.bak"));
+        assert!(!is_section_stream_name("DocInfo"));
+    }
+
+    #[test]
+    fn test_check_section_count() {
+        assert_eq!(check_section_count(2, 2), None);
+        let issue = check_section_count(3, 2).unwrap();
+        assert_eq!(
+            issue,
+            ValidationIssue::SectionCountMismatch {
+                declared: 3,
+                found: 2
+            }
+        );
+        assert!(issue.to_string().contains("declares 3, found 2"));
+    }
+}